@@ -0,0 +1,15 @@
+#!/bin/sh
+# Check that the library builds for a bare metal target without the standard 
+# library. A host build cannot catch this because the host target always 
+# ships `std`, so a dependency that links it by accident still builds there.
+#
+# The target must be installed first:
+#
+#     rustup target add thumbv7em-none-eabihf
+set -e
+
+TARGET="${TARGET:-thumbv7em-none-eabihf}"
+
+cargo build --no-default-features --target "$TARGET"
+cargo build --no-default-features --features mint --target "$TARGET"
+cargo build --no-default-features --features half --target "$TARGET"
@@ -153,6 +153,71 @@ where
         Self::normalize((other - self) * one_half + self)
     }
 
+    /// Linearly interpolate between the angles `self` and `other` along the
+    /// shorter arc of the unit circle.
+    ///
+    /// The interpolation takes the wrap-around at a full turn into account, so 
+    /// interpolating between angles on either side of the seam does not sweep 
+    /// the long way around the circle. The resulting angle lies in the 
+    /// range `[0, full_turn)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let angle1 = Degrees(350_f64);
+    /// let angle2 = Degrees(10_f64);
+    /// let expected = Degrees(0_f64);
+    /// let result = angle1.lerp(angle2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    fn lerp(self, other: Self, amount: Self::Dimensionless) -> Self {
+        let delta = Self::normalize_signed(other - self);
+        Self::normalize(self + delta * amount)
+    }
+
+    /// Clamp an angle between the angles `min` and `max`.
+    ///
+    /// The function compares the raw values of the angles, so it does not 
+    /// take the wrap-around at a full turn into account. The result is 
+    /// unspecified when `min > max`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Degrees,
+    /// # };
+    /// #
+    /// let pitch = Degrees(100_f64);
+    /// let min = Degrees(-89_f64);
+    /// let max = Degrees(89_f64);
+    ///
+    /// assert_eq!(pitch.clamp(min, max), Degrees(89_f64));
+    /// assert_eq!((-pitch).clamp(min, max), Degrees(-89_f64));
+    /// assert_eq!(Degrees(45_f64).clamp(min, max), Degrees(45_f64));
+    /// ```
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
     /// Compute the cosecant of a typed angle.
     #[inline]
     fn csc(self) -> Self::Dimensionless {
@@ -192,6 +257,42 @@ where
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
+
+    /// Construct a typed angle from a raw angle in units of radians.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Radians,  
+    /// # };
+    /// #
+    /// let angle = Radians::from_radians_value(2_f64);
+    ///
+    /// assert_eq!(angle, Radians(2_f64));
+    /// ```
+    #[inline]
+    pub fn from_radians_value(value: S) -> Self {
+        Radians(value)
+    }
+
+    /// Get the raw value of a typed angle in units of radians.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Radians,  
+    /// # };
+    /// #
+    /// let angle = Radians(2_f64);
+    ///
+    /// assert_eq!(angle.to_radians_value(), 2_f64);
+    /// ```
+    #[inline]
+    pub fn to_radians_value(self) -> S {
+        self.0
+    }
 }
 
 impl<S> Radians<S> 
@@ -263,6 +364,74 @@ where
     pub fn is_finite(self) -> bool {
         self.0.is_finite()
     }
+
+    /// Convert a typed angle to a raw angle in units of radians.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,  
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let angle = Degrees(180_f64);
+    ///
+    /// assert!(relative_eq!(angle.to_radians_value(), f64::consts::PI, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn to_radians_value(self) -> S {
+        Radians::from(self).0
+    }
+}
+
+/// The angle (arc length) along the unit circle in units of turns, where 
+/// one turn is a full revolution around the circle.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Turns<S>(pub S);
+
+impl<S> Turns<S> 
+where 
+    S: Scalar 
+{
+    /// Construct a typed angle of zero turns.
+    #[inline]
+    pub fn zero() -> Self {
+        Turns(S::zero())
+    }
+
+    /// Determine whether a typed angle is zero turns.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Returns `true` if the underlying floating point number of the typed
+    /// angle is finite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Turns,  
+    /// # };
+    /// #
+    /// let angle: Turns<f64> = Turns(0.25);
+    /// assert!(angle.is_finite()); 
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
 }
 
 impl<S> From<Degrees<S>> for Radians<S> 
@@ -285,6 +454,46 @@ where
     }
 }
 
+impl<S> From<Turns<S>> for Radians<S> 
+where 
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(turns: Turns<S>) -> Radians<S> {
+        Radians(turns.0 * num_traits::cast(2_f64 * f64::consts::PI).unwrap())
+    }
+}
+
+impl<S> From<Radians<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(radians: Radians<S>) -> Turns<S> {
+        Turns(radians.0 / num_traits::cast(2_f64 * f64::consts::PI).unwrap())
+    }
+}
+
+impl<S> From<Turns<S>> for Degrees<S> 
+where 
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(turns: Turns<S>) -> Degrees<S> {
+        Degrees(turns.0 * num_traits::cast(360_f64).unwrap())
+    }
+}
+
+impl<S> From<Degrees<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(degrees: Degrees<S>) -> Turns<S> {
+        Turns(degrees.0 / num_traits::cast(360_f64).unwrap())
+    }
+}
+
 impl<S> fmt::Display for Degrees<S> 
 where 
     S: fmt::Display 
@@ -303,6 +512,15 @@ where
     }
 }
 
+impl<S> fmt::Display for Turns<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} turns", self.0)
+    }
+}
+
 impl<S> ops::Add<Degrees<S>> for Degrees<S> 
 where 
     S: Scalar
@@ -679,6 +897,382 @@ where
     }
 }
 
+impl<S> ops::Add<Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn add(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<'a, S> ops::Add<&'a Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn add(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<S> ops::Add<Turns<S>> for &Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn add(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<'a, S> ops::Add<&'a Turns<S>> for &Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn add(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<S> ops::Sub<Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn sub(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<&'a Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn sub(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<S> ops::Sub<Turns<S>> for &Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn sub(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<&'a Turns<S>> for &Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn sub(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<S> ops::Mul<S> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Turns(self.0 * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Turns(self.0 * other)
+    }
+}
+
+impl<S> ops::Div<S> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Turns(self.0 / other)
+    }
+}
+
+impl<S> ops::Div<S> for &Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Turns(self.0 / other)
+    }
+}
+
+impl<S> ops::Div<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<&'a Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<S> ops::Div<Turns<S>> for &Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<&'a Turns<S>> for &Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<S> ops::Rem<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<&'a Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<S> ops::Rem<Turns<S>> for &Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<&'a Turns<S>> for &Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<S> ops::Neg for Turns<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Turns(-self.0)
+    }
+}
+
+impl<S> ops::Neg for &Turns<S> 
+where 
+    S: ScalarSigned
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Turns(-self.0)
+    }
+}
+
+impl<S> ops::AddAssign<Turns<S>> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn add_assign(&mut self, other: Turns<S>) {
+        *self = *self + other;
+    } 
+}
+
+impl<S> ops::SubAssign<Turns<S>> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Turns<S>) {
+        *self = *self - other;
+    } 
+}
+
+impl<S> ops::MulAssign<S> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        *self = *self * other;
+    } 
+}
+
+impl<S> ops::DivAssign<S> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        *self = *self / other;
+    } 
+}
+
+impl<S> ops::RemAssign<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn rem_assign(&mut self, other: Turns<S>) {
+        *self = *self % other;
+    } 
+}
+
+impl<S> Zero for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn zero() -> Turns<S> {
+        Turns(S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.0, &other.0, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.0, &other.0, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+    }
+}
+
 impl<S> ops::Add<Radians<S>> for Radians<S> 
 where 
     S: Scalar 
@@ -1081,6 +1675,11 @@ where
         S::tan(self.0)
     }
 
+    #[inline]
+    fn sin_cos(self) -> (Self::Dimensionless, Self::Dimensionless) {
+        S::sin_cos(self.0)
+    }
+
     #[inline]
     fn asin(ratio: Self::Dimensionless) -> Self {
         Radians(Self::Dimensionless::asin(ratio))
@@ -1128,6 +1727,11 @@ where
         Radians::from(self).tan()
     }
 
+    #[inline]
+    fn sin_cos(self) -> (Self::Dimensionless, Self::Dimensionless) {
+        Radians::from(self).sin_cos()
+    }
+
     #[inline]
     fn asin(ratio: Self::Dimensionless) -> Self {
         Radians(ratio.asin()).into()
@@ -1149,3 +1753,54 @@ where
     }
 }
 
+impl<S> Angle for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Dimensionless = S;
+
+    #[inline]
+    fn full_turn() -> Self {
+        Turns(S::one())
+    }
+
+    #[inline]
+    fn sin(self) -> Self::Dimensionless {
+        Radians::from(self).sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Self::Dimensionless {
+        Radians::from(self).cos()
+    }
+
+    #[inline]
+    fn tan(self) -> Self::Dimensionless {
+        Radians::from(self).tan()
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self::Dimensionless, Self::Dimensionless) {
+        Radians::from(self).sin_cos()
+    }
+
+    #[inline]
+    fn asin(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.asin()).into()
+    }
+
+    #[inline]
+    fn acos(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.acos()).into()
+    }
+
+    #[inline]
+    fn atan(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.atan()).into()
+    }
+
+    #[inline]
+    fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
+        Radians(Self::Dimensionless::atan2(a, b)).into()
+    }
+}
@@ -1,10 +1,14 @@
 mod coordinates;
 mod magnitude;
 mod scalar;
+mod square_matrix;
 mod unit;
+mod vector_space;
 
 pub use coordinates::*;
 pub use magnitude::*;
 pub use scalar::*;
+pub use square_matrix::*;
 pub use unit::*;
+pub use vector_space::*;
 
@@ -57,6 +57,54 @@ pub trait ScalarSigned
 where 
     Self: Scalar + Neg<Output = Self> 
 {
+    /// Compute the absolute value of a scalar.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     ScalarSigned,
+    /// # };
+    /// #
+    /// assert_eq!(ScalarSigned::abs(-3_i32), 3_i32);
+    /// assert_eq!(ScalarSigned::abs(-3_f64), 3_f64);
+    /// assert_eq!(ScalarSigned::abs(2_f64), 2_f64);
+    /// ```
+    #[inline]
+    fn abs(self) -> Self {
+        if self < Self::zero() { -self } else { self }
+    }
+
+    /// Compute the sign of a scalar.
+    ///
+    /// The sign is `1` for positive scalars, `-1` for negative scalars, and 
+    /// the scalar itself otherwise. In particular, the sign of zero is zero, 
+    /// and the sign of `NaN` is `NaN`. This differs from the floating point
+    /// `signum` function, which returns the sign bit of zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     ScalarSigned,
+    /// # };
+    /// #
+    /// assert_eq!(ScalarSigned::signum(-3_i32), -1_i32);
+    /// assert_eq!(ScalarSigned::signum(0_i32), 0_i32);
+    /// assert_eq!(ScalarSigned::signum(2.5_f64), 1_f64);
+    /// assert_eq!(ScalarSigned::signum(0_f64), 0_f64);
+    /// ```
+    #[inline]
+    fn signum(self) -> Self {
+        let zero = Self::zero();
+        if self > zero {
+            Self::one()
+        } else if self < zero {
+            -Self::one()
+        } else {
+            self
+        }
+    }
 }
 
 impl<T> ScalarSigned for T 
@@ -0,0 +1,74 @@
+use crate::base::scalar::{
+    ScalarFloat,
+};
+
+
+/// A type with this trait acts as a square matrix.
+///
+/// Each square matrix type provides the same basic operations on its elements:
+/// the identity matrix, transposition, the trace, the determinant, and matrix
+/// inversion. This trait collects these operations so that one can write code
+/// that is generic over the dimension of a square matrix.
+///
+/// ## Examples
+///
+/// ```
+/// # use cglinalg::{
+/// #     Matrix2x2,
+/// #     Matrix3x3,
+/// #     SquareMatrix,
+/// # };
+/// #
+/// fn is_orthogonal<M>(matrix: &M) -> bool
+/// where
+///     M: SquareMatrix + PartialEq
+/// {
+///     matrix.inverse() == Some(matrix.transpose())
+/// }
+///
+/// let matrix = Matrix2x2::new(
+///     0_f64, 1_f64,
+///     -1_f64, 0_f64
+/// );
+/// assert!(is_orthogonal(&matrix));
+///
+/// let matrix = Matrix3x3::new(
+///     1_f64, 2_f64, 3_f64,
+///     4_f64, 5_f64, 6_f64,
+///     7_f64, 8_f64, 10_f64
+/// );
+/// assert!(!is_orthogonal(&matrix));
+/// ```
+pub trait SquareMatrix
+where
+    Self: Sized
+{
+    /// The type of the elements of the matrix.
+    type Element: ScalarFloat;
+
+    /// Construct the identity matrix.
+    fn identity() -> Self;
+
+    /// Determine whether a matrix is the identity matrix.
+    fn is_identity(&self) -> bool;
+
+    /// Transpose a matrix.
+    fn transpose(&self) -> Self;
+
+    /// Compute the trace of a matrix.
+    ///
+    /// The trace of a matrix is the sum of its diagonal elements.
+    fn trace(&self) -> Self::Element;
+
+    /// Compute the determinant of a matrix.
+    fn determinant(&self) -> Self::Element;
+
+    /// Determine whether a matrix is invertible.
+    fn is_invertible(&self) -> bool;
+
+    /// Compute the inverse of a matrix.
+    ///
+    /// If the matrix is not invertible, the function returns `None`.
+    fn inverse(&self) -> Option<Self>;
+}
+
@@ -0,0 +1,91 @@
+use crate::base::scalar::{
+    Scalar,
+};
+use crate::base::magnitude::{
+    Magnitude,
+};
+
+use core::ops;
+
+
+/// A type with this trait acts as a vector in a vector space over a field
+/// of scalars.
+///
+/// A vector space supports adding and subtracting vectors, multiplying a vector
+/// by a scalar, and has a zero vector serving as the additive identity.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Vector3,
+/// #     VectorSpace,
+/// # };
+/// #
+/// fn linear_combination<V>(vectors: &[V], weights: &[V::Element]) -> V
+/// where
+///     V: VectorSpace
+/// {
+///     vectors.iter()
+///         .zip(weights.iter())
+///         .fold(V::zero(), |acc, (&vector, &weight)| acc + vector * weight)
+/// }
+///
+/// let vectors = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+/// let weights = [1_i32, 2_i32, 3_i32];
+/// let expected = Vector3::new(1_i32, 2_i32, 3_i32);
+/// let result = linear_combination(&vectors, &weights);
+///
+/// assert_eq!(result, expected);
+/// ```
+pub trait VectorSpace
+where
+    Self: Copy,
+    Self: ops::Add<Self, Output = Self>,
+    Self: ops::Sub<Self, Output = Self>,
+    Self: ops::Mul<<Self as VectorSpace>::Element, Output = Self>,
+{
+    /// The type of the scalars of the vector space.
+    type Element: Scalar;
+
+    /// Construct the zero vector.
+    fn zero() -> Self;
+}
+
+/// A type with this trait acts as a vector in a vector space with an inner
+/// product.
+///
+/// The magnitude and normalization operations come from the Euclidean norm
+/// induced by the inner product, which is provided by the [`Magnitude`] trait.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Vector2,
+/// #     InnerProductSpace,
+/// # };
+/// #
+/// fn project<V>(vector: &V, onto: &V) -> V
+/// where
+///     V: InnerProductSpace
+/// {
+///     *onto * (vector.dot(onto) / onto.dot(onto))
+/// }
+///
+/// let vector = Vector2::new(3_f64, 4_f64);
+/// let onto = Vector2::new(2_f64, 0_f64);
+/// let expected = Vector2::new(3_f64, 0_f64);
+/// let result = project(&vector, &onto);
+///
+/// assert_eq!(result, expected);
+/// ```
+pub trait InnerProductSpace
+where
+    Self: VectorSpace,
+    Self: Magnitude<Output = <Self as VectorSpace>::Element>,
+{
+    /// Compute the inner product of two vectors.
+    fn dot(&self, other: &Self) -> Self::Element;
+}
+
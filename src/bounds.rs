@@ -0,0 +1,281 @@
+use crate::base::{
+    Scalar,
+    ScalarFloat,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::point::{
+    Point3,
+};
+
+use core::fmt;
+
+
+/// An axis-aligned bounding box in three dimensions.
+///
+/// The box is the set of points lying between its `min` corner and its `max`
+/// corner in every coordinate. A box is only meaningful when each component
+/// of `min` is no greater than the corresponding component of `max`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Aabb3<S> {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner of the box with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+impl<S> Aabb3<S> {
+    /// Construct a new axis-aligned bounding box from its corners.
+    #[inline]
+    pub const fn new(min: Point3<S>, max: Point3<S>) -> Aabb3<S> {
+        Aabb3 {
+            min: min,
+            max: max,
+        }
+    }
+}
+
+impl<S> Aabb3<S>
+where
+    S: Scalar
+{
+    /// Construct the smallest axis-aligned bounding box containing every
+    /// point in a slice of points.
+    ///
+    /// The function returns `None` when the slice is empty.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let points = [
+    ///     Point3::new(1_f64, -2_f64, 3_f64),
+    ///     Point3::new(-4_f64, 5_f64, 0_f64),
+    ///     Point3::new(2_f64, 1_f64, -6_f64),
+    /// ];
+    /// let expected = Aabb3::new(
+    ///     Point3::new(-4_f64, -2_f64, -6_f64),
+    ///     Point3::new(2_f64, 5_f64, 3_f64),
+    /// );
+    /// let result = Aabb3::from_points(&points);
+    ///
+    /// assert_eq!(result, Some(expected));
+    /// assert_eq!(Aabb3::<f64>::from_points(&[]), None);
+    /// ```
+    #[inline]
+    pub fn from_points(points: &[Point3<S>]) -> Option<Aabb3<S>> {
+        let (first, rest) = points.split_first()?;
+        let mut min = first.to_vector();
+        let mut max = first.to_vector();
+        for point in rest.iter() {
+            min = min.component_min(&point.to_vector());
+            max = max.component_max(&point.to_vector());
+        }
+
+        Some(Aabb3::new(Point3::from_vector(min), Point3::from_vector(max)))
+    }
+
+    /// Determine whether a point lies inside a bounding box.
+    ///
+    /// Points on the boundary of the box count as inside the box.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(
+    ///     Point3::new(0_i32, 0_i32, 0_i32),
+    ///     Point3::new(2_i32, 2_i32, 2_i32),
+    /// );
+    ///
+    /// assert!(aabb.contains(&Point3::new(1_i32, 1_i32, 1_i32)));
+    /// assert!(aabb.contains(&Point3::new(2_i32, 0_i32, 1_i32)));
+    /// assert!(!aabb.contains(&Point3::new(3_i32, 1_i32, 1_i32)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point3<S>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y &&
+        self.min.z <= point.z && point.z <= self.max.z
+    }
+
+    /// Compute the center of a bounding box.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(
+    ///     Point3::new(-1_f64, 0_f64, 2_f64),
+    ///     Point3::new(3_f64, 4_f64, 4_f64),
+    /// );
+    /// let expected = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let result = aabb.center();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn center(&self) -> Point3<S> {
+        self.min + self.extents()
+    }
+
+    /// Compute the half-widths of a bounding box along each axis.
+    ///
+    /// A bounding box consists of the points within `extents` of its
+    /// center along each coordinate axis.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(
+    ///     Point3::new(-1_f64, 0_f64, 2_f64),
+    ///     Point3::new(3_f64, 4_f64, 4_f64),
+    /// );
+    /// let expected = Vector3::new(2_f64, 2_f64, 1_f64);
+    /// let result = aabb.extents();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn extents(&self) -> Vector3<S> {
+        let one = S::one();
+        let two = one + one;
+
+        (self.max - self.min) / two
+    }
+
+    /// Construct the smallest bounding box containing two bounding boxes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let aabb1 = Aabb3::new(
+    ///     Point3::new(0_i32, 0_i32, 0_i32),
+    ///     Point3::new(1_i32, 2_i32, 1_i32),
+    /// );
+    /// let aabb2 = Aabb3::new(
+    ///     Point3::new(-1_i32, 1_i32, 0_i32),
+    ///     Point3::new(0_i32, 3_i32, 2_i32),
+    /// );
+    /// let expected = Aabb3::new(
+    ///     Point3::new(-1_i32, 0_i32, 0_i32),
+    ///     Point3::new(1_i32, 3_i32, 2_i32),
+    /// );
+    /// let result = aabb1.merge(&aabb2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn merge(&self, other: &Aabb3<S>) -> Aabb3<S> {
+        let min = self.min.to_vector().component_min(&other.min.to_vector());
+        let max = self.max.to_vector().component_max(&other.max.to_vector());
+
+        Aabb3::new(Point3::from_vector(min), Point3::from_vector(max))
+    }
+
+    /// Return the eight corners of a bounding box.
+    #[inline]
+    fn corners(&self) -> [Point3<S>; 8] {
+        let min = self.min;
+        let max = self.max;
+
+        [
+            Point3::new(min.x, min.y, min.z),
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            Point3::new(max.x, max.y, max.z),
+        ]
+    }
+}
+
+impl<S> Aabb3<S>
+where
+    S: ScalarFloat
+{
+    /// Compute the bounding box of a bounding box under a homogeneous
+    /// transformation.
+    ///
+    /// The function transforms the eight corners of the box and returns the
+    /// smallest axis-aligned box containing them. Under a rotation the result
+    /// is generally larger than the rotated box itself.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Aabb3,
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 2_f64, 3_f64),
+    /// );
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, -1_f64, 2_f64));
+    /// let expected = Aabb3::new(
+    ///     Point3::new(1_f64, -1_f64, 2_f64),
+    ///     Point3::new(2_f64, 1_f64, 5_f64),
+    /// );
+    /// let result = aabb.transform(&matrix);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform(&self, matrix: &Matrix4x4<S>) -> Aabb3<S> {
+        let mut corners = self.corners();
+        for corner in corners.iter_mut() {
+            *corner = matrix.transform_point(corner);
+        }
+
+        // The array of corners is never empty.
+        Aabb3::from_points(&corners).unwrap()
+    }
+}
+
+impl<S> fmt::Display for Aabb3<S>
+where
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Aabb3 [min: {}, max: {}]",
+            self.min, self.max
+        )
+    }
+}
+
@@ -96,6 +96,8 @@ The biggest one is that it presently does not leverage SIMD instructions yet.
 #![allow(clippy::redundant_field_names)]
 #![no_std]
 extern crate core;
+#[cfg(feature = "std")]
+extern crate std;
 
 extern crate approx;
 extern crate num_traits;
@@ -121,6 +123,10 @@ mod translation;
 mod transform;
 mod similarity;
 
+mod bounds;
+mod plane;
+mod ray;
+
 
 pub use base::*;
 
@@ -141,3 +147,7 @@ pub use translation::*;
 pub use transform::*;
 pub use similarity::*;
 
+pub use bounds::*;
+pub use plane::*;
+pub use ray::*;
+
@@ -0,0 +1,113 @@
+use core::fmt;
+
+
+/// Measures the width of a formatted matrix element without allocating.
+///
+/// The integer part is everything before the first decimal point. Elements
+/// without a decimal point, such as integers or `NaN`, count entirely as
+/// integer part.
+struct ElementWidth {
+    width: usize,
+    integer_width: Option<usize>,
+}
+
+impl ElementWidth {
+    fn measure<S>(element: &S, precision: Option<usize>) -> ElementWidth
+    where
+        S: fmt::Display
+    {
+        let mut measure = ElementWidth { width: 0, integer_width: None };
+        // Writing into an `ElementWidth` never fails.
+        let _ = write_element(&mut measure, element, precision);
+
+        measure
+    }
+
+    fn integer_width(&self) -> usize {
+        self.integer_width.unwrap_or(self.width)
+    }
+
+    fn fractional_width(&self) -> usize {
+        self.width - self.integer_width()
+    }
+}
+
+impl fmt::Write for ElementWidth {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        for ch in string.chars() {
+            if ch == '.' && self.integer_width.is_none() {
+                self.integer_width = Some(self.width);
+            }
+            self.width += 1;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_element<W, S>(writer: &mut W, element: &S, precision: Option<usize>) -> fmt::Result
+where
+    W: fmt::Write,
+    S: fmt::Display
+{
+    match precision {
+        Some(precision) => write!(writer, "{:.*}", precision, element),
+        None => write!(writer, "{}", element),
+    }
+}
+
+fn write_padding(formatter: &mut fmt::Formatter, width: usize) -> fmt::Result {
+    for _ in 0..width {
+        formatter.write_str(" ")?;
+    }
+
+    Ok(())
+}
+
+/// Write a column-major matrix to a formatter as a grid of rows.
+///
+/// The elements are displayed in row-major order, one row per line, with the
+/// decimal points of each column lined up. The precision of the formatter, if
+/// any, applies to every element.
+pub fn fmt_matrix<S, const R: usize, const C: usize>(
+    formatter: &mut fmt::Formatter,
+    name: &str,
+    data: &[[S; R]; C]) -> fmt::Result
+where
+    S: fmt::Display
+{
+    let precision = formatter.precision();
+    let mut integer_widths = [0; C];
+    let mut fractional_widths = [0; C];
+    for (c, column) in data.iter().enumerate() {
+        for element in column.iter() {
+            let measure = ElementWidth::measure(element, precision);
+            integer_widths[c] = usize::max(integer_widths[c], measure.integer_width());
+            fractional_widths[c] = usize::max(fractional_widths[c], measure.fractional_width());
+        }
+    }
+
+    writeln!(formatter, "{} [", name)?;
+    for r in 0..R {
+        formatter.write_str("    [")?;
+        for (c, column) in data.iter().enumerate() {
+            let element = &column[r];
+            let measure = ElementWidth::measure(element, precision);
+            write_padding(formatter, integer_widths[c] - measure.integer_width())?;
+            write_element(formatter, element, precision)?;
+            if c + 1 < C {
+                formatter.write_str(",")?;
+                write_padding(formatter, fractional_widths[c] - measure.fractional_width() + 1)?;
+            } else {
+                write_padding(formatter, fractional_widths[c] - measure.fractional_width())?;
+            }
+        }
+        if r + 1 < R {
+            formatter.write_str("],\n")?;
+        } else {
+            formatter.write_str("]\n")?;
+        }
+    }
+    formatter.write_str("]")
+}
+
@@ -12,8 +12,10 @@ use crate::angle::{
 };
 use crate::base::{
     Magnitude,
+    SquareMatrix,
 };
 use crate::matrix::array::*;
+use crate::matrix::display::fmt_matrix;
 use crate::vector::{
     Vector1,
     Vector2,
@@ -35,6 +37,8 @@ use num_traits::{
     NumCast,
 };
 
+use core::array::TryFromSliceError;
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::*;
 use core::ops;
@@ -51,6 +55,16 @@ pub type Matrix3<S> = Matrix3x3<S>;
 pub type Matrix4<S> = Matrix4x4<S>;
 
 
+/// Compute the Frobenius norm of a matrix from its elements.
+#[inline]
+fn frobenius_norm<S>(elements: &[S]) -> S
+where
+    S: ScalarFloat
+{
+    elements.iter().fold(S::zero(), |acc, &element| acc + element * element).sqrt()
+}
+
+
 /// A **(1 row, 1 column)** matrix in column-major order.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -72,6 +86,29 @@ impl<S> Matrix1x1<S>
 where 
     S: Copy
 {
+    /// Construct a new matrix from a fill value.
+    ///
+    /// The resulting matrix is a matrix where each entry is the supplied fill
+    /// value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x1, 
+    /// # };
+    /// #
+    /// let fill_value: u32 = 3;
+    /// let expected = Matrix1x1::new(fill_value);
+    /// let result = Matrix1x1::from_fill(fill_value);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Matrix1x1<S> {
+        Matrix1x1::new(value)
+    }
+
     /// Get the row of the matrix by value.
     #[inline]
     pub fn row(&self, r: usize) -> Vector1<S> {
@@ -118,6 +155,12 @@ where
         <Self as AsRef<[S; 1]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 1]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector1<S>) -> Matrix1x1<S> {
@@ -287,6 +330,63 @@ where
     pub fn trace(&self) -> S {
         self.data[0][0]
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x1,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x1::new(1_i32);
+    /// let other = Matrix1x1::new(2_i32);
+    /// let expected = Matrix1x1::new(2_i32);
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix1x1::new(
+            self.data[0][0] * other.data[0][0]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x1,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x1::new(2_f64);
+    /// let other = Matrix1x1::new(2_f64);
+    /// let expected = Matrix1x1::new(1_f64);
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix1x1::new(
+            self.data[0][0] / other.data[0][0]
+        )
+    }
 }
 
 impl<S> Matrix1x1<S> 
@@ -374,6 +474,57 @@ where
     pub fn is_invertible(&self) -> bool {
         ulps_ne!(self.determinant(), S::zero())
     }
+
+    /// Determine whether a square matrix has an inverse matrix that can be 
+    /// computed reliably, within a tolerance `epsilon`.
+    ///
+    /// A matrix is treated as invertible when the absolute value of its 
+    /// determinant exceeds `epsilon`. This rejects nearly singular matrices 
+    /// whose inverses would be dominated by floating point error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x1, 
+    /// # };
+    /// #
+    /// let matrix = Matrix1x1::new(1e-20_f64);
+    ///
+    /// assert!(matrix.is_invertible_eps(1e-30_f64));
+    /// assert!(!matrix.is_invertible_eps(1e-12_f64));
+    /// ```
+    #[inline]
+    pub fn is_invertible_eps(&self, epsilon: S) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
+    /// Compute the inverse of a square matrix, if the absolute value of its 
+    /// determinant exceeds `epsilon`.
+    ///
+    /// The function returns `None` for matrices that are singular or nearly 
+    /// singular with respect to the tolerance `epsilon`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x1, 
+    /// # };
+    /// #
+    /// let matrix = Matrix1x1::new(1e-20_f64);
+    ///
+    /// assert!(matrix.inverse().is_some());
+    /// assert!(matrix.inverse_eps(1e-12_f64).is_none());
+    /// ```
+    #[inline]
+    pub fn inverse_eps(&self, epsilon: S) -> Option<Self> {
+        if self.is_invertible_eps(epsilon) {
+            self.inverse()
+        } else {
+            None
+        }
+    }
 }
 
 impl<S> fmt::Display for Matrix1x1<S> 
@@ -381,7 +532,7 @@ where
     S: fmt::Display 
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "Matrix1x1 [[{}]]", self.data[0][0])
+        fmt_matrix(formatter, "Matrix1x1", &self.data)
     }
 }
 
@@ -609,6 +760,12 @@ where
         <Self as AsRef<[S; 4]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 4]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector2<S>, c1: Vector2<S>) -> Matrix2x2<S> {
@@ -618,6 +775,36 @@ where
         )
     }
 
+    /// Construct a matrix from an array of column vectors.
+    ///
+    /// This is equivalent to calling `from_columns` with the elements of the
+    /// array in order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let columns = [
+    ///     Vector2::new(1_i32, 2_i32),
+    ///     Vector2::new(3_i32, 4_i32),
+    /// ];
+    /// let expected = Matrix2x2::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    /// );
+    /// let result = Matrix2x2::from_column_array(columns);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_column_array(columns: [Vector2<S>; 2]) -> Matrix2x2<S> {
+        Matrix2x2::from_columns(columns[0], columns[1])
+    }
+
     /// Map an operation on the elements of a matrix, returning a matrix whose 
     /// elements are elements of the new underlying type.
     ///
@@ -907,6 +1094,87 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///     5_i32, 11_i32,
+    ///     11_i32, 25_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix2x2<S> {
+        self.transpose() * self
+    }
+
+    /// Compute the Kronecker product of two matrices.
+    ///
+    /// The Kronecker product of a matrix `A` with a matrix `B` is the block 
+    /// matrix whose block in row `i` and column `j` is `A[i][j] * B`. For two 
+    /// `2x2` matrices this produces a `4x4` matrix
+    /// ```text
+    /// kronecker(A, B) = [ a00 * B  a01 * B ]
+    ///                   [ a10 * B  a11 * B ]
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1_i32, 0_i32,
+    ///     0_i32, 2_i32
+    /// );
+    /// let other = Matrix2x2::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     1_i32, 2_i32, 0_i32, 0_i32,
+    ///     3_i32, 4_i32, 0_i32, 0_i32,
+    ///     0_i32, 0_i32, 2_i32, 4_i32,
+    ///     0_i32, 0_i32, 6_i32, 8_i32
+    /// );
+    /// let result = matrix.kronecker(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn kronecker(&self, other: &Matrix2x2<S>) -> Matrix4x4<S> {
+        let a = &self.data;
+        let b = &other.data;
+
+        Matrix4x4::new(
+            a[0][0] * b[0][0], a[0][0] * b[0][1], a[0][1] * b[0][0], a[0][1] * b[0][1],
+            a[0][0] * b[1][0], a[0][0] * b[1][1], a[0][1] * b[1][0], a[0][1] * b[1][1],
+            a[1][0] * b[0][0], a[1][0] * b[0][1], a[1][1] * b[0][0], a[1][1] * b[0][1],
+            a[1][0] * b[1][0], a[1][0] * b[1][1], a[1][1] * b[1][0], a[1][1] * b[1][1]
+        )
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -1094,6 +1362,83 @@ where
     pub fn trace(&self) -> S {
         self.data[0][0] + self.data[1][1]
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32
+    /// );
+    /// let other = Matrix2x2::new(
+    ///     2_i32, 3_i32,
+    ///     4_i32, 2_i32
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///     2_i32, 6_i32,
+    ///     12_i32, 8_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix2x2::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     2_f64, 6_f64,
+    ///     12_f64, 8_f64
+    /// );
+    /// let other = Matrix2x2::new(
+    ///     2_f64, 3_f64,
+    ///     4_f64, 2_f64
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///     1_f64, 2_f64,
+    ///     3_f64, 4_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix2x2::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1]
+        )
+    }
 }
 
 impl<S> Matrix2x2<S> 
@@ -1246,6 +1591,34 @@ where
         )
     }
 
+    /// Compute the rotation angle of a two-dimensional rotation matrix.
+    ///
+    /// This is the inverse of [`Matrix2x2::from_angle`], assuming the matrix is 
+    /// a pure rotation. The angle is read from the first column of the matrix, 
+    /// and lies in the interval `(-pi, pi]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// #     Radians,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let angle = Radians(2_f64);
+    /// let matrix = Matrix2x2::from_angle(angle);
+    /// let result = matrix.to_angle();
+    ///
+    /// assert!(relative_eq!(result, angle, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn to_angle(&self) -> Radians<S> {
+        Radians::atan2(self.data[0][1], self.data[0][0])
+    }
+
     /// Construct a rotation matrix that rotates the shortest angular distance 
     /// between two vectors.
     ///
@@ -1392,32 +1765,62 @@ where
         self.data[1][0].is_finite() && self.data[1][1].is_finite()
     }
 
-    /// Compute the inverse of a square matrix, if the inverse exists. 
-    ///
-    /// Given a square matrix `self` Compute the matrix `m` if it exists 
-    /// such that
-    /// ```text
-    /// m * self = self * m = 1.
-    /// ```
-    /// Not every square matrix has an inverse.
+    /// Returns `true` if any element of a matrix is `NaN`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix2x2,  
-    /// # };
-    /// # use approx::{
-    /// #     relative_eq, 
+    /// #     Matrix2x2, 
     /// # };
     /// #
     /// let matrix = Matrix2x2::new(
-    ///     2_f64, 3_f64,
-    ///     1_f64, 5_f64 
-    /// );
-    /// let expected = Matrix2x2::new(
-    ///      5_f64 / 7_f64, -3_f64 / 7_f64,
-    ///     -1_f64 / 7_f64,  2_f64 / 7_f64
+    ///     1_f64, f64::NAN,
+    ///     3_f64, 4_f64
+    /// );
+    ///
+    /// assert!(matrix.is_nan());
+    ///
+    /// let matrix = Matrix2x2::new(
+    ///     1_f64, f64::INFINITY,
+    ///     3_f64, 4_f64
+    /// );
+    ///
+    /// assert!(!matrix.is_nan());
+    /// assert!(!matrix.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0][0].is_nan() || self.data[0][1].is_nan() ||
+        self.data[1][0].is_nan() || self.data[1][1].is_nan()
+    }
+
+    /// Compute the inverse of a square matrix, if the inverse exists. 
+    ///
+    /// Given a square matrix `self` Compute the matrix `m` if it exists 
+    /// such that
+    /// ```text
+    /// m * self = self * m = 1.
+    /// ```
+    /// Not every square matrix has an inverse.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,  
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     2_f64, 3_f64,
+    ///     1_f64, 5_f64 
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///      5_f64 / 7_f64, -3_f64 / 7_f64,
+    ///     -1_f64 / 7_f64,  2_f64 / 7_f64
     /// );
     /// let result = matrix.inverse().unwrap();
     ///
@@ -1462,6 +1865,63 @@ where
         ulps_ne!(self.determinant(), S::zero())
     }
 
+    /// Determine whether a square matrix has an inverse matrix that can be 
+    /// computed reliably, within a tolerance `epsilon`.
+    ///
+    /// A matrix is treated as invertible when the absolute value of its 
+    /// determinant exceeds `epsilon`. This rejects nearly singular matrices 
+    /// whose inverses would be dominated by floating point error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2, 
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1e-10_f64, 0_f64,
+    ///     0_f64,     1e-10_f64
+    /// );
+    ///
+    /// assert!(matrix.is_invertible_eps(1e-30_f64));
+    /// assert!(!matrix.is_invertible_eps(1e-12_f64));
+    /// ```
+    #[inline]
+    pub fn is_invertible_eps(&self, epsilon: S) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
+    /// Compute the inverse of a square matrix, if the absolute value of its 
+    /// determinant exceeds `epsilon`.
+    ///
+    /// The function returns `None` for matrices that are singular or nearly 
+    /// singular with respect to the tolerance `epsilon`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2, 
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1e-10_f64, 0_f64,
+    ///     0_f64,     1e-10_f64
+    /// );
+    ///
+    /// assert!(matrix.inverse().is_some());
+    /// assert!(matrix.inverse_eps(1e-12_f64).is_none());
+    /// ```
+    #[inline]
+    pub fn inverse_eps(&self, epsilon: S) -> Option<Self> {
+        if self.is_invertible_eps(epsilon) {
+            self.inverse()
+        } else {
+            None
+        }
+    }
+
     /// Determine whether a square matrix is a diagonal matrix. 
     ///
     /// A square matrix is a diagonal matrix if every off-diagonal 
@@ -1512,12 +1972,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            formatter, 
-            "Matrix2x2 [[{}, {}], [{}, {}]]", 
-            self.data[0][0], self.data[1][0],
-            self.data[0][1], self.data[1][1],
-        )
+        fmt_matrix(formatter, "Matrix2x2", &self.data)
     }
 }
 
@@ -1777,6 +2232,73 @@ where
         <Self as AsRef<[S; 9]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 9]>>::as_mut(self)
+    }
+
+    /// Construct a matrix from the first 9 elements of a slice in 
+    /// column-major order.
+    ///
+    /// Elements of the slice past the first 9 are ignored.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the slice has fewer than 9 elements.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let data = [
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64
+    /// ];
+    /// let matrix = Matrix3x3::from_cols_slice(&data);
+    ///
+    /// assert_eq!(matrix.c0r1, 2_f64);
+    /// assert_eq!(matrix.c1r0, 4_f64);
+    /// assert_eq!(matrix.to_cols_array(), data);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_cols_slice(data: &[S]) -> Matrix3x3<S> {
+        assert!(
+            data.len() >= 9, 
+            "Slice has too few elements for a Matrix3x3: expected at least 9, got {}", 
+            data.len()
+        );
+
+        Matrix3x3::new(
+            data[0], data[1], data[2],
+            data[3], data[4], data[5],
+            data[6], data[7], data[8]
+        )
+    }
+
+    /// Copy the elements of a matrix into an array in column-major order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::from_fill(1_i32);
+    ///
+    /// assert_eq!(matrix.to_cols_array(), [1_i32; 9]);
+    /// ```
+    #[inline]
+    pub fn to_cols_array(&self) -> [S; 9] {
+        *<Self as AsRef<[S; 9]>>::as_ref(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[rustfmt::skip]
     #[inline]
@@ -1790,6 +2312,38 @@ where
         )
     }
 
+    /// Construct a matrix from an array of column vectors.
+    ///
+    /// This is equivalent to calling `from_columns` with the elements of the
+    /// array in order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let columns = [
+    ///     Vector3::new(1_i32, 2_i32, 3_i32),
+    ///     Vector3::new(4_i32, 5_i32, 6_i32),
+    ///     Vector3::new(7_i32, 8_i32, 9_i32),
+    /// ];
+    /// let expected = Matrix3x3::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32,
+    /// );
+    /// let result = Matrix3x3::from_column_array(columns);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_column_array(columns: [Vector3<S>; 3]) -> Matrix3x3<S> {
+        Matrix3x3::from_columns(columns[0], columns[1], columns[2])
+    }
+
     /// Map an operation on the elements of a matrix, returning a matrix whose 
     /// elements are elements of the new underlying type.
     ///
@@ -1964,6 +2518,57 @@ where
             distance.x, distance.y, one
         )
     }
+
+    /// Construct a two-dimensional affine matrix from a linear transformation
+    /// and a translation.
+    ///
+    /// The upper left 2x2 block of the resulting matrix is `linear`, the last 
+    /// column holds `translation`, and the last row is `[0, 0, 1]`. Applied to 
+    /// a homogeneous point, the matrix first applies the linear transformation
+    /// and then translates the result.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x2,
+    /// #     Matrix3x3,
+    /// #     Vector2,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let linear = Matrix2x2::new(
+    ///     2_i32, 0_i32,
+    ///     0_i32, 3_i32,
+    /// );
+    /// let translation = Vector2::new(5_i32, -1_i32);
+    /// let matrix = Matrix3x3::from_affine(&linear, &translation);
+    /// let expected = Matrix3x3::new(
+    ///     2_i32,  0_i32, 0_i32,
+    ///     0_i32,  3_i32, 0_i32,
+    ///     5_i32, -1_i32, 1_i32,
+    /// );
+    ///
+    /// assert_eq!(matrix, expected);
+    ///
+    /// let point = Vector3::new(1_i32, 1_i32, 1_i32);
+    /// let expected = Vector3::new(7_i32, 2_i32, 1_i32);
+    /// let result = matrix * point;
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_affine(linear: &Matrix2x2<S>, translation: &Vector2<S>) -> Matrix3x3<S> {
+        let one = S::one();
+        let zero = S::zero();
+
+        Matrix3x3::new(
+            linear.c0r0,   linear.c0r1,   zero,
+            linear.c1r0,   linear.c1r1,   zero,
+            translation.x, translation.y, one
+        )
+    }
     
     /// Construct a three-dimensional uniform scaling matrix.
     ///
@@ -2446,6 +3051,39 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     14_i32, 32_i32, 50_i32,
+    ///     32_i32, 77_i32, 122_i32,
+    ///     50_i32, 122_i32, 194_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix3x3<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -2663,6 +3301,91 @@ where
     pub fn trace(&self) -> S {
         self.data[0][0] + self.data[1][1] + self.data[2][2]
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32
+    /// );
+    /// let other = Matrix3x3::new(
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     2_i32, 6_i32, 12_i32,
+    ///     8_i32, 15_i32, 24_i32,
+    ///     14_i32, 24_i32, 36_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix3x3::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1], self.data[2][2] * other.data[2][2]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     2_f64, 6_f64, 12_f64,
+    ///     8_f64, 15_f64, 24_f64,
+    ///     14_f64, 24_f64, 36_f64
+    /// );
+    /// let other = Matrix3x3::new(
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix3x3::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1], self.data[2][2] / other.data[2][2]
+        )
+    }
 }
 
 impl<S> Matrix3x3<S> 
@@ -2934,33 +3657,75 @@ where
         self.data[2][0] * self.data[0][1] * self.data[1][2] - 
         self.data[2][0] * self.data[0][2] * self.data[1][1]
     }
-}
 
-impl<S> Matrix3x3<S> 
-where 
-    S: ScalarFloat
-{
-    /// Construct an affine rotation matrix in two dimensions that rotates a 
-    /// vector in the **xy-plane** by an angle `angle`.
+    /// Compute the coefficients of the characteristic polynomial of a matrix.
     ///
-    /// This is the affine matrix counterpart to the 2x2 matrix function 
-    /// `from_angle`.
+    /// The characteristic polynomial of a matrix `A` is 
+    /// ```text
+    /// p(lambda) == det(A - lambda * I) 
+    ///           == c0 + c1 * lambda + c2 * lambda^2 + c3 * lambda^3
+    /// ```
+    /// and the function returns its coefficients as `[c0, c1, c2, c3]`. For a 
+    /// three-by-three matrix these are `c0 == det(A)`, `c1 == -m`, 
+    /// `c2 == trace(A)`, and `c3 == -1`, where `m` is the sum of the 
+    /// principal two-by-two minors of `A`. The roots of the characteristic 
+    /// polynomial are the eigenvalues of the matrix.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix3x3,
-    /// #     Angle,
-    /// #     Radians,
-    /// #     Vector3, 
-    /// # };
-    /// # use approx::{
-    /// #     relative_eq, 
+    /// #     Matrix3x3, 
     /// # };
     /// #
-    /// let angle: Radians<f64> = Radians::full_turn_div_4();
-    /// let matrix = Matrix3x3::from_affine_angle(angle);
+    /// let matrix = Matrix3x3::new(
+    ///     2_i32, 0_i32, 0_i32,
+    ///     1_i32, 3_i32, 0_i32,
+    ///     4_i32, 5_i32, 6_i32
+    /// );
+    /// // The eigenvalues of a triangular matrix are its diagonal elements, 
+    /// // so p(lambda) == (2 - lambda) * (3 - lambda) * (6 - lambda).
+    /// let expected = [36_i32, -36_i32, 11_i32, -1_i32];
+    /// let result = matrix.characteristic_polynomial();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn characteristic_polynomial(&self) -> [S; 4] {
+        let principal_minors = 
+            (self.data[0][0] * self.data[1][1] - self.data[1][0] * self.data[0][1]) +
+            (self.data[0][0] * self.data[2][2] - self.data[2][0] * self.data[0][2]) +
+            (self.data[1][1] * self.data[2][2] - self.data[2][1] * self.data[1][2]);
+
+        [self.determinant(), -principal_minors, self.trace(), -S::one()]
+    }
+}
+
+impl<S> Matrix3x3<S> 
+where 
+    S: ScalarFloat
+{
+    /// Construct an affine rotation matrix in two dimensions that rotates a 
+    /// vector in the **xy-plane** by an angle `angle`.
+    ///
+    /// This is the affine matrix counterpart to the 2x2 matrix function 
+    /// `from_angle`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Angle,
+    /// #     Radians,
+    /// #     Vector3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let angle: Radians<f64> = Radians::full_turn_div_4();
+    /// let matrix = Matrix3x3::from_affine_angle(angle);
     /// let unit_x = Vector3::unit_x();
     /// let expected = Vector3::unit_y();
     /// let result = matrix * unit_x;
@@ -3139,6 +3904,122 @@ where
         )
     }
 
+    /// Spherically interpolate between two rotation matrices.
+    ///
+    /// The function computes the rotation taking `self` to `other`, scales 
+    /// its angle by `amount`, and applies the result to `self`. Unlike a 
+    /// component-wise linear interpolation, every intermediate matrix is a 
+    /// rotation matrix, and the interpolation moves at a constant angular 
+    /// speed along the shortest path between the two orientations. Both 
+    /// matrices are assumed to be rotation matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle, 
+    /// #     Matrix3x3,
+    /// #     Radians,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let matrix1 = Matrix3x3::from_angle_z(Radians(0_f64));
+    /// let matrix2 = Matrix3x3::from_angle_z(Radians::full_turn_div_2());
+    /// let expected = Matrix3x3::from_angle_z(Radians::full_turn_div_4());
+    /// let result = matrix1.slerp(&matrix2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: &Matrix3x3<S>, amount: S) -> Matrix3x3<S> {
+        let difference = self.transpose() * other;
+        let (axis, angle) = difference.to_axis_angle();
+
+        self * Matrix3x3::from_axis_angle(&axis, angle * amount)
+    }
+
+    /// Compute the axis and angle of a rotation matrix.
+    ///
+    /// This is the inverse of [`Matrix3x3::from_axis_angle`]. The matrix is 
+    /// assumed to be a proper rotation matrix, i.e. orthogonal with determinant 
+    /// one; the result is meaningless for any other matrix. The returned angle 
+    /// lies in the interval `[0, pi]`.
+    ///
+    /// The axis is read off from the antisymmetric part of the matrix, whose 
+    /// magnitude is proportional to the sine of the angle. That estimate 
+    /// loses precision as the angle approaches `pi`, so for angles larger than 
+    /// `pi / 2` the axis is recovered from the symmetric part of the matrix 
+    /// instead, with the antisymmetric part only fixing its sign. When the 
+    /// angle is zero every axis is valid, and the function returns the 
+    /// **x-axis**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Radians,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let angle = Radians(2_f64);
+    /// let matrix = Matrix3x3::from_axis_angle(&axis, angle);
+    /// let (result_axis, result_angle) = matrix.to_axis_angle();
+    ///
+    /// assert!(relative_eq!(result_axis.into_inner(), axis.into_inner(), epsilon = 1e-10));
+    /// assert!(relative_eq!(result_angle, angle, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn to_axis_angle(&self) -> (Unit<Vector3<S>>, Radians<S>) {
+        let one = S::one();
+        let two = one + one;
+        let antisymmetric = Vector3::new(
+            self.c1r2 - self.c2r1,
+            self.c2r0 - self.c0r2,
+            self.c0r1 - self.c1r0
+        );
+        let sin_angle = antisymmetric.magnitude() / two;
+        let cos_angle = (self.trace() - one) / two;
+        let angle = Radians::atan2(sin_angle, cos_angle);
+
+        if cos_angle >= S::zero() {
+            let axis = Unit::try_from_value(antisymmetric, S::default_epsilon())
+                .unwrap_or_else(|| Unit::from_value_unchecked(Vector3::unit_x()));
+
+            return (axis, angle);
+        }
+
+        // The symmetric part of the matrix is 
+        // `cos(angle) * I + (1 - cos(angle)) * axis * axis^T`. Recover the axis 
+        // from its largest diagonal element, which is well conditioned because 
+        // `1 - cos(angle) > 1` here.
+        let one_minus_cos_angle = one - cos_angle;
+        let diagonal = Vector3::new(self.c0r0, self.c1r1, self.c2r2);
+        let axis = if diagonal.x >= diagonal.y && diagonal.x >= diagonal.z {
+            let x = ((diagonal.x - cos_angle) / one_minus_cos_angle).sqrt();
+            let scale = two * one_minus_cos_angle * x;
+            Vector3::new(x, (self.c0r1 + self.c1r0) / scale, (self.c0r2 + self.c2r0) / scale)
+        } else if diagonal.y >= diagonal.z {
+            let y = ((diagonal.y - cos_angle) / one_minus_cos_angle).sqrt();
+            let scale = two * one_minus_cos_angle * y;
+            Vector3::new((self.c0r1 + self.c1r0) / scale, y, (self.c1r2 + self.c2r1) / scale)
+        } else {
+            let z = ((diagonal.z - cos_angle) / one_minus_cos_angle).sqrt();
+            let scale = two * one_minus_cos_angle * z;
+            Vector3::new((self.c0r2 + self.c2r0) / scale, (self.c1r2 + self.c2r1) / scale, z)
+        };
+        let axis = if axis.dot(&antisymmetric) < S::zero() { -axis } else { axis };
+
+        (Unit::from_value(axis), angle)
+    }
+
     /// Construct a rotation matrix that transforms the coordinate system of
     /// an observer located at the origin facing the **positive z-axis** into a
     /// coordinate system of an observer located at the origin facing the 
@@ -3146,6 +4027,9 @@ where
     ///
     /// The function maps the **positive z-axis** to the direction `direction`.
     ///
+    /// When `up` is parallel to `direction`, a substitute up direction is 
+    /// chosen so that the result is still a rotation matrix.
+    ///
     /// ## Example
     ///
     /// ```
@@ -3177,7 +4061,7 @@ where
     #[inline]
     pub fn face_towards(direction: &Vector3<S>, up: &Vector3<S>) -> Matrix3x3<S> {
         let z_axis = direction.normalize();
-        let x_axis = up.cross(&z_axis).normalize();
+        let x_axis = Self::horizontal_axis(up, &z_axis);
         let y_axis = z_axis.cross(&x_axis).normalize();
 
         Matrix3x3::new(
@@ -3187,6 +4071,28 @@ where
         )
     }
 
+    /// Compute the unit horizontal axis of an observer's coordinate system
+    /// from the `up` direction and the unit viewing axis `z_axis`.
+    ///
+    /// When `up` is parallel to `z_axis`, their cross product vanishes and does 
+    /// not determine a horizontal axis. In that case we substitute the 
+    /// coordinate axis that `z_axis` is least aligned with for `up`, so the 
+    /// resulting coordinate system remains orthonormal.
+    #[inline]
+    fn horizontal_axis(up: &Vector3<S>, z_axis: &Vector3<S>) -> Vector3<S> {
+        if let Some(x_axis) = up.cross(z_axis).try_normalize(S::default_epsilon()) {
+            return x_axis;
+        }
+
+        let fallback_up = if z_axis.x.abs() < z_axis.y.abs() {
+            Vector3::unit_x()
+        } else {
+            Vector3::unit_y()
+        };
+
+        fallback_up.cross(z_axis).normalize()
+    }
+
     /// Construct a coordinate transformation matrix that transforms
     /// a coordinate system of an observer located at the origin facing 
     /// the direction `direction` into the coordinate system of an observer located
@@ -3196,6 +4102,9 @@ where
     /// the new the coordinate system. This corresponds to a rotation matrix.
     /// This transformation is a **right-handed** coordinate transformation.
     ///
+    /// When `up` is parallel to `direction`, a substitute up direction is 
+    /// chosen so that the result is still a rotation matrix.
+    ///
     /// ## Example
     ///
     /// ```
@@ -3233,6 +4142,9 @@ where
     /// The function maps the direction `direction` to the **positive z-axis** in 
     /// the new the coordinate system. This corresponds to a rotation matrix.
     /// This transformation is a **left-handed** coordinate transformation. 
+    ///
+    /// When `up` is parallel to `direction`, a substitute up direction is 
+    /// chosen so that the result is still a rotation matrix.
     #[inline]
     pub fn look_at_lh(direction: &Vector3<S>, up: &Vector3<S>) -> Matrix3x3<S> {
         // The inverse of a rotation matrix is its transpose.
@@ -3242,6 +4154,12 @@ where
     /// Construct a rotation matrix that rotates the shortest angular distance 
     /// between two vectors.
     ///
+    /// When the two vectors point in opposite directions, there is no unique 
+    /// shortest rotation between them, and the function returns a rotation by 
+    /// pi radians about an axis perpendicular to `v1`. If either vector is 
+    /// zero, the function returns the identity matrix. The function always 
+    /// returns `Some`.
+    ///
     /// ## Example
     ///
     /// ```
@@ -3269,24 +4187,22 @@ where
             v1.try_normalize(S::zero()), 
             v2.try_normalize(S::zero()))
          {
-            let cross = unit_v1.cross(&unit_v2);
-
-            if let Some(axis) = Unit::try_from_value(cross, S::default_epsilon()) {
-                return Some(
-                    Matrix3x3::from_axis_angle(&axis, Radians::acos(unit_v1.dot(&unit_v2)))
-                );
-            }
-
-            if unit_v1.dot(&unit_v2) < S::zero() {
-                return None;
-            }
+            return Self::rotation_between_axis(
+                &Unit::from_value_unchecked(unit_v1), 
+                &Unit::from_value_unchecked(unit_v2)
+            );
         }
 
         Some(Self::identity())
     }
 
     /// Construct a rotation matrix that rotates the shortest angular distance 
-    /// between two vectors.
+    /// between two unit vectors.
+    ///
+    /// When the two vectors point in opposite directions, there is no unique 
+    /// shortest rotation between them, and the function returns a rotation by 
+    /// pi radians about an axis perpendicular to `unit_v1`. The function 
+    /// always returns `Some`.
     ///
     /// ## Example
     ///
@@ -3325,7 +4241,22 @@ where
         }
 
         if cos_angle < S::zero() {
-            return None;
+            // The vectors point in opposite directions, so the cross product 
+            // does not determine a rotation axis. Every axis perpendicular to 
+            // `unit_v1` rotates it onto `unit_v2` by an angle of pi radians, so 
+            // we choose the one perpendicular to the coordinate axis that 
+            // `unit_v1` is least aligned with.
+            let v1 = unit_v1.as_ref();
+            let coordinate_axis = if v1.x.abs() < v1.y.abs() { 
+                Vector3::unit_x() 
+            } else { 
+                Vector3::unit_y() 
+            };
+            let axis = Unit::from_value(v1.cross(&coordinate_axis));
+
+            return Some(
+                Matrix3x3::from_axis_angle(&axis, Radians::full_turn_div_2())
+            );
         }
 
         Some(Self::identity())
@@ -3384,6 +4315,45 @@ where
         self.data[2][2].is_finite()
     }
 
+    /// Returns `true` if any element of a matrix is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3, 
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, f64::NAN, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64
+    /// );
+    ///
+    /// assert!(matrix.is_nan());
+    ///
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, f64::INFINITY, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64
+    /// );
+    ///
+    /// assert!(!matrix.is_nan());
+    /// assert!(!matrix.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0][0].is_nan() || 
+        self.data[0][1].is_nan() || 
+        self.data[0][2].is_nan() ||
+        self.data[1][0].is_nan() || 
+        self.data[1][1].is_nan() || 
+        self.data[1][2].is_nan() ||
+        self.data[2][0].is_nan() || 
+        self.data[2][1].is_nan() || 
+        self.data[2][2].is_nan()
+    }
+
     /// Compute the inverse of a square matrix, if the inverse exists. 
     ///
     /// Given a square matrix `self` Compute the matrix `m` if it exists 
@@ -3466,6 +4436,104 @@ where
     pub fn is_invertible(&self) -> bool {
         ulps_ne!(self.determinant(), S::zero())
     }
+
+    /// Determine whether a square matrix has an inverse matrix that can be 
+    /// computed reliably, within a tolerance `epsilon`.
+    ///
+    /// A matrix is treated as invertible when the absolute value of its 
+    /// determinant exceeds `epsilon`. This rejects nearly singular matrices 
+    /// whose inverses would be dominated by floating point error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3, 
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1e-7_f64, 0_f64,    0_f64,
+    ///     0_f64,    1e-7_f64, 0_f64,
+    ///     0_f64,    0_f64,    1e-6_f64
+    /// );
+    ///
+    /// assert!(matrix.is_invertible_eps(1e-30_f64));
+    /// assert!(!matrix.is_invertible_eps(1e-12_f64));
+    /// ```
+    #[inline]
+    pub fn is_invertible_eps(&self, epsilon: S) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
+    /// Compute the inverse of a square matrix, if the absolute value of its 
+    /// determinant exceeds `epsilon`.
+    ///
+    /// The function returns `None` for matrices that are singular or nearly 
+    /// singular with respect to the tolerance `epsilon`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3, 
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1e-7_f64, 0_f64,    0_f64,
+    ///     0_f64,    1e-7_f64, 0_f64,
+    ///     0_f64,    0_f64,    1e-6_f64
+    /// );
+    ///
+    /// assert!(matrix.inverse().is_some());
+    /// assert!(matrix.inverse_eps(1e-12_f64).is_none());
+    /// ```
+    #[inline]
+    pub fn inverse_eps(&self, epsilon: S) -> Option<Self> {
+        if self.is_invertible_eps(epsilon) {
+            self.inverse()
+        } else {
+            None
+        }
+    }
+
+    /// Estimate the condition number of a square matrix.
+    ///
+    /// The estimate is the product of the Frobenius norms of the matrix and 
+    /// its inverse. It bounds the exact condition number with respect to the 
+    /// matrix 2-norm from above, and it is at least `3` for every invertible 
+    /// matrix, with equality for multiples of orthogonal matrices. Large 
+    /// values warn that inverting the matrix loses precision. The function 
+    /// returns infinity for singular matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let identity: Matrix3x3<f64> = Matrix3x3::identity();
+    ///
+    /// assert!(relative_eq!(identity.condition_number(), 3_f64, epsilon = 1e-10));
+    ///
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 0_f64, 0_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    ///     0_f64, 0_f64, 1e-8_f64,
+    /// );
+    ///
+    /// assert!(matrix.condition_number() > 1e8_f64);
+    /// ```
+    #[inline]
+    pub fn condition_number(&self) -> S {
+        match self.inverse() {
+            Some(inverse) => frobenius_norm(self.as_slice()) * frobenius_norm(inverse.as_slice()),
+            None => S::infinity(),
+        }
+    }
     
     /// Determine whether a square matrix is a diagonal matrix. 
     ///
@@ -3532,6 +4600,66 @@ where
     pub fn lerp(&self, other: &Matrix3x3<S>, amount: S) -> Matrix3x3<S> {
         self + ((other - self) * amount)
     }
+
+    /// Orthonormalize the columns of a matrix with the Gram-Schmidt process.
+    ///
+    /// The first column of the result is the normalized first column of 
+    /// `self`. Each following column is the corresponding column of `self` 
+    /// with its components along the previous columns removed, and then 
+    /// normalized. The function returns `None` when the columns are linearly 
+    /// dependent. A column counts as dependent on the previous ones when what 
+    /// remains of it after removing their components is no longer than 
+    /// `sqrt(epsilon)` times its original length, so the test does not 
+    /// depend on the scale of the matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     1_f64, 3_f64, 0_f64,
+    ///     1_f64, 1_f64, 4_f64
+    /// );
+    /// let result = matrix.orthonormalize().unwrap();
+    ///
+    /// assert!(relative_eq!(result, Matrix3x3::identity(), epsilon = 1e-10));
+    ///
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     1_f64, 2_f64, 3_f64,
+    ///     0_f64, 0_f64, 1_f64
+    /// );
+    ///
+    /// assert!(matrix.orthonormalize().is_none());
+    /// ```
+    #[inline]
+    pub fn orthonormalize(&self) -> Option<Matrix3x3<S>> {
+        // The residual of a dependent column is rounding error, which is 
+        // proportional to the length of the column, so the tolerance has to 
+        // scale with it.
+        let tolerance = S::default_epsilon().sqrt();
+        let column0 = self.column(0);
+        let u0 = Unit::try_from_value(column0, tolerance * column0.magnitude())?.into_inner();
+        let column1 = self.column(1);
+        let residual1 = column1 - u0 * u0.dot(&column1);
+        let u1 = Unit::try_from_value(residual1, tolerance * column1.magnitude())?.into_inner();
+        // Remove the components one at a time, in the manner of the modified 
+        // Gram-Schmidt process, which is more stable in floating point 
+        // arithmetic than removing them all at once.
+        let column2 = self.column(2);
+        let mut residual2 = column2 - u0 * u0.dot(&column2);
+        residual2 -= u1 * u1.dot(&residual2);
+        let u2 = Unit::try_from_value(residual2, tolerance * column2.magnitude())?.into_inner();
+
+        Some(Matrix3x3::from_columns(u0, u1, u2))
+    }
 }
 
 impl<S> fmt::Display for Matrix3x3<S> 
@@ -3539,13 +4667,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            formatter, 
-            "Matrix3x3 [[{}, {}, {}], [{}, {}, {}], [{}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0],
-            self.data[0][1], self.data[1][1], self.data[2][1],
-            self.data[0][2], self.data[1][2], self.data[2][2],
-        )
+        fmt_matrix(formatter, "Matrix3x3", &self.data)
     }
 }
 
@@ -3871,52 +4993,193 @@ where
         <Self as AsRef<[S; 16]>>::as_ref(self)
     }
 
-    /// Construct a matrix from a set of column vectors.
-    #[rustfmt::skip]
+    /// Get a mutable slice of the underlying elements of the data type.
     #[inline]
-    pub fn from_columns(
-        c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>, c3: Vector4<S>) -> Matrix4x4<S> 
-    {
-        Matrix4x4::new(
-            c0[0], c0[1], c0[2], c0[3],
-            c1[0], c1[1], c1[2], c1[3],
-            c2[0], c2[1], c2[2], c2[3],
-            c3[0], c3[1], c3[2], c3[3],
-        )
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 16]>>::as_mut(self)
     }
 
-    /// Map an operation on the elements of a matrix, returning a matrix whose 
-    /// elements are elements of the new underlying type.
+    /// Construct a matrix from the first 16 elements of a slice in 
+    /// column-major order.
+    ///
+    /// Elements of the slice past the first 16 are ignored.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the slice has fewer than 16 elements.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix4x4, 
+    /// #     Matrix4x4,
     /// # };
     /// #
-    /// let matrix = Matrix4x4::new(
-    ///     1_u32,  2_u32,  3_u32,  4_u32, 
-    ///     5_u32,  6_u32,  7_u32,  8_u32,
-    ///     9_u32,  10_u32, 11_u32, 12_u32,
-    ///     13_u32, 14_u32, 15_u32, 16_u32
-    /// );
-    /// let expected = Matrix4x4::new(
-    ///     2_i32,  4_i32,  6_i32,  8_i32,
-    ///     10_i32, 12_i32, 14_i32, 16_i32,
-    ///     18_i32, 20_i32, 22_i32, 24_i32,
-    ///     26_i32, 28_i32, 30_i32, 32_i32
-    /// );
-    /// let result = matrix.map(|comp| (2 * comp) as i32);
+    /// let data = [
+    ///     1_f64, 2_f64, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64,
+    ///     13_f64, 14_f64, 15_f64, 16_f64
+    /// ];
+    /// let matrix = Matrix4x4::from_cols_slice(&data);
     ///
-    /// assert_eq!(result, expected);
+    /// assert_eq!(matrix.c0r1, 2_f64);
+    /// assert_eq!(matrix.c1r0, 5_f64);
+    /// assert_eq!(matrix.to_cols_array(), data);
     /// ```
     #[rustfmt::skip]
     #[inline]
-    pub fn map<T, F>(self, mut op: F) -> Matrix4x4<T> 
-    where 
-        F: FnMut(S) -> T
-    {
+    pub fn from_cols_slice(data: &[S]) -> Matrix4x4<S> {
+        assert!(
+            data.len() >= 16, 
+            "Slice has too few elements for a Matrix4x4: expected at least 16, got {}", 
+            data.len()
+        );
+
+        Matrix4x4::new(
+            data[0], data[1], data[2], data[3],
+            data[4], data[5], data[6], data[7],
+            data[8], data[9], data[10], data[11],
+            data[12], data[13], data[14], data[15]
+        )
+    }
+
+    /// Copy the elements of a matrix into an array in column-major order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::from_fill(1_i32);
+    ///
+    /// assert_eq!(matrix.to_cols_array(), [1_i32; 16]);
+    /// ```
+    #[inline]
+    pub fn to_cols_array(&self) -> [S; 16] {
+        *<Self as AsRef<[S; 16]>>::as_ref(self)
+    }
+
+    /// Construct a matrix from a set of column vectors.
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_columns(
+        c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>, c3: Vector4<S>) -> Matrix4x4<S> 
+    {
+        Matrix4x4::new(
+            c0[0], c0[1], c0[2], c0[3],
+            c1[0], c1[1], c1[2], c1[3],
+            c2[0], c2[1], c2[2], c2[3],
+            c3[0], c3[1], c3[2], c3[3],
+        )
+    }
+
+    /// Construct a matrix from an array of column vectors.
+    ///
+    /// This is equivalent to calling `from_columns` with the elements of the
+    /// array in order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let columns = [
+    ///     Vector4::new(1_i32,  2_i32,  3_i32,  4_i32),
+    ///     Vector4::new(5_i32,  6_i32,  7_i32,  8_i32),
+    ///     Vector4::new(9_i32,  10_i32, 11_i32, 12_i32),
+    ///     Vector4::new(13_i32, 14_i32, 15_i32, 16_i32),
+    /// ];
+    /// let expected = Matrix4x4::new(
+    ///     1_i32,  2_i32,  3_i32,  4_i32,
+    ///     5_i32,  6_i32,  7_i32,  8_i32,
+    ///     9_i32,  10_i32, 11_i32, 12_i32,
+    ///     13_i32, 14_i32, 15_i32, 16_i32,
+    /// );
+    /// let result = Matrix4x4::from_column_array(columns);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_column_array(columns: [Vector4<S>; 4]) -> Matrix4x4<S> {
+        Matrix4x4::from_columns(columns[0], columns[1], columns[2], columns[3])
+    }
+
+    /// Extract the upper left 3x3 block of a matrix.
+    ///
+    /// For an affine matrix this is its linear part, for example the rotation
+    /// part of a rigid transformation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_i32,  2_i32,  3_i32,  4_i32,
+    ///     5_i32,  6_i32,  7_i32,  8_i32,
+    ///     9_i32,  10_i32, 11_i32, 12_i32,
+    ///     13_i32, 14_i32, 15_i32, 16_i32,
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     1_i32, 2_i32,  3_i32,
+    ///     5_i32, 6_i32,  7_i32,
+    ///     9_i32, 10_i32, 11_i32,
+    /// );
+    /// let result = matrix.to_mat3();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_mat3(&self) -> Matrix3x3<S> {
+        Matrix3x3::new(
+            self.data[0][0], self.data[0][1], self.data[0][2],
+            self.data[1][0], self.data[1][1], self.data[1][2],
+            self.data[2][0], self.data[2][1], self.data[2][2],
+        )
+    }
+
+    /// Map an operation on the elements of a matrix, returning a matrix whose 
+    /// elements are elements of the new underlying type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_u32,  2_u32,  3_u32,  4_u32, 
+    ///     5_u32,  6_u32,  7_u32,  8_u32,
+    ///     9_u32,  10_u32, 11_u32, 12_u32,
+    ///     13_u32, 14_u32, 15_u32, 16_u32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     2_i32,  4_i32,  6_i32,  8_i32,
+    ///     10_i32, 12_i32, 14_i32, 16_i32,
+    ///     18_i32, 20_i32, 22_i32, 24_i32,
+    ///     26_i32, 28_i32, 30_i32, 32_i32
+    /// );
+    /// let result = matrix.map(|comp| (2 * comp) as i32);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn map<T, F>(self, mut op: F) -> Matrix4x4<T> 
+    where 
+        F: FnMut(S) -> T
+    {
         Matrix4x4::new(
             op(self.data[0][0]), 
             op(self.data[0][1]), 
@@ -4048,6 +5311,41 @@ impl<S> Matrix4x4<S>
 where 
     S: Scalar
 {
+    /// Construct a matrix from a 3x3 matrix by placing it in the upper left 
+    /// block of the identity matrix.
+    ///
+    /// This embeds a linear transformation such as a rotation matrix into an 
+    /// affine matrix that leaves the **w-component** unchanged. The function 
+    /// is the inverse of [`Matrix4x4::to_mat3`] on such matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32,
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     1_i32, 2_i32, 3_i32, 0_i32,
+    ///     4_i32, 5_i32, 6_i32, 0_i32,
+    ///     7_i32, 8_i32, 9_i32, 0_i32,
+    ///     0_i32, 0_i32, 0_i32, 1_i32,
+    /// );
+    /// let result = Matrix4x4::from_mat3(&matrix);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_mat3(matrix: &Matrix3x3<S>) -> Matrix4x4<S> {
+        Matrix4x4::from(matrix)
+    }
+
     /// Construct an affine translation matrix in three-dimensions.
     ///
     ///
@@ -4458,6 +5756,41 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32,
+    ///     13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     30_i32, 70_i32, 110_i32, 150_i32,
+    ///     70_i32, 174_i32, 278_i32, 382_i32,
+    ///     110_i32, 278_i32, 446_i32, 614_i32,
+    ///     150_i32, 382_i32, 614_i32, 846_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix4x4<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -4696,6 +6029,99 @@ where
     pub fn trace(&self) -> S {
         self.data[0][0] + self.data[1][1] + self.data[2][2] + self.data[3][3]
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32,
+    ///     13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let other = Matrix4x4::new(
+    ///     2_i32, 3_i32, 4_i32, 2_i32,
+    ///     3_i32, 4_i32, 2_i32, 3_i32,
+    ///     4_i32, 2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32, 2_i32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     2_i32, 6_i32, 12_i32, 8_i32,
+    ///     15_i32, 24_i32, 14_i32, 24_i32,
+    ///     36_i32, 20_i32, 33_i32, 48_i32,
+    ///     26_i32, 42_i32, 60_i32, 32_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix4x4::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2], self.data[0][3] * other.data[0][3],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2], self.data[1][3] * other.data[1][3],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1], self.data[2][2] * other.data[2][2], self.data[2][3] * other.data[2][3],
+            self.data[3][0] * other.data[3][0], self.data[3][1] * other.data[3][1], self.data[3][2] * other.data[3][2], self.data[3][3] * other.data[3][3]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     2_f64, 6_f64, 12_f64, 8_f64,
+    ///     15_f64, 24_f64, 14_f64, 24_f64,
+    ///     36_f64, 20_f64, 33_f64, 48_f64,
+    ///     26_f64, 42_f64, 60_f64, 32_f64
+    /// );
+    /// let other = Matrix4x4::new(
+    ///     2_f64, 3_f64, 4_f64, 2_f64,
+    ///     3_f64, 4_f64, 2_f64, 3_f64,
+    ///     4_f64, 2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64, 2_f64
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64,
+    ///     13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix4x4::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2], self.data[0][3] / other.data[0][3],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2], self.data[1][3] / other.data[1][3],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1], self.data[2][2] / other.data[2][2], self.data[2][3] / other.data[2][3],
+            self.data[3][0] / other.data[3][0], self.data[3][1] / other.data[3][1], self.data[3][2] / other.data[3][2], self.data[3][3] / other.data[3][3]
+        )
+    }
 }
 
 impl<S> Matrix4x4<S> 
@@ -5101,6 +6527,56 @@ where
         )
     }
 
+    /// Construct an affine matrix from a translation, a rotation, and a 
+    /// nonuniform scaling.
+    ///
+    /// The resulting matrix first scales, then rotates, then translates. Its 
+    /// upper left 3x3 block is `rotation * diag(scale)`, and its last column 
+    /// holds the translation. This is the same matrix as the product
+    /// `translation * rotation * scale` of the individual affine matrices, 
+    /// without computing the matrix products.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Matrix3x3,
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Radians,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let translation = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let rotation = Matrix3x3::from_angle_z(Radians::full_turn_div_4());
+    /// let scale = Vector3::new(2_f64, 3_f64, 4_f64);
+    /// let matrix = Matrix4x4::from_translation_rotation_scale(&translation, &rotation, &scale);
+    /// let point = Point3::new(1_f64, 1_f64, 1_f64);
+    /// let expected = Point3::new(-2_f64, 4_f64, 7_f64);
+    /// let result = matrix.transform_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_translation_rotation_scale(
+        translation: &Vector3<S>, rotation: &Matrix3x3<S>, scale: &Vector3<S>) -> Matrix4x4<S>
+    {
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix4x4::new(
+            rotation[0][0] * scale.x, rotation[0][1] * scale.x, rotation[0][2] * scale.x, zero,
+            rotation[1][0] * scale.y, rotation[1][1] * scale.y, rotation[1][2] * scale.y, zero,
+            rotation[2][0] * scale.z, rotation[2][1] * scale.z, rotation[2][2] * scale.z, zero,
+            translation.x,            translation.y,            translation.z,            one
+        )
+    }
+
     /// Construct a new three-dimensional orthographic projection matrix.
     ///
     /// ## Example
@@ -5150,37 +6626,90 @@ where
         )
     }
 
-    /// Construct a new three-dimensional orthographic projection matrix.
+    /// Construct a new three-dimensional orthographic projection matrix that 
+    /// maps depth values into the `[0, 1]` clip space range used by Vulkan, 
+    /// Metal, DirectX and WebGPU.
+    ///
+    /// The `near` plane maps to a depth of `0` and the `far` plane maps to a
+    /// depth of `1`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix4x4,
-    /// #     Degrees,
-    /// # };
-    /// # use approx::{
-    /// #     relative_eq, 
+    /// #     Matrix4x4, 
     /// # };
     /// #
-    /// let vfov = Degrees(90.0);
-    /// let aspect = 800 as f64 / 600 as f64;
+    /// let left = -4.0;
+    /// let right = 4.0;
+    /// let bottom = -2.0;
+    /// let top = 2.0;
     /// let near = 1.0;
     /// let far = 100.0;
     /// let expected = Matrix4x4::new(
-    ///     2.0 / 100.0, 0.0,         0.0,          0.0, 
-    ///     0.0,         2.0 / 75.0,  0.0,          0.0, 
-    ///     0.0,         0.0,        -2.0 / 99.0,   0.0, 
-    ///     0.0,         0.0,        -101.0 / 99.0, 1.0
+    ///     1.0 / 4.0,  0.0,        0.0,         0.0,
+    ///     0.0,        1.0 / 2.0,  0.0,         0.0,
+    ///     0.0,        0.0,       -1.0 / 99.0,  0.0,
+    ///     0.0,        0.0,       -1.0 / 99.0,  1.0
     /// );
-    /// let result = Matrix4x4::from_orthographic_fov(vfov, aspect, near, far);
+    /// let result = Matrix4x4::from_orthographic_wgpu(left, right, bottom, top, near, far);
     ///
-    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// assert_eq!(result, expected);
     /// ```
     #[rustfmt::skip]
     #[inline]
-    pub fn from_orthographic_fov<A: Into<Radians<S>>>(
-        vfov: A, aspect: S, near: S, far: S) -> Matrix4x4<S> 
+    pub fn from_orthographic_wgpu(
+        left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4x4<S> 
+    {
+        let zero = S::zero();
+        let one  = S::one();
+        let two = one + one;
+        let sx =  two / (right - left);
+        let sy =  two / (top - bottom);
+        let sz = -one / (far - near);
+        let tx = -(right + left) / (right - left);
+        let ty = -(top + bottom) / (top - bottom);
+        let tz = -near / (far - near);
+
+        Matrix4x4::new(
+            sx,   zero, zero, zero,
+            zero, sy,   zero, zero,
+            zero, zero, sz,   zero,
+            tx,   ty,   tz,   one
+        )
+    }
+
+    /// Construct a new three-dimensional orthographic projection matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(90.0);
+    /// let aspect = 800 as f64 / 600 as f64;
+    /// let near = 1.0;
+    /// let far = 100.0;
+    /// let expected = Matrix4x4::new(
+    ///     2.0 / 100.0, 0.0,         0.0,          0.0, 
+    ///     0.0,         2.0 / 75.0,  0.0,          0.0, 
+    ///     0.0,         0.0,        -2.0 / 99.0,   0.0, 
+    ///     0.0,         0.0,        -101.0 / 99.0, 1.0
+    /// );
+    /// let result = Matrix4x4::from_orthographic_fov(vfov, aspect, near, far);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_orthographic_fov<A: Into<Radians<S>>>(
+        vfov: A, aspect: S, near: S, far: S) -> Matrix4x4<S> 
     {
         let one_half = num_traits::cast(0.5).unwrap();
         let width = far * Angle::tan(vfov.into() * one_half);
@@ -5260,6 +6789,74 @@ where
         )
     }
 
+    /// Construct a new three-dimensional perspective projection matrix based
+    /// on arbitrary `left`, `right`, `bottom`, `top`, `near` and `far` planes
+    /// that maps depth values into the `[0, 1]` clip space range used by 
+    /// Vulkan, Metal, DirectX and WebGPU.
+    ///
+    /// The `near` plane maps to a depth of `0` and the `far` plane maps to a
+    /// depth of `1`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let left = -4.0;
+    /// let right = 4.0;
+    /// let bottom = -2.0;
+    /// let top = 3.0;
+    /// let near = 1.0;
+    /// let far = 100.0;
+    /// let expected = Matrix4x4::new(
+    ///     1.0 / 4.0,  0.0,        0.0,           0.0,
+    ///     0.0,        2.0 / 5.0,  0.0,           0.0,
+    ///     0.0,        1.0 / 5.0, -100.0 / 99.0, -1.0,
+    ///     0.0,        0.0,       -100.0 / 99.0,  0.0
+    /// );
+    /// let result = Matrix4x4::from_perspective_wgpu(left, right, bottom, top, near, far);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_wgpu(
+        left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4x4<S> 
+    {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+
+        let c0r0 = (two * near) / (right - left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = (two * near) / (top - bottom);
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = (right + left) / (right - left);
+        let c2r1 = (top + bottom) / (top - bottom);
+        let c2r2 = far / (near - far);
+        let c2r3 = -one;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = (far * near) / (near - far);
+        let c3r3 = zero;
+
+        Matrix4x4::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
     /// Construct a perspective projection matrix based on the `near` 
     /// plane, the `far` plane and the vertical field of view angle `vfov` and 
     /// the horizontal/vertical aspect ratio `aspect`.
@@ -5309,6 +6906,128 @@ where
         )
     }
 
+    /// Construct a new three-dimensional reversed-z perspective projection 
+    /// matrix based on arbitrary `left`, `right`, `bottom`, `top`, `near` and 
+    /// `far` planes.
+    ///
+    /// The matrix maps the `near` plane to a clip space depth of `1` and the 
+    /// `far` plane to a clip space depth of `0`, which distributes 
+    /// floating point depth precision more evenly over the view frustum.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let left = -4.0;
+    /// let right = 4.0;
+    /// let bottom = -2.0;
+    /// let top = 3.0;
+    /// let near = 1.0;
+    /// let far = 100.0;
+    /// let expected = Matrix4x4::new(
+    ///     1.0 / 4.0,  0.0,         0.0,          0.0,
+    ///     0.0,        2.0 / 5.0,   0.0,          0.0,
+    ///     0.0,        1.0 / 5.0,   1.0 / 99.0,  -1.0,
+    ///     0.0,        0.0,         100.0 / 99.0, 0.0
+    /// );
+    /// let result = Matrix4x4::from_perspective_reverse_z(left, right, bottom, top, near, far);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_reverse_z(
+        left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4x4<S> 
+    {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+
+        let c0r0 = (two * near) / (right - left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = (two * near) / (top - bottom);
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = (right + left) / (right - left);
+        let c2r1 = (top + bottom) / (top - bottom);
+        let c2r2 = near / (far - near);
+        let c2r3 = -one;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = (far * near) / (far - near);
+        let c3r3 = zero;
+
+        Matrix4x4::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// Construct a reversed-z perspective projection matrix based on the `near` 
+    /// plane, the `far` plane and the vertical field of view angle `vfov` and 
+    /// the horizontal/vertical aspect ratio `aspect`.
+    ///
+    /// The matrix maps the `near` plane to a clip space depth of `1` and the 
+    /// `far` plane to a clip space depth of `0`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(90.0);
+    /// let aspect = 2.0;
+    /// let near = 1.0;
+    /// let far = 101.0;
+    /// let expected = Matrix4x4::new(
+    ///     1.0 / 2.0, 0.0,  0.0,           0.0, 
+    ///     0.0,       1.0,  0.0,           0.0, 
+    ///     0.0,       0.0,  1.0 / 100.0,  -1.0, 
+    ///     0.0,       0.0,  101.0 / 100.0, 0.0
+    /// );
+    /// let result = Matrix4x4::from_perspective_fov_reverse_z(vfov, aspect, near, far);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_fov_reverse_z<A: Into<Radians<S>>>(
+        vfov: A, aspect: S, near: S, far: S) -> Matrix4x4<S> 
+    {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let range = Angle::tan(vfov.into() / two) * near;
+        let sx = (two * near) / (range * aspect + range * aspect);
+        let sy = near / range;
+        let sz = near / (far - near);
+        let pz = (far * near) / (far - near);
+        
+        Matrix4x4::new(
+            sx,    zero,  zero,  zero,
+            zero,  sy,    zero,  zero,
+            zero,  zero,  sz,   -one,
+            zero,  zero,  pz,    zero
+        )
+    }
+
     /// Construct an affine coordinate transformation matrix that transforms
     /// a coordinate system of an observer located at the origin facing the **z-axis**
     /// into a coordinate system of an observer located at the position `eye` facing
@@ -5316,6 +7035,9 @@ where
     ///
     /// The function maps the **z-axis** to the direction `direction`, and locates the 
     /// origin of the coordinate system to the `eye` position.
+    ///
+    /// When `up` is parallel to the viewing direction, a substitute up 
+    /// direction is chosen so that the result is still a rigid transformation.
     #[rustfmt::skip]
     #[inline]
     pub fn face_towards(
@@ -5324,7 +7046,7 @@ where
         let zero = S::zero();
         let one = S::one();
         let z_axis = direction.normalize();
-        let x_axis = up.cross(&z_axis).normalize();
+        let x_axis = Matrix3x3::horizontal_axis(up, &z_axis);
         let y_axis = z_axis.cross(&x_axis).normalize();
 
         let eye_vec = eye - Point3::origin();
@@ -5351,6 +7073,9 @@ where
     /// coordinate transformation. It is conventionally used in computer graphics 
     /// for camera view transformations.
     ///
+    /// When `up` is parallel to the viewing direction, a substitute up 
+    /// direction is chosen so that the result is still a rigid transformation.
+    ///
     /// ## Example
     ///
     /// ```
@@ -5380,13 +7105,99 @@ where
     #[rustfmt::skip]
     #[inline]
     pub fn look_at_rh(
-        eye: &Point3<S>, target: &Point3<S>, up: &Vector3<S>) -> Matrix4x4<S> {
-        let direction = -(target - eye);
-        
+        eye: &Point3<S>, target: &Point3<S>, up: &Vector3<S>) -> Matrix4x4<S> 
+    {
+        Matrix4x4::look_to_rh(eye, &(target - eye), up)
+    }
+
+    /// Construct an affine coordinate transformation matrix that transforms
+    /// a coordinate system of an observer located at the position `eye` facing 
+    /// the position `target` into the coordinate system of an observer located
+    /// at the origin facing the **positive z-axis**.
+    ///
+    /// The function maps the direction of the target `target` to the 
+    /// **positive z-axis** and locates the `eye` position to the origin in the
+    /// new the coordinate system. This transformation is a **left-handed** 
+    /// coordinate transformation. It is conventionally used in computer graphics 
+    /// for camera view transformations.
+    ///
+    /// When `up` is parallel to the viewing direction, a substitute up 
+    /// direction is chosen so that the result is still a rigid transformation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Vector3,
+    /// #     Point3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,  
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let target = Point3::new(4_f64, 5_f64, 3_f64);
+    /// let up = Vector3::unit_z();
+    /// let expected = Matrix4x4::new(
+    ///      -1_f64 / f64::sqrt(2_f64),  0_f64, 1_f64 / f64::sqrt(2_f64),  0_f64,
+    ///       1_f64 / f64::sqrt(2_f64),  0_f64, 1_f64 / f64::sqrt(2_f64),  0_f64, 
+    ///       0_f64,                     1_f64,                    0_f64,  0_f64,
+    ///      -1_f64 / f64::sqrt(2_f64), -3_f64,  -3_f64 / f64::sqrt(2_f64),  1_f64
+    /// );
+    /// let result = Matrix4x4::look_at_lh(&eye, &target, &up);
+    /// 
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn look_at_lh(
+        eye: &Point3<S>, target: &Point3<S>, up: &Vector3<S>) -> Matrix4x4<S> 
+    {
+        Matrix4x4::look_to_lh(eye, &(target - eye), up)
+    }
+
+    /// Construct an affine coordinate transformation matrix that transforms
+    /// a coordinate system of an observer located at the position `eye` facing 
+    /// the direction `direction` into the coordinate system of an observer 
+    /// located at the origin facing the **negative z-axis**.
+    ///
+    /// This is the **right-handed** camera view transformation for a camera 
+    /// that stores its position and viewing direction instead of a target 
+    /// point. It is equivalent to `look_at_rh(eye, eye + direction, up)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Vector3,
+    /// #     Point3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,  
+    /// # };
+    /// #
+    /// let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Vector3::new(3_f64, 3_f64, 0_f64);
+    /// let up = Vector3::unit_z();
+    /// let expected = Matrix4x4::look_at_rh(&eye, &(eye + direction), &up);
+    /// let result = Matrix4x4::look_to_rh(&eye, &direction, &up);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn look_to_rh(
+        eye: &Point3<S>, direction: &Vector3<S>, up: &Vector3<S>) -> Matrix4x4<S> 
+    {
+        let direction = -direction;
+
         let zero = S::zero();
         let one = S::one();
         let z_axis = direction.normalize();
-        let x_axis = up.cross(&z_axis).normalize();
+        let x_axis = Matrix3x3::horizontal_axis(up, &z_axis);
         let y_axis = z_axis.cross(&x_axis).normalize();
 
         let eye_vec = eye - Point3::origin();
@@ -5403,15 +7214,13 @@ where
     }
 
     /// Construct an affine coordinate transformation matrix that transforms
-    /// a coordinate system of an observer located at the position `eye` facing 
-    /// the position `target` into the coordinate system of an observer located
-    /// at the origin facing the **positive z-axis**.
-    ///
-    /// The function maps the direction of the target `target` to the 
-    /// **positive z-axis** and locates the `eye` position to the origin in the
-    /// new the coordinate system. This transformation is a **left-handed** 
-    /// coordinate transformation. It is conventionally used in computer graphics 
-    /// for camera view transformations.
+    /// a coordinate system of an observer located at the position `eye` facing 
+    /// the direction `direction` into the coordinate system of an observer 
+    /// located at the origin facing the **positive z-axis**.
+    ///
+    /// This is the **left-handed** camera view transformation for a camera 
+    /// that stores its position and viewing direction instead of a target 
+    /// point. It is equivalent to `look_at_lh(eye, eye + direction, up)`.
     ///
     /// ## Example
     ///
@@ -5424,32 +7233,24 @@ where
     /// # use approx::{
     /// #     relative_eq,  
     /// # };
-    /// # use core::f64;
     /// #
     /// let eye = Point3::new(1_f64, 2_f64, 3_f64);
-    /// let target = Point3::new(4_f64, 5_f64, 3_f64);
+    /// let direction = Vector3::new(3_f64, 3_f64, 0_f64);
     /// let up = Vector3::unit_z();
-    /// let expected = Matrix4x4::new(
-    ///      -1_f64 / f64::sqrt(2_f64),  0_f64, 1_f64 / f64::sqrt(2_f64),  0_f64,
-    ///       1_f64 / f64::sqrt(2_f64),  0_f64, 1_f64 / f64::sqrt(2_f64),  0_f64, 
-    ///       0_f64,                     1_f64,                    0_f64,  0_f64,
-    ///      -1_f64 / f64::sqrt(2_f64), -3_f64,  -3_f64 / f64::sqrt(2_f64),  1_f64
-    /// );
-    /// let result = Matrix4x4::look_at_lh(&eye, &target, &up);
-    /// 
-    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// let expected = Matrix4x4::look_at_lh(&eye, &(eye + direction), &up);
+    /// let result = Matrix4x4::look_to_lh(&eye, &direction, &up);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[rustfmt::skip]
     #[inline]
-    pub fn look_at_lh(
-        eye: &Point3<S>, target: &Point3<S>, up: &Vector3<S>) -> Matrix4x4<S> {
-
-        let direction = target - eye;
-         
+    pub fn look_to_lh(
+        eye: &Point3<S>, direction: &Vector3<S>, up: &Vector3<S>) -> Matrix4x4<S> 
+    {
         let zero = S::zero();
         let one = S::one();
         let z_axis = direction.normalize();
-        let x_axis = up.cross(&z_axis).normalize();
+        let x_axis = Matrix3x3::horizontal_axis(up, &z_axis);
         let y_axis = z_axis.cross(&x_axis).normalize();
 
         let eye_vec = eye - Point3::origin();
@@ -5465,6 +7266,63 @@ where
         )
     }
 
+    /// Interpolate from a rigid view matrix towards a right-handed look-at 
+    /// view matrix.
+    ///
+    /// The function constructs the matrix `look_at_rh(eye, target, up)` and 
+    /// spherically interpolates the rotation part of `current` towards its 
+    /// rotation part. It recovers the eye position of `current`, linearly 
+    /// interpolates it towards `eye`, and rebuilds the translation from the 
+    /// interpolated eye and rotation, so the camera moves along a straight 
+    /// line even while it turns. Calling it every frame with a 
+    /// small `amount` makes a camera follow a moving target without snapping 
+    /// to it. The matrix `current` is assumed to be a rigid transformation, 
+    /// such as the result of a previous call to this function. When 
+    /// `amount == 0` the result is `current`, and when `amount == 1` the 
+    /// result is the look-at matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::new(2_f64, 1_f64, 0_f64);
+    /// let up = Vector3::unit_y();
+    /// let current = Matrix4x4::look_at_rh(&eye, &Point3::origin(), &up);
+    /// let expected = Matrix4x4::look_at_rh(&eye, &target, &up);
+    /// let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 1_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn look_at_interpolated(
+        eye: &Point3<S>, 
+        target: &Point3<S>, 
+        up: &Vector3<S>, 
+        current: &Matrix4x4<S>, 
+        amount: S) -> Matrix4x4<S> 
+    {
+        let goal = Matrix4x4::look_at_rh(eye, target, up);
+        let current_rotation = current.to_mat3();
+        let rotation = current_rotation.slerp(&goal.to_mat3(), amount);
+        // A view matrix maps the eye to the origin, so its translation is 
+        // `-rotation * eye`, and the inverse of a rotation is its transpose.
+        let current_eye = -(current_rotation.transpose() * current.column(3).contract());
+        let interpolated_eye = current_eye.lerp(&eye.to_vector(), amount);
+        let translation = -(rotation * interpolated_eye);
+        let scale = Vector3::from_fill(S::one());
+
+        Matrix4x4::from_translation_rotation_scale(&translation, &rotation, &scale)
+    }
+
     /// Returns `true` if the elements of a matrix are all finite. 
     /// Otherwise, it returns `false`. 
     ///
@@ -5517,6 +7375,46 @@ where
         self.data[3][2].is_finite() && self.data[3][3].is_finite()
     }
 
+    /// Returns `true` if any element of a matrix is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_f64, f64::NAN, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64,
+    ///     13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(matrix.is_nan());
+    ///
+    /// let matrix = Matrix4x4::new(
+    ///     1_f64, f64::INFINITY, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64,
+    ///     13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(!matrix.is_nan());
+    /// assert!(!matrix.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0][0].is_nan() || self.data[0][1].is_nan() || 
+        self.data[0][2].is_nan() || self.data[0][3].is_nan() ||
+        self.data[1][0].is_nan() || self.data[1][1].is_nan() || 
+        self.data[1][2].is_nan() || self.data[1][3].is_nan() ||
+        self.data[2][0].is_nan() || self.data[2][1].is_nan() || 
+        self.data[2][2].is_nan() || self.data[2][3].is_nan() ||
+        self.data[3][0].is_nan() || self.data[3][1].is_nan() ||
+        self.data[3][2].is_nan() || self.data[3][3].is_nan()
+    }
+
     /// Compute the inverse of a square matrix, if the inverse exists. 
     ///
     /// Given a square matrix `self` Compute the matrix `m` if it exists 
@@ -5648,6 +7546,102 @@ where
         ulps_ne!(self.determinant(), S::zero())
     }
 
+    /// Determine whether a square matrix has an inverse matrix that can be 
+    /// computed reliably, within a tolerance `epsilon`.
+    ///
+    /// A matrix is treated as invertible when the absolute value of its 
+    /// determinant exceeds `epsilon`. This rejects nearly singular matrices 
+    /// whose inverses would be dominated by floating point error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1e-5_f64, 0_f64,    0_f64,    0_f64,
+    ///     0_f64,    1e-5_f64, 0_f64,    0_f64,
+    ///     0_f64,    0_f64,    1e-5_f64, 0_f64,
+    ///     0_f64,    0_f64,    0_f64,    1e-5_f64
+    /// );
+    ///
+    /// assert!(matrix.is_invertible_eps(1e-30_f64));
+    /// assert!(!matrix.is_invertible_eps(1e-12_f64));
+    /// ```
+    #[inline]
+    pub fn is_invertible_eps(&self, epsilon: S) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
+    /// Compute the inverse of a square matrix, if the absolute value of its 
+    /// determinant exceeds `epsilon`.
+    ///
+    /// The function returns `None` for matrices that are singular or nearly 
+    /// singular with respect to the tolerance `epsilon`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4, 
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1e-5_f64, 0_f64,    0_f64,    0_f64,
+    ///     0_f64,    1e-5_f64, 0_f64,    0_f64,
+    ///     0_f64,    0_f64,    1e-5_f64, 0_f64,
+    ///     0_f64,    0_f64,    0_f64,    1e-5_f64
+    /// );
+    ///
+    /// assert!(matrix.inverse().is_some());
+    /// assert!(matrix.inverse_eps(1e-12_f64).is_none());
+    /// ```
+    #[inline]
+    pub fn inverse_eps(&self, epsilon: S) -> Option<Self> {
+        if self.is_invertible_eps(epsilon) {
+            self.inverse()
+        } else {
+            None
+        }
+    }
+
+    /// Estimate the condition number of a square matrix.
+    ///
+    /// The estimate is the product of the Frobenius norms of the matrix and 
+    /// its inverse. It bounds the exact condition number with respect to the 
+    /// matrix 2-norm from above, and it is at least `4` for every invertible 
+    /// matrix, with equality for multiples of orthogonal matrices. Large 
+    /// values warn that inverting the matrix loses precision. The function 
+    /// returns infinity for singular matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let identity: Matrix4x4<f64> = Matrix4x4::identity();
+    ///
+    /// assert!(relative_eq!(identity.condition_number(), 4_f64, epsilon = 1e-10));
+    ///
+    /// let matrix = Matrix4x4::from_affine_nonuniform_scale(1_f64, 1_f64, 1e-8_f64);
+    ///
+    /// assert!(matrix.condition_number() > 1e8_f64);
+    /// ```
+    #[inline]
+    pub fn condition_number(&self) -> S {
+        match self.inverse() {
+            Some(inverse) => frobenius_norm(self.as_slice()) * frobenius_norm(inverse.as_slice()),
+            None => S::infinity(),
+        }
+    }
+
     /// Determine whether a square matrix is a diagonal matrix. 
     ///
     /// A square matrix is a diagonal matrix if every off-diagonal 
@@ -5713,6 +7707,209 @@ where
     pub fn lerp(&self, other: &Matrix4x4<S>, amount: S) -> Matrix4x4<S> {
         self + ((other - self) * amount)
     }
+
+    /// Orthonormalize the upper left 3x3 block of a matrix, leaving the rest 
+    /// of the matrix unchanged.
+    ///
+    /// Accumulating many rigid transformations in floating point arithmetic 
+    /// slowly introduces scaling and shearing into their rotation part. This 
+    /// function replaces the upper left block with a rotation matrix while 
+    /// preserving the translation and the bottom row. The first column keeps 
+    /// its direction, the second column is orthogonalized against it with the
+    /// Gram-Schmidt process, and the third column is their cross product, so 
+    /// the result is always a proper rotation.
+    ///
+    /// The third column of the block is never read. Since it is replaced by 
+    /// the cross product of the first two columns, a block with a negative 
+    /// determinant, such as a reflection, becomes a rotation instead of 
+    /// staying a reflection. For example, the block `diag(1, 1, -1)` becomes 
+    /// the identity.
+    ///
+    /// The function does not reject degenerate blocks either; it still 
+    /// returns a rotation. A first column with no usable direction is 
+    /// replaced by the **x-axis**, and a second column dependent on the first 
+    /// one, in the same sense as in [`Matrix3x3::orthonormalize`], is replaced 
+    /// by an arbitrary direction perpendicular to the first column. Use 
+    /// [`Matrix3x3::orthonormalize`] on the block to detect these cases 
+    /// instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x3,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1.01_f64, 0.02_f64, 0_f64,     0_f64,
+    ///     0_f64,    0.99_f64, 0.01_f64,  0_f64,
+    ///     0.01_f64, 0_f64,    1.02_f64,  0_f64,
+    ///     4_f64,    5_f64,    6_f64,     1_f64
+    /// );
+    /// let result = matrix.orthonormalize_rotation();
+    /// let rotation = result.to_mat3();
+    ///
+    /// assert!(relative_eq!(rotation.transpose() * rotation, Matrix3x3::identity(), epsilon = 1e-10));
+    /// assert!(relative_eq!(rotation.determinant(), 1_f64, epsilon = 1e-10));
+    /// assert_eq!(result.column(3), matrix.column(3));
+    /// ```
+    #[inline]
+    pub fn orthonormalize_rotation(&self) -> Matrix4x4<S> {
+        let tolerance = S::default_epsilon().sqrt();
+        let column0 = self.column(0).contract();
+        let u0 = Unit::try_from_value(column0, tolerance * column0.magnitude())
+            .map(|unit| unit.into_inner())
+            .unwrap_or_else(Vector3::unit_x);
+        let column1 = self.column(1).contract();
+        let residual1 = column1 - u0 * u0.dot(&column1);
+        let u1 = Unit::try_from_value(residual1, tolerance * column1.magnitude())
+            .map(|unit| unit.into_inner())
+            .unwrap_or_else(|| u0.any_perpendicular().normalize());
+        let u2 = u0.cross(&u1);
+
+        let mut result = *self;
+        result.data[0][0] = u0.x;
+        result.data[0][1] = u0.y;
+        result.data[0][2] = u0.z;
+        result.data[1][0] = u1.x;
+        result.data[1][1] = u1.y;
+        result.data[1][2] = u1.z;
+        result.data[2][0] = u2.x;
+        result.data[2][1] = u2.y;
+        result.data[2][2] = u2.z;
+
+        result
+    }
+
+    /// Extract the six clipping planes of the view frustum described by a 
+    /// projection matrix, or by a combined view-projection matrix.
+    ///
+    /// Each plane is returned as the coefficients `(a, b, c, d)` of the plane
+    /// equation `a * x + b * y + c * z + d == 0`, normalized such that
+    /// `a^2 + b^2 + c^2 == 1`. The normals point into the frustum, so a point 
+    /// inside the frustum has a non-negative signed distance to every plane. 
+    /// The planes are ordered left, right, bottom, top, near, far. 
+    ///
+    /// The planes are extracted assuming the OpenGL clip space convention of 
+    /// the depth range `[-1, 1]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Degrees,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let matrix = Matrix4x4::from_perspective_fov(vfov, aspect, near, far);
+    /// let planes = matrix.extract_frustum_planes();
+    /// let inside = Vector4::new(1_f64, 1_f64, -10_f64, 1_f64);
+    /// let outside = Vector4::new(1_f64, 1_f64, 10_f64, 1_f64);
+    ///
+    /// assert!(planes.iter().all(|plane| plane.dot(&inside) >= 0_f64));
+    /// assert!(planes.iter().any(|plane| plane.dot(&outside) < 0_f64));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn extract_frustum_planes(&self) -> [Vector4<S>; 6] {
+        let row0 = self.row(0);
+        let row1 = self.row(1);
+        let row2 = self.row(2);
+        let row3 = self.row(3);
+        let normalize_plane = |plane: Vector4<S>| {
+            plane / plane.contract().magnitude()
+        };
+
+        [
+            normalize_plane(row3 + row0),
+            normalize_plane(row3 - row0),
+            normalize_plane(row3 + row1),
+            normalize_plane(row3 - row1),
+            normalize_plane(row3 + row2),
+            normalize_plane(row3 - row2),
+        ]
+    }
+
+    /// Apply the homogeneous transformation described by the matrix to a point.
+    ///
+    /// The point is extended to homogeneous coordinates with `w == 1`, 
+    /// multiplied by the matrix, and then divided by the resulting `w` 
+    /// component.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+    /// let point = Point3::new(4_f64, 5_f64, 6_f64);
+    /// let expected = Point3::new(5_f64, 7_f64, 9_f64);
+    /// let result = matrix.transform_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        let homogeneous = self * point.to_homogeneous();
+        let one_div_w = S::one() / homogeneous.w;
+
+        Point3::new(
+            homogeneous.x * one_div_w,
+            homogeneous.y * one_div_w,
+            homogeneous.z * one_div_w,
+        )
+    }
+
+    /// Apply the homogeneous transformation described by the matrix to each
+    /// point in `points`, writing the results to the corresponding elements 
+    /// of `out`.
+    ///
+    /// The slices `points` and `out` must have the same length.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+    /// let points = [
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(4_f64, 5_f64, 6_f64),
+    /// ];
+    /// let expected = [
+    ///     Point3::new(1_f64, 2_f64, 3_f64),
+    ///     Point3::new(5_f64, 7_f64, 9_f64),
+    /// ];
+    /// let mut result = [Point3::origin(); 2];
+    /// matrix.transform_points(&points, &mut result);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &[Point3<S>], out: &mut [Point3<S>]) {
+        debug_assert_eq!(points.len(), out.len());
+
+        for (point, transformed_point) in points.iter().zip(out.iter_mut()) {
+            *transformed_point = self.transform_point(point);
+        }
+    }
 }
 
 impl<S> fmt::Display for Matrix4x4<S> 
@@ -5720,15 +7917,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // We print the matrix contents in row-major order like mathematical convention.
-        writeln!(
-            formatter, 
-            "Matrix4x4 [[{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}]]",
-            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0],
-            self.data[0][1], self.data[1][1], self.data[2][1], self.data[3][1],
-            self.data[0][2], self.data[1][2], self.data[2][2], self.data[3][2],
-            self.data[0][3], self.data[1][3], self.data[2][3], self.data[3][3]
-        )
+        fmt_matrix(formatter, "Matrix4x4", &self.data)
     }
 }
 
@@ -5885,6 +8074,29 @@ impl<S> Matrix1x2<S>
 where 
     S: Copy
 {
+    /// Construct a new matrix from a fill value.
+    ///
+    /// The resulting matrix is a matrix where each entry is the supplied fill
+    /// value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x2, 
+    /// # };
+    /// #
+    /// let fill_value: u32 = 3;
+    /// let expected = Matrix1x2::new(fill_value, fill_value);
+    /// let result = Matrix1x2::from_fill(fill_value);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Matrix1x2<S> {
+        Matrix1x2::new(value, value)
+    }
+
     /// Get the row of the matrix by value.
     #[inline]
     pub fn row(&self, r: usize) -> Vector2<S> {
@@ -5931,6 +8143,12 @@ where
         <Self as AsRef<[S; 2]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 2]>>::as_mut(self)
+    }
+
     /// Map an operation on the elements of a matrix, returning a matrix whose 
     /// elements are elements of the new underlying type.
     ///
@@ -6001,40 +8219,117 @@ where
 {
     /// Compute a zero matrix.
     ///
-    /// A zero matrix is a matrix in which all of its elements are zero.
+    /// A zero matrix is a matrix in which all of its elements are zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x2, 
+    /// # };
+    /// #
+    /// let matrix: Matrix1x2<i32> = Matrix1x2::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn zero() -> Matrix1x2<S> {
+        Matrix1x2::new(S::zero(), S::zero())
+    }
+    
+    /// Determine whether a matrix is a zero matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x2, 
+    /// # };
+    /// #
+    /// let matrix: Matrix1x2<i32> = Matrix1x2::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0][0].is_zero() && self.data[1][0].is_zero()
+    }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix1x2, 
+    /// #     Matrix1x2,
     /// # };
     /// #
-    /// let matrix: Matrix1x2<i32> = Matrix1x2::zero();
+    /// let matrix = Matrix1x2::new(
+    ///     1_i32,
+    ///     2_i32
+    /// );
+    /// let other = Matrix1x2::new(
+    ///     2_i32,
+    ///     3_i32
+    /// );
+    /// let expected = Matrix1x2::new(
+    ///     2_i32,
+    ///     6_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
     ///
-    /// assert!(matrix.is_zero());
+    /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn zero() -> Matrix1x2<S> {
-        Matrix1x2::new(S::zero(), S::zero())
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix1x2::new(
+            self.data[0][0] * other.data[0][0],
+            self.data[1][0] * other.data[1][0]
+        )
     }
-    
-    /// Determine whether a matrix is a zero matrix.
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix1x2, 
+    /// #     Matrix1x2,
     /// # };
     /// #
-    /// let matrix: Matrix1x2<i32> = Matrix1x2::zero();
+    /// let matrix = Matrix1x2::new(
+    ///     2_f64,
+    ///     6_f64
+    /// );
+    /// let other = Matrix1x2::new(
+    ///     2_f64,
+    ///     3_f64
+    /// );
+    /// let expected = Matrix1x2::new(
+    ///     1_f64,
+    ///     2_f64
+    /// );
+    /// let result = matrix.component_div(&other);
     ///
-    /// assert!(matrix.is_zero());
+    /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0][0].is_zero() && self.data[1][0].is_zero()
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix1x2::new(
+            self.data[0][0] / other.data[0][0],
+            self.data[1][0] / other.data[1][0]
+        )
     }
 }
 
@@ -6043,11 +8338,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter, 
-            "Matrix1x2 [[{}, {}]]", 
-            self.data[0][0], self.data[1][0]
-        )
+        fmt_matrix(formatter, "Matrix1x2", &self.data)
     }
 }
 
@@ -6121,6 +8412,29 @@ impl<S> Matrix1x3<S>
 where 
     S: Copy 
 {
+    /// Construct a new matrix from a fill value.
+    ///
+    /// The resulting matrix is a matrix where each entry is the supplied fill
+    /// value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x3, 
+    /// # };
+    /// #
+    /// let fill_value: u32 = 3;
+    /// let expected = Matrix1x3::new(fill_value, fill_value, fill_value);
+    /// let result = Matrix1x3::from_fill(fill_value);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Matrix1x3<S> {
+        Matrix1x3::new(value, value, value)
+    }
+
     /// Get the row of the matrix by value.
     #[inline]
     pub fn row(&self, r: usize) -> Vector3<S> {
@@ -6167,6 +8481,12 @@ where
         <Self as AsRef<[S; 3]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 3]>>::as_mut(self)
+    }
+
     /// Map an operation on the elements of a matrix, returning a matrix whose 
     /// elements are elements of the new underlying type.
     ///
@@ -6279,6 +8599,91 @@ where
         self.data[1][0].is_zero() && 
         self.data[2][0].is_zero()
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x3::new(
+    ///     1_i32,
+    ///     2_i32,
+    ///     3_i32
+    /// );
+    /// let other = Matrix1x3::new(
+    ///     2_i32,
+    ///     3_i32,
+    ///     4_i32
+    /// );
+    /// let expected = Matrix1x3::new(
+    ///     2_i32,
+    ///     6_i32,
+    ///     12_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix1x3::new(
+            self.data[0][0] * other.data[0][0],
+            self.data[1][0] * other.data[1][0],
+            self.data[2][0] * other.data[2][0]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x3::new(
+    ///     2_f64,
+    ///     6_f64,
+    ///     12_f64
+    /// );
+    /// let other = Matrix1x3::new(
+    ///     2_f64,
+    ///     3_f64,
+    ///     4_f64
+    /// );
+    /// let expected = Matrix1x3::new(
+    ///     1_f64,
+    ///     2_f64,
+    ///     3_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix1x3::new(
+            self.data[0][0] / other.data[0][0],
+            self.data[1][0] / other.data[1][0],
+            self.data[2][0] / other.data[2][0]
+        )
+    }
 }
 
 impl<S> fmt::Display for Matrix1x3<S> 
@@ -6286,11 +8691,7 @@ where
     S: fmt::Display 
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter, 
-            "Matrix1x3 [[{}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0]
-        )
+        fmt_matrix(formatter, "Matrix1x3", &self.data)
     }
 }
 
@@ -6365,6 +8766,29 @@ impl<S> Matrix1x4<S>
 where 
     S: Copy
 {
+    /// Construct a new matrix from a fill value.
+    ///
+    /// The resulting matrix is a matrix where each entry is the supplied fill
+    /// value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x4, 
+    /// # };
+    /// #
+    /// let fill_value: u32 = 3;
+    /// let expected = Matrix1x4::new(fill_value, fill_value, fill_value, fill_value);
+    /// let result = Matrix1x4::from_fill(fill_value);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Matrix1x4<S> {
+        Matrix1x4::new(value, value, value, value)
+    }
+
     /// Get the row of the matrix by value.
     #[inline]
     pub fn row(&self, r: usize) -> Vector4<S> {
@@ -6416,6 +8840,12 @@ where
         <Self as AsRef<[S; 4]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 4]>>::as_mut(self)
+    }
+
     /// Map an operation on the elements of a matrix, returning a matrix whose 
     /// elements are elements of the new underlying type.
     ///
@@ -6534,6 +8964,99 @@ where
         self.data[2][0].is_zero() &&
         self.data[3][0].is_zero()
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x4::new(
+    ///     1_i32,
+    ///     2_i32,
+    ///     3_i32,
+    ///     4_i32
+    /// );
+    /// let other = Matrix1x4::new(
+    ///     2_i32,
+    ///     3_i32,
+    ///     4_i32,
+    ///     2_i32
+    /// );
+    /// let expected = Matrix1x4::new(
+    ///     2_i32,
+    ///     6_i32,
+    ///     12_i32,
+    ///     8_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix1x4::new(
+            self.data[0][0] * other.data[0][0],
+            self.data[1][0] * other.data[1][0],
+            self.data[2][0] * other.data[2][0],
+            self.data[3][0] * other.data[3][0]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix1x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix1x4::new(
+    ///     2_f64,
+    ///     6_f64,
+    ///     12_f64,
+    ///     8_f64
+    /// );
+    /// let other = Matrix1x4::new(
+    ///     2_f64,
+    ///     3_f64,
+    ///     4_f64,
+    ///     2_f64
+    /// );
+    /// let expected = Matrix1x4::new(
+    ///     1_f64,
+    ///     2_f64,
+    ///     3_f64,
+    ///     4_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix1x4::new(
+            self.data[0][0] / other.data[0][0],
+            self.data[1][0] / other.data[1][0],
+            self.data[2][0] / other.data[2][0],
+            self.data[3][0] / other.data[3][0]
+        )
+    }
 }
 
 impl<S> fmt::Display for Matrix1x4<S> 
@@ -6541,11 +9064,7 @@ where
     S: fmt::Display 
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter, 
-            "Matrix1x4 [[{}, {}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0]
-        )
+        fmt_matrix(formatter, "Matrix1x4", &self.data)
     }
 }
 
@@ -6803,6 +9322,12 @@ where
         <Self as AsRef<[S; 6]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 6]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>) -> Matrix2x3<S> {
@@ -6918,64 +9443,226 @@ impl<S> Matrix2x3<S> where S: Scalar {
     ///     2_i32, 2_i32, 
     ///     3_i32, 3_i32
     /// );
-    /// let expected = Matrix3x2::new(
-    ///     1_i32, 2_i32, 3_i32, 
-    ///     1_i32, 2_i32, 3_i32
+    /// let expected = Matrix3x2::new(
+    ///     1_i32, 2_i32, 3_i32, 
+    ///     1_i32, 2_i32, 3_i32
+    /// );
+    /// let result = matrix.transpose();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transpose(&self) -> Matrix3x2<S> {
+        Matrix3x2::new(
+            self.data[0][0], self.data[1][0], self.data[2][0], 
+            self.data[0][1], self.data[1][1], self.data[2][1]
+        )
+    }
+
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x3,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x3::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    ///     5_i32, 6_i32
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     5_i32, 11_i32, 17_i32,
+    ///     11_i32, 25_i32, 39_i32,
+    ///     17_i32, 39_i32, 61_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix3x3<S> {
+        self.transpose() * self
+    }
+
+    /// Compute a zero matrix.
+    ///
+    /// A zero matrix is a matrix in which all of its elements are zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x3, 
+    /// # };
+    /// #
+    /// let matrix: Matrix2x3<i32> = Matrix2x3::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn zero() -> Matrix2x3<S> {
+        Matrix2x3::new(
+            S::zero(), S::zero(), 
+            S::zero(), S::zero(), 
+            S::zero(), S::zero()
+        )
+    }
+    
+    /// Determine whether a matrix is a zero matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x3, 
+    /// # };
+    /// #
+    /// let matrix: Matrix2x3<i32> = Matrix2x3::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0][0].is_zero() && self.data[0][1].is_zero() &&
+        self.data[1][0].is_zero() && self.data[1][1].is_zero() &&
+        self.data[2][0].is_zero() && self.data[2][1].is_zero()
+    }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x3::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    ///     5_i32, 6_i32
+    /// );
+    /// let other = Matrix2x3::new(
+    ///     2_i32, 3_i32,
+    ///     4_i32, 2_i32,
+    ///     3_i32, 4_i32
+    /// );
+    /// let expected = Matrix2x3::new(
+    ///     2_i32, 6_i32,
+    ///     12_i32, 8_i32,
+    ///     15_i32, 24_i32
     /// );
-    /// let result = matrix.transpose();
+    /// let result = matrix.component_mul(&other);
     ///
     /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn transpose(&self) -> Matrix3x2<S> {
-        Matrix3x2::new(
-            self.data[0][0], self.data[1][0], self.data[2][0], 
-            self.data[0][1], self.data[1][1], self.data[2][1]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix2x3::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1]
         )
     }
 
-    /// Compute a zero matrix.
+    /// Compute the component-wise quotient of two matrices.
     ///
-    /// A zero matrix is a matrix in which all of its elements are zero.
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix2x3, 
+    /// #     Matrix2x3,
     /// # };
     /// #
-    /// let matrix: Matrix2x3<i32> = Matrix2x3::zero();
+    /// let matrix = Matrix2x3::new(
+    ///     2_f64, 6_f64,
+    ///     12_f64, 8_f64,
+    ///     15_f64, 24_f64
+    /// );
+    /// let other = Matrix2x3::new(
+    ///     2_f64, 3_f64,
+    ///     4_f64, 2_f64,
+    ///     3_f64, 4_f64
+    /// );
+    /// let expected = Matrix2x3::new(
+    ///     1_f64, 2_f64,
+    ///     3_f64, 4_f64,
+    ///     5_f64, 6_f64
+    /// );
+    /// let result = matrix.component_div(&other);
     ///
-    /// assert!(matrix.is_zero());
+    /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn zero() -> Matrix2x3<S> {
+    pub fn component_div(&self, other: &Self) -> Self {
         Matrix2x3::new(
-            S::zero(), S::zero(), 
-            S::zero(), S::zero(), 
-            S::zero(), S::zero()
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1]
         )
     }
-    
-    /// Determine whether a matrix is a zero matrix.
+}
+
+impl<S> Matrix2x3<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more columns than rows, so the pseudoinverse is 
+    /// computed as `A^T * (A * A^T)^-1`. When the rows of the matrix are 
+    /// linearly independent, the result is a right inverse of the matrix. 
+    /// The function returns `None` if the Gram matrix `A * A^T` is not 
+    /// invertible.
     ///
     /// ## Example
     ///
     /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
     /// # use cglinalg::{
-    /// #     Matrix2x3, 
+    /// #     Matrix2x3,
+    /// #     Matrix2x2,
     /// # };
     /// #
-    /// let matrix: Matrix2x3<i32> = Matrix2x3::zero();
+    /// let matrix = Matrix2x3::new(
+    ///     1_f64, 2_f64,
+    ///     3_f64, 4_f64,
+    ///     5_f64, 7_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix2x2::identity();
+    /// let result = matrix * pseudo_inverse;
     ///
-    /// assert!(matrix.is_zero());
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0][0].is_zero() && self.data[0][1].is_zero() &&
-        self.data[1][0].is_zero() && self.data[1][1].is_zero() &&
-        self.data[2][0].is_zero() && self.data[2][1].is_zero()
+    pub fn pseudo_inverse(&self) -> Option<Matrix3x2<S>> {
+        let transpose = self.transpose();
+        let gram = self * transpose;
+        
+        gram.inverse().map(|gram_inverse| transpose * gram_inverse)
     }
 }
 
@@ -6984,13 +9671,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix2x3 [[{}, {}, {}], [{}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0], 
-            self.data[0][1], self.data[1][1], self.data[2][1]
-        )
+        fmt_matrix(formatter, "Matrix2x3", &self.data)
     }
 }
 
@@ -7243,6 +9924,12 @@ where
         <Self as AsRef<[S; 6]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 6]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector3<S>, c1: Vector3<S>) -> Matrix3x2<S> {
@@ -7374,6 +10061,38 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x2,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///     14_i32, 32_i32,
+    ///     32_i32, 77_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix2x2<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -7419,6 +10138,124 @@ where
         self.data[1][1].is_zero() && 
         self.data[1][2].is_zero()
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32
+    /// );
+    /// let other = Matrix3x2::new(
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32
+    /// );
+    /// let expected = Matrix3x2::new(
+    ///     2_i32, 6_i32, 12_i32,
+    ///     8_i32, 15_i32, 24_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix3x2::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     2_f64, 6_f64, 12_f64,
+    ///     8_f64, 15_f64, 24_f64
+    /// );
+    /// let other = Matrix3x2::new(
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64
+    /// );
+    /// let expected = Matrix3x2::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix3x2::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2]
+        )
+    }
+}
+
+impl<S> Matrix3x2<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more rows than columns, so the pseudoinverse is 
+    /// computed from the normal equations as `(A^T * A)^-1 * A^T`. When the 
+    /// columns of the matrix are linearly independent, the result is a left 
+    /// inverse of the matrix. The function returns `None` if the Gram matrix 
+    /// `A^T * A` is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
+    /// # use cglinalg::{
+    /// #     Matrix3x2,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     1_f64, 3_f64, 5_f64,
+    ///     2_f64, 4_f64, 7_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix2x2::identity();
+    /// let result = pseudo_inverse * matrix;
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn pseudo_inverse(&self) -> Option<Matrix2x3<S>> {
+        let gram = self.gram();
+        
+        gram.inverse().map(|gram_inverse| gram_inverse * self.transpose())
+    }
 }
 
 impl<S> fmt::Display for Matrix3x2<S> 
@@ -7426,14 +10263,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix3x2 [[{}, {}], [{}, {}], [{}, {}]]", 
-            self.data[0][0], self.data[1][0], 
-            self.data[0][1], self.data[1][1], 
-            self.data[0][2], self.data[1][2]
-        )
+        fmt_matrix(formatter, "Matrix3x2", &self.data)
     }
 }
 
@@ -7715,6 +10545,12 @@ where
         <Self as AsRef<[S; 8]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 8]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(
@@ -7873,6 +10709,42 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x4,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x4::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    ///     5_i32, 6_i32,
+    ///     7_i32, 8_i32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     5_i32, 11_i32, 17_i32, 23_i32,
+    ///     11_i32, 25_i32, 39_i32, 53_i32,
+    ///     17_i32, 39_i32, 61_i32, 83_i32,
+    ///     23_i32, 53_i32, 83_i32, 113_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix4x4<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -7884,39 +10756,176 @@ where
     /// #     Matrix2x4, 
     /// # };
     /// #
-    /// let matrix: Matrix2x4<i32> = Matrix2x4::zero();
+    /// let matrix: Matrix2x4<i32> = Matrix2x4::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn zero() -> Matrix2x4<S> {
+        Matrix2x4::new(
+            S::zero(), S::zero(), 
+            S::zero(), S::zero(), 
+            S::zero(), S::zero(),
+            S::zero(), S::zero()
+        )
+    }
+    
+    /// Determine whether a matrix is a zero matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x4, 
+    /// # };
+    /// #
+    /// let matrix: Matrix2x4<i32> = Matrix2x4::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0][0].is_zero() && self.data[0][1].is_zero() &&
+        self.data[1][0].is_zero() && self.data[1][1].is_zero() &&
+        self.data[2][0].is_zero() && self.data[2][1].is_zero() &&
+        self.data[3][0].is_zero() && self.data[3][1].is_zero()
+    }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x4::new(
+    ///     1_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    ///     5_i32, 6_i32,
+    ///     7_i32, 8_i32
+    /// );
+    /// let other = Matrix2x4::new(
+    ///     2_i32, 3_i32,
+    ///     4_i32, 2_i32,
+    ///     3_i32, 4_i32,
+    ///     2_i32, 3_i32
+    /// );
+    /// let expected = Matrix2x4::new(
+    ///     2_i32, 6_i32,
+    ///     12_i32, 8_i32,
+    ///     15_i32, 24_i32,
+    ///     14_i32, 24_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix2x4::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1],
+            self.data[3][0] * other.data[3][0], self.data[3][1] * other.data[3][1]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix2x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x4::new(
+    ///     2_f64, 6_f64,
+    ///     12_f64, 8_f64,
+    ///     15_f64, 24_f64,
+    ///     14_f64, 24_f64
+    /// );
+    /// let other = Matrix2x4::new(
+    ///     2_f64, 3_f64,
+    ///     4_f64, 2_f64,
+    ///     3_f64, 4_f64,
+    ///     2_f64, 3_f64
+    /// );
+    /// let expected = Matrix2x4::new(
+    ///     1_f64, 2_f64,
+    ///     3_f64, 4_f64,
+    ///     5_f64, 6_f64,
+    ///     7_f64, 8_f64
+    /// );
+    /// let result = matrix.component_div(&other);
     ///
-    /// assert!(matrix.is_zero());
+    /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn zero() -> Matrix2x4<S> {
+    pub fn component_div(&self, other: &Self) -> Self {
         Matrix2x4::new(
-            S::zero(), S::zero(), 
-            S::zero(), S::zero(), 
-            S::zero(), S::zero(),
-            S::zero(), S::zero()
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1],
+            self.data[3][0] / other.data[3][0], self.data[3][1] / other.data[3][1]
         )
     }
-    
-    /// Determine whether a matrix is a zero matrix.
+}
+
+impl<S> Matrix2x4<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more columns than rows, so the pseudoinverse is 
+    /// computed as `A^T * (A * A^T)^-1`. When the rows of the matrix are 
+    /// linearly independent, the result is a right inverse of the matrix. 
+    /// The function returns `None` if the Gram matrix `A * A^T` is not 
+    /// invertible.
     ///
     /// ## Example
     ///
     /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
     /// # use cglinalg::{
-    /// #     Matrix2x4, 
+    /// #     Matrix2x4,
+    /// #     Matrix2x2,
     /// # };
     /// #
-    /// let matrix: Matrix2x4<i32> = Matrix2x4::zero();
+    /// let matrix = Matrix2x4::new(
+    ///     1_f64, 0_f64,
+    ///     2_f64, 1_f64,
+    ///     0_f64, 3_f64,
+    ///     1_f64, 1_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix2x2::identity();
+    /// let result = matrix * pseudo_inverse;
     ///
-    /// assert!(matrix.is_zero());
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0][0].is_zero() && self.data[0][1].is_zero() &&
-        self.data[1][0].is_zero() && self.data[1][1].is_zero() &&
-        self.data[2][0].is_zero() && self.data[2][1].is_zero() &&
-        self.data[3][0].is_zero() && self.data[3][1].is_zero()
+    pub fn pseudo_inverse(&self) -> Option<Matrix4x2<S>> {
+        let transpose = self.transpose();
+        let gram = self * transpose;
+        
+        gram.inverse().map(|gram_inverse| transpose * gram_inverse)
     }
 }
 
@@ -7925,13 +10934,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix2x4 [[{}, {}, {}, {}], [{}, {}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0], 
-            self.data[0][1], self.data[1][1], self.data[2][1], self.data[3][1]
-        )
+        fmt_matrix(formatter, "Matrix2x4", &self.data)
     }
 }
 
@@ -8195,6 +11198,12 @@ where
         <Self as AsRef<[S; 8]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 8]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector4<S>, c1: Vector4<S>) -> Matrix4x2<S> {
@@ -8345,6 +11354,38 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x2,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = Matrix2x2::new(
+    ///     30_i32, 70_i32,
+    ///     70_i32, 174_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix2x2<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -8392,6 +11433,124 @@ where
         self.data[1][2].is_zero() &&
         self.data[1][3].is_zero()
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let other = Matrix4x2::new(
+    ///     2_i32, 3_i32, 4_i32, 2_i32,
+    ///     3_i32, 4_i32, 2_i32, 3_i32
+    /// );
+    /// let expected = Matrix4x2::new(
+    ///     2_i32, 6_i32, 12_i32, 8_i32,
+    ///     15_i32, 24_i32, 14_i32, 24_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix4x2::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2], self.data[0][3] * other.data[0][3],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2], self.data[1][3] * other.data[1][3]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x2::new(
+    ///     2_f64, 6_f64, 12_f64, 8_f64,
+    ///     15_f64, 24_f64, 14_f64, 24_f64
+    /// );
+    /// let other = Matrix4x2::new(
+    ///     2_f64, 3_f64, 4_f64, 2_f64,
+    ///     3_f64, 4_f64, 2_f64, 3_f64
+    /// );
+    /// let expected = Matrix4x2::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix4x2::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2], self.data[0][3] / other.data[0][3],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2], self.data[1][3] / other.data[1][3]
+        )
+    }
+}
+
+impl<S> Matrix4x2<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more rows than columns, so the pseudoinverse is 
+    /// computed from the normal equations as `(A^T * A)^-1 * A^T`. When the 
+    /// columns of the matrix are linearly independent, the result is a left 
+    /// inverse of the matrix. The function returns `None` if the Gram matrix 
+    /// `A^T * A` is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
+    /// # use cglinalg::{
+    /// #     Matrix4x2,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x2::new(
+    ///     1_f64, 2_f64, 0_f64, 1_f64,
+    ///     0_f64, 1_f64, 3_f64, 1_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix2x2::identity();
+    /// let result = pseudo_inverse * matrix;
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn pseudo_inverse(&self) -> Option<Matrix2x4<S>> {
+        let gram = self.gram();
+        
+        gram.inverse().map(|gram_inverse| gram_inverse * self.transpose())
+    }
 }
 
 impl<S> fmt::Display for Matrix4x2<S> 
@@ -8399,15 +11558,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix4x2 [[{}, {}], [{}, {}], [{}, {}], [{}, {}]]", 
-            self.data[0][0], self.data[1][0], 
-            self.data[0][1], self.data[1][1], 
-            self.data[0][2], self.data[1][2],
-            self.data[0][3], self.data[1][3]
-        )
+        fmt_matrix(formatter, "Matrix4x2", &self.data)
     }
 }
 
@@ -8692,6 +11843,12 @@ where
         <Self as AsRef<[S; 12]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 12]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(
@@ -8850,81 +12007,254 @@ where
     /// # };
     /// #
     /// let matrix = Matrix3x4::new(
-    ///     1_i32, 1_i32, 1_i32,
-    ///     2_i32, 2_i32, 2_i32,
-    ///     3_i32, 3_i32, 3_i32,
-    ///     4_i32, 4_i32, 4_i32
+    ///     1_i32, 1_i32, 1_i32,
+    ///     2_i32, 2_i32, 2_i32,
+    ///     3_i32, 3_i32, 3_i32,
+    ///     4_i32, 4_i32, 4_i32
+    /// );
+    /// let expected = Matrix4x3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     1_i32, 2_i32, 3_i32, 4_i32
+    /// );
+    /// let result = matrix.transpose();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transpose(&self) -> Matrix4x3<S> {
+        Matrix4x3::new(
+            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0],
+            self.data[0][1], self.data[1][1], self.data[2][1], self.data[3][1],
+            self.data[0][2], self.data[1][2], self.data[2][2], self.data[3][2]
+        )
+    }
+
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x4,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x4::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32,
+    ///     10_i32, 11_i32, 12_i32
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///     14_i32, 32_i32, 50_i32, 68_i32,
+    ///     32_i32, 77_i32, 122_i32, 167_i32,
+    ///     50_i32, 122_i32, 194_i32, 266_i32,
+    ///     68_i32, 167_i32, 266_i32, 365_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix4x4<S> {
+        self.transpose() * self
+    }
+
+    /// Compute a zero matrix.
+    ///
+    /// A zero matrix is a matrix in which all of its elements are zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x4, 
+    /// # };
+    /// #
+    /// let matrix: Matrix3x4<i32> = Matrix3x4::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn zero() -> Matrix3x4<S> {
+        Matrix3x4::new(
+            S::zero(), S::zero(), S::zero(),
+            S::zero(), S::zero(), S::zero(),
+            S::zero(), S::zero(), S::zero(),
+            S::zero(), S::zero(), S::zero()
+        )
+    }
+    
+    /// Determine whether a matrix is a zero matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x4, 
+    /// # };
+    /// #
+    /// let matrix: Matrix3x4<i32> = Matrix3x4::zero();
+    ///
+    /// assert!(matrix.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0][0].is_zero() && 
+        self.data[0][1].is_zero() &&
+        self.data[0][2].is_zero() &&
+        self.data[1][0].is_zero() && 
+        self.data[1][1].is_zero() &&
+        self.data[1][2].is_zero() &&
+        self.data[2][0].is_zero() && 
+        self.data[2][1].is_zero() &&
+        self.data[2][2].is_zero() &&
+        self.data[3][0].is_zero() && 
+        self.data[3][1].is_zero() &&
+        self.data[3][2].is_zero()
+    }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix3x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x4::new(
+    ///     1_i32, 2_i32, 3_i32,
+    ///     4_i32, 5_i32, 6_i32,
+    ///     7_i32, 8_i32, 9_i32,
+    ///     10_i32, 11_i32, 12_i32
     /// );
-    /// let expected = Matrix4x3::new(
-    ///     1_i32, 2_i32, 3_i32, 4_i32,
-    ///     1_i32, 2_i32, 3_i32, 4_i32,
-    ///     1_i32, 2_i32, 3_i32, 4_i32
+    /// let other = Matrix3x4::new(
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32,
+    ///     2_i32, 3_i32, 4_i32
     /// );
-    /// let result = matrix.transpose();
+    /// let expected = Matrix3x4::new(
+    ///     2_i32, 6_i32, 12_i32,
+    ///     8_i32, 15_i32, 24_i32,
+    ///     14_i32, 24_i32, 36_i32,
+    ///     20_i32, 33_i32, 48_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
     ///
     /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn transpose(&self) -> Matrix4x3<S> {
-        Matrix4x3::new(
-            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0],
-            self.data[0][1], self.data[1][1], self.data[2][1], self.data[3][1],
-            self.data[0][2], self.data[1][2], self.data[2][2], self.data[3][2]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix3x4::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1], self.data[2][2] * other.data[2][2],
+            self.data[3][0] * other.data[3][0], self.data[3][1] * other.data[3][1], self.data[3][2] * other.data[3][2]
         )
     }
 
-    /// Compute a zero matrix.
+    /// Compute the component-wise quotient of two matrices.
     ///
-    /// A zero matrix is a matrix in which all of its elements are zero.
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Matrix3x4, 
+    /// #     Matrix3x4,
     /// # };
     /// #
-    /// let matrix: Matrix3x4<i32> = Matrix3x4::zero();
+    /// let matrix = Matrix3x4::new(
+    ///     2_f64, 6_f64, 12_f64,
+    ///     8_f64, 15_f64, 24_f64,
+    ///     14_f64, 24_f64, 36_f64,
+    ///     20_f64, 33_f64, 48_f64
+    /// );
+    /// let other = Matrix3x4::new(
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64,
+    ///     2_f64, 3_f64, 4_f64
+    /// );
+    /// let expected = Matrix3x4::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64,
+    ///     10_f64, 11_f64, 12_f64
+    /// );
+    /// let result = matrix.component_div(&other);
     ///
-    /// assert!(matrix.is_zero());
+    /// assert_eq!(result, expected);
     /// ```
+    #[rustfmt::skip]
     #[inline]
-    pub fn zero() -> Matrix3x4<S> {
+    pub fn component_div(&self, other: &Self) -> Self {
         Matrix3x4::new(
-            S::zero(), S::zero(), S::zero(),
-            S::zero(), S::zero(), S::zero(),
-            S::zero(), S::zero(), S::zero(),
-            S::zero(), S::zero(), S::zero()
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1], self.data[2][2] / other.data[2][2],
+            self.data[3][0] / other.data[3][0], self.data[3][1] / other.data[3][1], self.data[3][2] / other.data[3][2]
         )
     }
-    
-    /// Determine whether a matrix is a zero matrix.
+}
+
+impl<S> Matrix3x4<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more columns than rows, so the pseudoinverse is 
+    /// computed as `A^T * (A * A^T)^-1`. When the rows of the matrix are 
+    /// linearly independent, the result is a right inverse of the matrix. 
+    /// The function returns `None` if the Gram matrix `A * A^T` is not 
+    /// invertible.
     ///
     /// ## Example
     ///
     /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
     /// # use cglinalg::{
-    /// #     Matrix3x4, 
+    /// #     Matrix3x4,
+    /// #     Matrix3x3,
     /// # };
     /// #
-    /// let matrix: Matrix3x4<i32> = Matrix3x4::zero();
+    /// let matrix = Matrix3x4::new(
+    ///     1_f64, 0_f64, 2_f64,
+    ///     0_f64, 1_f64, 1_f64,
+    ///     3_f64, 0_f64, 1_f64,
+    ///     1_f64, 2_f64, 0_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix3x3::identity();
+    /// let result = matrix * pseudo_inverse;
     ///
-    /// assert!(matrix.is_zero());
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0][0].is_zero() && 
-        self.data[0][1].is_zero() &&
-        self.data[0][2].is_zero() &&
-        self.data[1][0].is_zero() && 
-        self.data[1][1].is_zero() &&
-        self.data[1][2].is_zero() &&
-        self.data[2][0].is_zero() && 
-        self.data[2][1].is_zero() &&
-        self.data[2][2].is_zero() &&
-        self.data[3][0].is_zero() && 
-        self.data[3][1].is_zero() &&
-        self.data[3][2].is_zero()
+    pub fn pseudo_inverse(&self) -> Option<Matrix4x3<S>> {
+        let transpose = self.transpose();
+        let gram = self * transpose;
+        
+        gram.inverse().map(|gram_inverse| transpose * gram_inverse)
     }
 }
 
@@ -8933,14 +12263,7 @@ where
     S: fmt::Display 
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix3x4 [[{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0], self.data[3][0], 
-            self.data[0][1], self.data[1][1], self.data[2][1], self.data[3][1],
-            self.data[0][2], self.data[1][2], self.data[2][2], self.data[3][2]
-        )
+        fmt_matrix(formatter, "Matrix3x4", &self.data)
     }
 }
 
@@ -9217,6 +12540,12 @@ where
         <Self as AsRef<[S; 12]>>::as_ref(self)
     }
 
+    /// Get a mutable slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        <Self as AsMut<[S; 12]>>::as_mut(self)
+    }
+
     /// Construct a matrix from a set of column vectors.
     #[inline]
     pub fn from_columns(c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>) -> Matrix4x3<S> {
@@ -9391,6 +12720,40 @@ where
         )
     }
 
+    /// Compute the Gram matrix of the columns of a matrix.
+    ///
+    /// The Gram matrix of a matrix `A` is the product `A^T * A`. The element 
+    /// in row `i` and column `j` of the Gram matrix is the dot product of 
+    /// columns `i` and `j` of `A`, so the Gram matrix is always symmetric.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x3,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     30_i32, 70_i32, 110_i32,
+    ///     70_i32, 174_i32, 278_i32,
+    ///     110_i32, 278_i32, 446_i32
+    /// );
+    /// let result = matrix.gram();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, result.transpose());
+    /// ```
+    #[inline]
+    pub fn gram(&self) -> Matrix3x3<S> {
+        self.transpose() * self
+    }
+
     /// Compute a zero matrix.
     ///
     /// A zero matrix is a matrix in which all of its elements are zero.
@@ -9443,6 +12806,133 @@ where
         self.data[2][2].is_zero() &&
         self.data[2][3].is_zero()
     }
+
+    /// Compute the component-wise product of two matrices.
+    ///
+    /// The component-wise product of two matrices is also known as the 
+    /// Hadamard product. Each element of the result is the product of the 
+    /// corresponding elements of the two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32,
+    ///     5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32
+    /// );
+    /// let other = Matrix4x3::new(
+    ///     2_i32, 3_i32, 4_i32, 2_i32,
+    ///     3_i32, 4_i32, 2_i32, 3_i32,
+    ///     4_i32, 2_i32, 3_i32, 4_i32
+    /// );
+    /// let expected = Matrix4x3::new(
+    ///     2_i32, 6_i32, 12_i32, 8_i32,
+    ///     15_i32, 24_i32, 14_i32, 24_i32,
+    ///     36_i32, 20_i32, 33_i32, 48_i32
+    /// );
+    /// let result = matrix.component_mul(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Matrix4x3::new(
+            self.data[0][0] * other.data[0][0], self.data[0][1] * other.data[0][1], self.data[0][2] * other.data[0][2], self.data[0][3] * other.data[0][3],
+            self.data[1][0] * other.data[1][0], self.data[1][1] * other.data[1][1], self.data[1][2] * other.data[1][2], self.data[1][3] * other.data[1][3],
+            self.data[2][0] * other.data[2][0], self.data[2][1] * other.data[2][1], self.data[2][2] * other.data[2][2], self.data[2][3] * other.data[2][3]
+        )
+    }
+
+    /// Compute the component-wise quotient of two matrices.
+    ///
+    /// Each element of the result is the element of `self` divided by the 
+    /// corresponding element of `other`. Division by a zero element follows 
+    /// the behavior of the scalar type: for integer scalars it panics, and for 
+    /// floating point scalars it produces an infinite or `NaN` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x3::new(
+    ///     2_f64, 6_f64, 12_f64, 8_f64,
+    ///     15_f64, 24_f64, 14_f64, 24_f64,
+    ///     36_f64, 20_f64, 33_f64, 48_f64
+    /// );
+    /// let other = Matrix4x3::new(
+    ///     2_f64, 3_f64, 4_f64, 2_f64,
+    ///     3_f64, 4_f64, 2_f64, 3_f64,
+    ///     4_f64, 2_f64, 3_f64, 4_f64
+    /// );
+    /// let expected = Matrix4x3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64,
+    ///     5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64
+    /// );
+    /// let result = matrix.component_div(&other);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn component_div(&self, other: &Self) -> Self {
+        Matrix4x3::new(
+            self.data[0][0] / other.data[0][0], self.data[0][1] / other.data[0][1], self.data[0][2] / other.data[0][2], self.data[0][3] / other.data[0][3],
+            self.data[1][0] / other.data[1][0], self.data[1][1] / other.data[1][1], self.data[1][2] / other.data[1][2], self.data[1][3] / other.data[1][3],
+            self.data[2][0] / other.data[2][0], self.data[2][1] / other.data[2][1], self.data[2][2] / other.data[2][2], self.data[2][3] / other.data[2][3]
+        )
+    }
+}
+
+impl<S> Matrix4x3<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the Moore-Penrose pseudoinverse of a matrix.
+    ///
+    /// The matrix has more rows than columns, so the pseudoinverse is 
+    /// computed from the normal equations as `(A^T * A)^-1 * A^T`. When the 
+    /// columns of the matrix are linearly independent, the result is a left 
+    /// inverse of the matrix. The function returns `None` if the Gram matrix 
+    /// `A^T * A` is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # }; 
+    /// # use cglinalg::{
+    /// #     Matrix4x3,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x3::new(
+    ///     1_f64, 0_f64, 3_f64, 1_f64,
+    ///     0_f64, 1_f64, 0_f64, 2_f64,
+    ///     2_f64, 1_f64, 1_f64, 0_f64
+    /// );
+    /// let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+    /// let expected = Matrix3x3::identity();
+    /// let result = pseudo_inverse * matrix;
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn pseudo_inverse(&self) -> Option<Matrix3x4<S>> {
+        let gram = self.gram();
+        
+        gram.inverse().map(|gram_inverse| gram_inverse * self.transpose())
+    }
 }
 
 impl<S> fmt::Display for Matrix4x3<S> 
@@ -9450,15 +12940,7 @@ where
     S: fmt::Display
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // NOTE: We display matrices in traditional row-major order.
-        write!(
-            formatter, 
-            "Matrix4x3 [[{}, {}, {}], [{}, {}, {}], [{}, {}, {}], [{}, {}, {}]]", 
-            self.data[0][0], self.data[1][0], self.data[2][0], 
-            self.data[0][1], self.data[1][1], self.data[2][1],
-            self.data[0][2], self.data[1][2], self.data[2][2],
-            self.data[0][3], self.data[1][3], self.data[2][3]
-        )
+        fmt_matrix(formatter, "Matrix4x3", &self.data)
     }
 }
 
@@ -9654,11 +13136,17 @@ macro_rules! impl_index_ops {
             }
         }
         
-        impl<S> ops::Index<(usize, usize)> for $MatrixNxM<S>{
+        /// Index a matrix element by a `(row, column)` pair, in conventional 
+        /// mathematical order. The element `matrix[(i, j)]` in row `i` and 
+        /// column `j` is the same element as `matrix[j][i]`, since indexing 
+        /// the underlying column-major storage takes the column first.
+        ///
+        /// Earlier versions of the library indexed by a `(column, row)` pair.
+        impl<S> ops::Index<(usize, usize)> for $MatrixNxM<S> {
             type Output = S;
         
             #[inline]
-            fn index(&self, (column, row): (usize, usize)) -> &Self::Output {
+            fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
                 let v: &[[S; $rows]; $columns] = self.as_ref();
                 &v[column][row]
             }
@@ -9666,7 +13154,7 @@ macro_rules! impl_index_ops {
         
         impl<S> ops::IndexMut<(usize, usize)> for $MatrixNxM<S> {
             #[inline]
-            fn index_mut(&mut self, (column, row): (usize, usize)) -> &mut Self::Output {
+            fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
                 let v: &mut [[S; $rows]; $columns] = self.as_mut();
                 &mut v[column][row]
             }
@@ -9689,6 +13177,105 @@ impl_index_ops!(Matrix3x4, Vector3, (3, 4));
 impl_index_ops!(Matrix4x3, Vector4, (4, 3));
 
 
+macro_rules! impl_matrix_default {
+    ($MatrixMxN:ident) => {
+        /// The default matrix is the zero matrix. 
+        ///
+        /// The zero matrix is used instead of the identity matrix because 
+        /// every matrix shape has one, and it matches the default value of the 
+        /// vector types.
+        impl<S> Default for $MatrixMxN<S> where S: Scalar {
+            #[inline]
+            fn default() -> Self {
+                $MatrixMxN::zero()
+            }
+        }
+    }
+}
+
+impl_matrix_default!(Matrix1x1);
+impl_matrix_default!(Matrix2x2);
+impl_matrix_default!(Matrix3x3);
+impl_matrix_default!(Matrix4x4);
+impl_matrix_default!(Matrix1x2);
+impl_matrix_default!(Matrix1x3);
+impl_matrix_default!(Matrix1x4);
+impl_matrix_default!(Matrix2x3);
+impl_matrix_default!(Matrix3x2);
+impl_matrix_default!(Matrix2x4);
+impl_matrix_default!(Matrix4x2);
+impl_matrix_default!(Matrix3x4);
+impl_matrix_default!(Matrix4x3);
+
+
+macro_rules! impl_matrix_try_from_slice {
+    ($MatrixMxN:ident, $n:expr) => {
+        /// Construct a matrix from a slice of its elements in column-major 
+        /// order.
+        ///
+        /// The conversion fails if the length of the slice is not the number 
+        /// of elements of the matrix.
+        impl<S> TryFrom<&[S]> for $MatrixMxN<S> where S: Scalar {
+            type Error = TryFromSliceError;
+
+            #[inline]
+            fn try_from(slice: &[S]) -> Result<Self, Self::Error> {
+                <[S; $n]>::try_from(slice).map($MatrixMxN::from)
+            }
+        }
+    }
+}
+
+impl_matrix_try_from_slice!(Matrix1x1, 1);
+impl_matrix_try_from_slice!(Matrix2x2, 4);
+impl_matrix_try_from_slice!(Matrix3x3, 9);
+impl_matrix_try_from_slice!(Matrix4x4, 16);
+impl_matrix_try_from_slice!(Matrix1x2, 2);
+impl_matrix_try_from_slice!(Matrix1x3, 3);
+impl_matrix_try_from_slice!(Matrix1x4, 4);
+impl_matrix_try_from_slice!(Matrix2x3, 6);
+impl_matrix_try_from_slice!(Matrix3x2, 6);
+impl_matrix_try_from_slice!(Matrix2x4, 8);
+impl_matrix_try_from_slice!(Matrix4x2, 8);
+impl_matrix_try_from_slice!(Matrix3x4, 12);
+impl_matrix_try_from_slice!(Matrix4x3, 12);
+
+
+macro_rules! impl_matrix_to_string_precision {
+    ($MatrixMxN:ident) => {
+        #[cfg(feature = "std")]
+        impl<S> $MatrixMxN<S> 
+        where 
+            S: ScalarFloat + fmt::Display
+        {
+            /// Format a matrix as a grid of rows, writing each element with 
+            /// `digits` digits after the decimal point.
+            ///
+            /// This produces the same output as formatting the matrix with 
+            /// `format!("{:.*}", digits, matrix)`.
+            #[inline]
+            pub fn to_string_precision(&self, digits: usize) -> std::string::String {
+                std::format!("{:.*}", digits, self)
+            }
+        }
+    }
+}
+
+impl_matrix_to_string_precision!(Matrix1x1);
+impl_matrix_to_string_precision!(Matrix2x2);
+impl_matrix_to_string_precision!(Matrix3x3);
+impl_matrix_to_string_precision!(Matrix4x4);
+impl_matrix_to_string_precision!(Matrix1x2);
+impl_matrix_to_string_precision!(Matrix1x3);
+impl_matrix_to_string_precision!(Matrix1x4);
+impl_matrix_to_string_precision!(Matrix2x3);
+impl_matrix_to_string_precision!(Matrix3x2);
+impl_matrix_to_string_precision!(Matrix2x4);
+impl_matrix_to_string_precision!(Matrix4x2);
+impl_matrix_to_string_precision!(Matrix3x4);
+impl_matrix_to_string_precision!(Matrix4x3);
+
+
 
 macro_rules! impl_matrix_matrix_binary_ops {
     ($OpType:ident, $op:ident, $op_impl:ident, $T:ty, $Output:ty, { $( ($col:expr, $row:expr) ),* }) => {
@@ -12014,3 +15601,115 @@ impl_approx_eq_ops!(
     (2, 0), (2, 1), (2, 2), (2, 3)
 });
 
+
+macro_rules! impl_square_matrix {
+    ($MatrixN:ident) => {
+        impl<S> SquareMatrix for $MatrixN<S> where S: ScalarFloat {
+            type Element = S;
+
+            #[inline]
+            fn identity() -> Self {
+                $MatrixN::identity()
+            }
+
+            #[inline]
+            fn is_identity(&self) -> bool {
+                $MatrixN::is_identity(self)
+            }
+
+            #[inline]
+            fn transpose(&self) -> Self {
+                $MatrixN::transpose(self)
+            }
+
+            #[inline]
+            fn trace(&self) -> Self::Element {
+                $MatrixN::trace(self)
+            }
+
+            #[inline]
+            fn determinant(&self) -> Self::Element {
+                $MatrixN::determinant(self)
+            }
+
+            #[inline]
+            fn is_invertible(&self) -> bool {
+                $MatrixN::is_invertible(self)
+            }
+
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                $MatrixN::inverse(self)
+            }
+        }
+    }
+}
+
+impl_square_matrix!(Matrix2x2);
+impl_square_matrix!(Matrix3x3);
+impl_square_matrix!(Matrix4x4);
+
+
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::ColumnMatrix2<S>> for Matrix2x2<S> {
+    #[inline]
+    fn from(other: mint::ColumnMatrix2<S>) -> Matrix2x2<S> {
+        Matrix2x2 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Matrix2x2<S>> for mint::ColumnMatrix2<S> {
+    #[inline]
+    fn from(other: Matrix2x2<S>) -> mint::ColumnMatrix2<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Matrix2x2<S> {
+    type MintType = mint::ColumnMatrix2<S>;
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::ColumnMatrix3<S>> for Matrix3x3<S> {
+    #[inline]
+    fn from(other: mint::ColumnMatrix3<S>) -> Matrix3x3<S> {
+        Matrix3x3 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Matrix3x3<S>> for mint::ColumnMatrix3<S> {
+    #[inline]
+    fn from(other: Matrix3x3<S>) -> mint::ColumnMatrix3<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Matrix3x3<S> {
+    type MintType = mint::ColumnMatrix3<S>;
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::ColumnMatrix4<S>> for Matrix4x4<S> {
+    #[inline]
+    fn from(other: mint::ColumnMatrix4<S>) -> Matrix4x4<S> {
+        Matrix4x4 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Matrix4x4<S>> for mint::ColumnMatrix4<S> {
+    #[inline]
+    fn from(other: Matrix4x4<S>) -> mint::ColumnMatrix4<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Matrix4x4<S> {
+    type MintType = mint::ColumnMatrix4<S>;
+}
@@ -1,4 +1,5 @@
 mod array;
+mod display;
 mod matrix;
 
 pub use matrix::*;
@@ -0,0 +1,256 @@
+use crate::base::{
+    Magnitude,
+    ScalarFloat,
+    Unit,
+};
+use crate::vector::{
+    Vector3,
+    Vector4,
+};
+use crate::point::{
+    Point3,
+};
+
+use core::fmt;
+
+
+/// A plane in three dimensions.
+///
+/// The plane is the set of points `p` satisfying the plane equation
+/// ```text
+/// dot(normal, p) + distance == 0
+/// ```
+/// Since the normal has unit length, `-distance` is the signed distance from
+/// the origin to the plane along the normal. The coefficients
+/// `(normal.x, normal.y, normal.z, distance)` are the same plane coefficients
+/// the `frustum_planes` functions of the projections return.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plane3<S> {
+    /// The unit normal vector to the plane.
+    pub normal: Unit<Vector3<S>>,
+    /// The constant term of the plane equation.
+    pub distance: S,
+}
+
+impl<S> Plane3<S> {
+    /// Construct a new plane from its unit normal vector and the constant
+    /// term of its plane equation.
+    #[inline]
+    pub const fn new(normal: Unit<Vector3<S>>, distance: S) -> Plane3<S> {
+        Plane3 {
+            normal: normal,
+            distance: distance,
+        }
+    }
+}
+
+impl<S> Plane3<S>
+where
+    S: ScalarFloat
+{
+    /// Construct the plane passing through three points.
+    ///
+    /// The normal of the plane follows the right-hand rule: it points towards
+    /// the side of the plane from which the points `a`, `b`, `c` appear in
+    /// counterclockwise order. The function returns `None` when the points
+    /// are collinear up to rounding error, since they do not determine a 
+    /// unique plane. The test is relative to the lengths of the sides `b - a` 
+    /// and `c - a`, so it does not depend on the size of the triangle.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Plane3,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let a = Point3::new(0_f64, 0_f64, 2_f64);
+    /// let b = Point3::new(1_f64, 0_f64, 2_f64);
+    /// let c = Point3::new(0_f64, 1_f64, 2_f64);
+    /// let plane = Plane3::from_points(&a, &b, &c).unwrap();
+    ///
+    /// assert_eq!(plane.normal.into_inner(), Vector3::unit_z());
+    /// assert_eq!(plane.distance, -2_f64);
+    ///
+    /// let collinear = Point3::new(2_f64, 0_f64, 2_f64);
+    ///
+    /// assert!(Plane3::from_points(&a, &b, &collinear).is_none());
+    /// ```
+    #[inline]
+    pub fn from_points(a: &Point3<S>, b: &Point3<S>, c: &Point3<S>) -> Option<Plane3<S>> {
+        let ab = b - a;
+        let ac = c - a;
+        // The magnitude of the cross product is |ab| * |ac| * sin(angle), so
+        // the tolerance has to scale with the lengths of the sides.
+        let tolerance = S::default_epsilon().sqrt() * ab.magnitude() * ac.magnitude();
+        Unit::try_from_value(ab.cross(&ac), tolerance).map(|normal| {
+            let distance = -normal.dot(&a.to_vector());
+
+            Plane3::new(normal, distance)
+        })
+    }
+
+    /// Construct a plane from the coefficients `(a, b, c, d)` of its plane
+    /// equation `a * x + b * y + c * z + d == 0`.
+    ///
+    /// The coefficients need not be normalized. The function divides them by
+    /// the length of `(a, b, c)`, and returns `None` when that length is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Plane3,
+    /// #     Vector3,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let coefficients = Vector4::new(0_f64, 3_f64, 4_f64, 10_f64);
+    /// let plane = Plane3::from_coefficients(&coefficients).unwrap();
+    ///
+    /// assert_eq!(plane.normal.into_inner(), Vector3::new(0_f64, 3_f64 / 5_f64, 4_f64 / 5_f64));
+    /// assert_eq!(plane.distance, 2_f64);
+    ///
+    /// let coefficients: Vector4<f64> = Vector4::unit_w();
+    ///
+    /// assert!(Plane3::from_coefficients(&coefficients).is_none());
+    /// ```
+    #[inline]
+    pub fn from_coefficients(coefficients: &Vector4<S>) -> Option<Plane3<S>> {
+        let normal = coefficients.contract();
+        Unit::try_from_value_with_magnitude(normal, S::zero()).map(|(normal, magnitude)| {
+            Plane3::new(normal, coefficients.w / magnitude)
+        })
+    }
+
+    /// Normalize a plane so that its normal vector has unit length.
+    ///
+    /// The normal of a plane is a unit vector when the plane is constructed,
+    /// but floating point rounding error can make its length drift away from
+    /// one. This function divides the normal and the distance by the length 
+    /// of the normal, so the plane equation describes the same plane.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Magnitude,
+    /// #     Plane3,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let a = Point3::new(1_f64, 0_f64, 0_f64);
+    /// let b = Point3::new(0_f64, 1_f64, 0_f64);
+    /// let c = Point3::new(0_f64, 0_f64, 1_f64);
+    /// let plane = Plane3::from_points(&a, &b, &c).unwrap();
+    /// let result = plane.normalize();
+    ///
+    /// assert!(relative_eq!(result.normal.magnitude(), 1_f64, epsilon = 1e-15));
+    /// assert!(relative_eq!(result.signed_distance(&a), 0_f64, epsilon = 1e-15));
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Plane3<S> {
+        let magnitude = self.normal.magnitude();
+        let normal = Unit::from_value_unchecked(self.normal.into_inner() / magnitude);
+
+        Plane3::new(normal, self.distance / magnitude)
+    }
+
+    /// Return the coefficients `(a, b, c, d)` of the plane equation
+    /// `a * x + b * y + c * z + d == 0` of a plane.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Plane3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let plane = Plane3::new(Unit::from_value(Vector3::unit_y()), -3_f64);
+    /// let expected = Vector4::new(0_f64, 1_f64, 0_f64, -3_f64);
+    /// let result = plane.to_coefficients();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn to_coefficients(&self) -> Vector4<S> {
+        self.normal.into_inner().extend(self.distance)
+    }
+
+    /// Compute the signed distance from a point to a plane.
+    ///
+    /// The distance is positive for points on the side of the plane the
+    /// normal points towards, negative for points on the other side, and zero
+    /// for points in the plane.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Plane3,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let plane = Plane3::new(Unit::from_value(Vector3::unit_z()), -1_f64);
+    ///
+    /// assert_eq!(plane.signed_distance(&Point3::new(4_f64, 5_f64, 3_f64)), 2_f64);
+    /// assert_eq!(plane.signed_distance(&Point3::new(4_f64, 5_f64, 1_f64)), 0_f64);
+    /// assert_eq!(plane.signed_distance(&Point3::new(4_f64, 5_f64, -1_f64)), -2_f64);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, point: &Point3<S>) -> S {
+        self.normal.dot(&point.to_vector()) + self.distance
+    }
+
+    /// Compute the orthogonal projection of a point onto a plane.
+    ///
+    /// The projection is the point in the plane closest to `point`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Plane3,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let plane = Plane3::new(Unit::from_value(Vector3::unit_z()), -1_f64);
+    /// let point = Point3::new(4_f64, 5_f64, 3_f64);
+    /// let expected = Point3::new(4_f64, 5_f64, 1_f64);
+    /// let result = plane.project_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(plane.signed_distance(&result), 0_f64);
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: &Point3<S>) -> Point3<S> {
+        point - self.normal.into_inner() * self.signed_distance(point)
+    }
+}
+
+impl<S> fmt::Display for Plane3<S>
+where
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Plane3 [normal: {}, distance: {}]",
+            self.normal, self.distance
+        )
+    }
+}
+
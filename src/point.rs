@@ -4,11 +4,15 @@ use crate::base::{
     ScalarSigned,
     ScalarFloat,
 };
+use crate::angle::{
+    Radians,
+};
 use crate::vector::{
     Vector1,
     Vector2,
     Vector3,
     Vector4,
+    XYZW,
 };
 use crate::{
     impl_coords,
@@ -18,11 +22,26 @@ use num_traits::{
     NumCast,
 };
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops;
 use core::ops::*;
 
 
+/// Compare two floating point numbers with a total order, where every `NaN` 
+/// is equal to every other `NaN` and greater than every other number.
+#[inline]
+fn total_cmp<S>(a: S, b: S) -> Ordering 
+where 
+    S: ScalarFloat
+{
+    match a.partial_cmp(&b) {
+        Some(ordering) => ordering,
+        None => a.is_nan().cmp(&b.is_nan()),
+    }
+}
+
+
 /// A point is a location in a one-dimensional Euclidean space.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -620,6 +639,130 @@ where
     }
 }
 
+impl<S> Point2<S> 
+where 
+    S: ScalarFloat
+{
+    /// Linearly interpolate between the two points `self` and `other`, with 
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
+    ///
+    /// The result always lies on the line segment between the two points.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point2, 
+    /// # };
+    /// #
+    /// let point1 = Point2::new(0_f64, 0_f64);
+    /// let point2 = Point2::new(2_f64, 4_f64);
+    ///
+    /// assert_eq!(point1.lerp_clamped(&point2, 2_f64), point2);
+    /// assert_eq!(point1.lerp_clamped(&point2, -1_f64), point1);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: &Point2<S>, amount: S) -> Point2<S> {
+        let amount = amount.max(S::zero()).min(S::one());
+
+        self + (other - self) * amount
+    }
+
+    /// Compute the barycentric coordinates of the point `p` with respect to 
+    /// the triangle with vertices `a`, `b`, and `c`.
+    ///
+    /// The barycentric coordinates `(u, v, w)` are the weights of the vertices 
+    /// `a`, `b`, and `c`, respectively, such that
+    /// ```text
+    /// p == u * a + v * b + w * c
+    /// ```
+    /// and `u + v + w == 1`. The point `p` lies inside the triangle when all 
+    /// three coordinates are nonnegative. The function returns `None` if the 
+    /// triangle is degenerate, i.e. it has zero area.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point2,
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let a = Point2::new(0_f64, 0_f64);
+    /// let b = Point2::new(4_f64, 0_f64);
+    /// let c = Point2::new(0_f64, 4_f64);
+    /// let p = Point2::new(1_f64, 2_f64);
+    /// let expected = Some(Vector3::new(0.25_f64, 0.25_f64, 0.5_f64));
+    /// let result = Point2::barycentric(&p, &a, &b, &c);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// // A degenerate triangle has no barycentric coordinate system.
+    /// let c = Point2::new(8_f64, 0_f64);
+    ///
+    /// assert!(Point2::barycentric(&p, &a, &b, &c).is_none());
+    /// ```
+    #[inline]
+    pub fn barycentric(
+        p: &Point2<S>, a: &Point2<S>, b: &Point2<S>, c: &Point2<S>) -> Option<Vector3<S>> 
+    {
+        let area = (b - a).perp_dot(&(c - a));
+        if area.is_zero() {
+            return None;
+        }
+
+        let u = (b - p).perp_dot(&(c - p)) / area;
+        let v = (c - p).perp_dot(&(a - p)) / area;
+        let w = S::one() - u - v;
+
+        Some(Vector3::new(u, v, w))
+    }
+
+    /// Returns `true` if the elements of a point are all finite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point2, 
+    /// # };
+    /// #
+    /// let point = Point2::new(1_f64, 2_f64);
+    ///
+    /// assert!(point.is_finite());
+    ///
+    /// let point = Point2::new(1_f64, f64::INFINITY);
+    ///
+    /// assert!(!point.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data.is_finite()
+    }
+
+    /// Returns `true` if any element of a point is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point2, 
+    /// # };
+    /// #
+    /// let point = Point2::new(1_f64, f64::NAN);
+    ///
+    /// assert!(point.is_nan());
+    ///
+    /// let point = Point2::new(1_f64, 2_f64);
+    ///
+    /// assert!(!point.is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data.is_nan()
+    }
+}
+
 impl<S> fmt::Display for Point2<S> 
 where 
     S: fmt::Display 
@@ -714,6 +857,28 @@ where
         Point2::new(self.data[0], self.data[1])
     }
 
+    /// Expand a three-dimensional point to a four-dimensional point using
+    /// the supplied **w-component**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Point4, 
+    /// # };
+    /// #
+    /// let point = Point3::new(1_u32, 2_u32, 3_u32);
+    /// let expected = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let result = point.extend(4_u32);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn extend(self, w: S) -> Point4<S> {
+        Point4::new(self.data[0], self.data[1], self.data[2], w)
+    }
+
     /// Construct a new point from a fill value.
     /// 
     /// ## Example
@@ -969,6 +1134,290 @@ where
     }
 }
 
+impl<S> Point3<S> 
+where 
+    S: ScalarFloat
+{
+    /// Compute the midpoint of the line segment between the points `self` 
+    /// and `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let point1 = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let point2 = Point3::new(2_f64, 4_f64, 6_f64);
+    /// let expected = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let result = point1.midpoint(&point2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn midpoint(&self, other: &Point3<S>) -> Point3<S> {
+        let one_half = num_traits::cast(0.5_f64).unwrap();
+
+        self + (other - self) * one_half
+    }
+
+    /// Linearly interpolate between the two points `self` and `other`, with 
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
+    ///
+    /// The result always lies on the line segment between the two points.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let point1 = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let point2 = Point3::new(2_f64, 4_f64, 6_f64);
+    ///
+    /// assert_eq!(point1.lerp_clamped(&point2, 2_f64), point2);
+    /// assert_eq!(point1.lerp_clamped(&point2, -1_f64), point1);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: &Point3<S>, amount: S) -> Point3<S> {
+        let amount = amount.max(S::zero()).min(S::one());
+
+        self + (other - self) * amount
+    }
+
+    /// Construct a point from spherical coordinates centered at the point 
+    /// `center`.
+    ///
+    /// The resulting point is `center` displaced by the vector 
+    /// `Vector3::from_spherical(radius, theta, phi)`, so the angles follow the 
+    /// same convention: the polar angle `theta` is measured from the 
+    /// **positive y-axis**, and the azimuthal angle `phi` is measured in the 
+    /// **zx-plane** from the **positive x-axis** towards the **positive z-axis**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Point3,
+    /// #     Radians,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let radius = 2_f64;
+    /// let theta = Radians::full_turn_div_4();
+    /// let phi = Radians::full_turn_div_4();
+    /// let expected = Point3::new(1_f64, 2_f64, 5_f64);
+    /// let result = Point3::from_spherical(&center, radius, theta, phi);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_spherical(center: &Point3<S>, radius: S, theta: Radians<S>, phi: Radians<S>) -> Point3<S> {
+        center + Vector3::from_spherical(radius, theta, phi)
+    }
+
+    /// Compute the centroid (the arithmetic mean) of a collection of points.
+    ///
+    /// The function returns `None` if the collection of points is empty.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let points = [
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(3_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 3_f64, 0_f64),
+    ///     Point3::new(0_f64, 0_f64, 3_f64),
+    /// ];
+    /// let expected = Some(Point3::new(0.75_f64, 0.75_f64, 0.75_f64));
+    /// let result = Point3::centroid(&points);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let points: [Point3<f64>; 0] = [];
+    ///
+    /// assert!(Point3::centroid(&points).is_none());
+    /// ```
+    #[inline]
+    pub fn centroid(points: &[Point3<S>]) -> Option<Point3<S>> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let count: S = num_traits::cast(points.len()).unwrap();
+        let mut sum = Vector3::zero();
+        for point in points.iter() {
+            sum += point.to_vector();
+        }
+
+        Some(Point3::from_vector(sum / count))
+    }
+
+    /// Returns `true` if the elements of a point are all finite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert!(point.is_finite());
+    ///
+    /// let point = Point3::new(1_f64, f64::INFINITY, 3_f64);
+    ///
+    /// assert!(!point.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data.is_finite()
+    }
+
+    /// Returns `true` if any element of a point is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let point = Point3::new(1_f64, f64::NAN, 3_f64);
+    ///
+    /// assert!(point.is_nan());
+    ///
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert!(!point.is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data.is_nan()
+    }
+
+    /// Evaluate the cubic Hermite curve between the points `p0` and `p1` with 
+    /// tangents `m0` and `m1` at the parameter `t`.
+    ///
+    /// The curve starts at `p0` when `t == 0` with velocity `m0`, and ends at
+    /// `p1` when `t == 1` with velocity `m1`. For a curve through a 
+    /// sequence of points, the parameter `t` usually lies in `[0, 1]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let p0 = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let p1 = Point3::new(2_f64, 0_f64, 0_f64);
+    /// let m0 = Vector3::new(2_f64, 0_f64, 0_f64);
+    /// let m1 = Vector3::new(2_f64, 0_f64, 0_f64);
+    ///
+    /// assert_eq!(Point3::hermite(&p0, &m0, &p1, &m1, 0_f64), p0);
+    /// assert_eq!(Point3::hermite(&p0, &m0, &p1, &m1, 0.5_f64), Point3::new(1_f64, 0_f64, 0_f64));
+    /// assert_eq!(Point3::hermite(&p0, &m0, &p1, &m1, 1_f64), p1);
+    /// ```
+    #[inline]
+    pub fn hermite(p0: &Point3<S>, m0: &Vector3<S>, p1: &Point3<S>, m1: &Vector3<S>, t: S) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let three = two + one;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        // The basis functions weighting `p0` and `p1` sum to one, so the curve 
+        // is an affine combination of the endpoints plus the tangent terms.
+        let h01 = -two * t3 + three * t2;
+        let h10 = t3 - two * t2 + t;
+        let h11 = t3 - t2;
+
+        p0 + (p1 - p0) * h01 + m0 * h10 + m1 * h11
+    }
+
+    /// Evaluate the uniform Catmull-Rom spline segment between the points 
+    /// `p1` and `p2` at the parameter `t`.
+    ///
+    /// The points `p0` and `p3` are the neighbouring control points, which
+    /// determine the tangents at `p1` and `p2`. The segment passes through 
+    /// `p1` when `t == 0` and through `p2` when `t == 1`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// #
+    /// let p0 = Point3::new(-1_f64, 0_f64, 0_f64);
+    /// let p1 = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let p2 = Point3::new(1_f64, 1_f64, 0_f64);
+    /// let p3 = Point3::new(2_f64, 1_f64, 0_f64);
+    ///
+    /// assert_eq!(Point3::catmull_rom(&p0, &p1, &p2, &p3, 0_f64), p1);
+    /// assert_eq!(Point3::catmull_rom(&p0, &p1, &p2, &p3, 1_f64), p2);
+    /// ```
+    #[inline]
+    pub fn catmull_rom(p0: &Point3<S>, p1: &Point3<S>, p2: &Point3<S>, p3: &Point3<S>, t: S) -> Point3<S> {
+        let one_half = num_traits::cast(0.5_f64).unwrap();
+        let m1 = (p2 - p0) * one_half;
+        let m2 = (p3 - p1) * one_half;
+
+        Point3::hermite(p1, &m1, p2, &m2, t)
+    }
+
+    /// Compare two points lexicographically, first by their **x-components**, 
+    /// then by their **y-components**, and then by their **z-components**.
+    ///
+    /// Floating point numbers only have a partial order, so this function 
+    /// uses a total order on the components: every `NaN` compares equal to 
+    /// every other `NaN` and greater than every other number, and `-0.0`
+    /// compares equal to `0.0`. This makes the function usable with sorting 
+    /// functions such as `sort_by`, which need a total order even in the 
+    /// presence of `NaN` components.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3, 
+    /// # };
+    /// # use core::cmp::Ordering;
+    /// #
+    /// let mut points = [
+    ///     Point3::new(1_f64, 2_f64, 3_f64),
+    ///     Point3::new(f64::NAN, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 2_f64, -3_f64),
+    ///     Point3::new(-1_f64, 5_f64, 0_f64),
+    /// ];
+    /// points.sort_by(|a, b| a.cmp_lexicographic(b));
+    ///
+    /// assert_eq!(points[0], Point3::new(-1_f64, 5_f64, 0_f64));
+    /// assert_eq!(points[1], Point3::new(1_f64, 2_f64, -3_f64));
+    /// assert_eq!(points[2], Point3::new(1_f64, 2_f64, 3_f64));
+    /// assert!(points[3].x.is_nan());
+    /// assert_eq!(points[0].cmp_lexicographic(&points[0]), Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn cmp_lexicographic(&self, other: &Point3<S>) -> Ordering {
+        total_cmp(self.data[0], other.data[0])
+            .then_with(|| total_cmp(self.data[1], other.data[1]))
+            .then_with(|| total_cmp(self.data[2], other.data[2]))
+    }
+}
+
 impl<S> fmt::Display for Point3<S> 
 where 
     S: fmt::Display
@@ -1027,6 +1476,298 @@ where
 }
 
 
+/// A representation of four-dimensional points in a Euclidean space.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Point4<S> {
+    data: Vector4<S>,
+}
+
+impl<S> Point4<S> {
+    /// Construct a new point in four-dimensional Euclidean space.
+    #[inline]
+    pub const fn new(x: S, y: S, z: S, w: S) -> Point4<S> {
+        Point4 { 
+            data: Vector4::new(x, y, z, w),
+        }
+    }
+}
+
+impl<S> Point4<S> 
+where 
+    S: Copy
+{
+    /// Contract a four-dimensional point, removing its **w-component**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Point4, 
+    /// # };
+    /// #
+    /// let point = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected = Point3::new(1_u32, 2_u32, 3_u32);
+    /// let result = point.contract();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn contract(self) -> Point3<S> {
+        Point3::new(self.data[0], self.data[1], self.data[2])
+    }
+
+    /// Construct a new point from a fill value.
+    /// 
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4, 
+    /// # };
+    /// #
+    /// let fill_value = 3_u32;
+    /// let expected = Point4::new(3_u32, 3_u32, 3_u32, 3_u32);
+    /// let result = Point4::from_fill(fill_value);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Point4<S> {
+        Point4::new(value, value, value, value)
+    }
+
+    /// The length of the the underlying array storing the point components.
+    #[allow(clippy::len_without_is_empty)]
+    #[inline]
+    pub fn len(&self) -> usize {
+        4
+    }
+
+    /// The shape of the underlying array storing the point components.
+    ///
+    /// The shape is the equivalent number of columns and rows of the 
+    /// array as though it represents a matrix. The order of the descriptions 
+    /// of the shape of the array is **(rows, columns)**.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (4, 1)
+    }
+
+    /// Get a pointer to the underlying array.
+    #[inline]
+    pub fn as_ptr(&self) -> *const S {
+        self.data.as_ptr()
+    }
+
+    /// Get a mutable pointer to the underlying array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        self.data.as_mut_ptr()
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 4]>>::as_ref(self)
+    }
+
+    /// Map an operation on that acts on the coordinates of a point, returning 
+    /// a point whose coordinates are of the new scalar type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4,  
+    /// # };
+    /// #
+    /// let vector: Point4<u32> = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected: Point4<i32> = Point4::new(2_i32, 3_i32, 4_i32, 5_i32);
+    /// let result: Point4<i32> = vector.map(|comp| (comp + 1) as i32);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn map<T, F>(self, op: F) -> Point4<T> 
+    where 
+        F: FnMut(S) -> T
+    {
+        Point4 {
+            data: self.data.map(op),
+        }
+    }
+}
+
+impl<S> Point4<S> 
+where 
+    S: NumCast + Copy
+{
+    /// Cast a point from one type of scalars to another type of scalars.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4,   
+    /// # };
+    /// #
+    /// let point: Point4<u32> = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected: Option<Point4<i32>> = Some(Point4::new(1_i32, 2_i32, 3_i32, 4_i32));
+    /// let result = point.cast::<i32>();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Point4<T>> {
+        let x = num_traits::cast(self.data[0])?;
+        let y = num_traits::cast(self.data[1])?;
+        let z = num_traits::cast(self.data[2])?;
+        let w = num_traits::cast(self.data[3])?;
+
+        Some(Point4::new(x, y, z, w))
+    }
+}
+
+impl<S> Point4<S> 
+where 
+    S: Scalar
+{
+    /// Compute the origin of the Euclidean vector space.
+    #[inline]
+    pub fn origin() -> Point4<S> {
+        Point4::new(S::zero(), S::zero(), S::zero(), S::zero())
+    }
+
+    /// Convert a vector to a point. 
+    /// 
+    /// Points are locations in Euclidean space, whereas vectors
+    /// are displacements relative to the origin in Euclidean space.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let result = Point4::from_vector(vector);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_vector(vector: Vector4<S>) -> Point4<S> {
+        Point4 {
+            data: vector,
+        }
+    }
+
+    /// Convert a point to a vector.
+    /// 
+    /// Points are locations in Euclidean space, whereas vectors
+    /// are displacements relative to the origin in Euclidean space.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let point = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let result = point.to_vector();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn to_vector(self) -> Vector4<S> {
+        self.data
+    }
+
+    /// Compute the dot product (inner product) of two points.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point4, 
+    /// # };
+    /// #
+    /// let point1 = Point4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let point2 = Point4::new(5_f64, 6_f64, 7_f64, 8_f64);
+    /// 
+    /// assert_eq!(point1.dot(&point2), 70_f64);
+    /// ```
+    #[inline]
+    pub fn dot(self, other: &Point4<S>) -> S {
+        self.data.dot(&other.data)
+    }
+}
+
+impl<S> fmt::Display for Point4<S> 
+where 
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter, 
+            "Point4 [{}, {}, {}, {}]", 
+            self.data[0], self.data[1], self.data[2], self.data[3]
+        )
+    }
+}
+
+impl<S> From<(S, S, S, S)> for Point4<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from((x, y, z, w): (S, S, S, S)) -> Point4<S> {
+        Point4::new(x, y, z, w)
+    }
+}
+
+impl<S> From<[S; 4]> for Point4<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(v: [S; 4]) -> Point4<S> {
+        Point4::new(v[0], v[1], v[2], v[3])
+    }
+}
+
+impl<'a, S> From<&'a [S; 4]> for &'a Point4<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(v: &'a [S; 4]) -> &'a Point4<S> {
+        unsafe { 
+            &*(v as *const [S; 4] as *const Point4<S>)
+        }
+    }
+}
+
+impl<'a, S> From<&'a (S, S, S, S)> for &'a Point4<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &'a (S, S, S, S)) -> &'a Point4<S> {
+        unsafe { 
+            &*(v as *const (S, S, S, S) as *const Point4<S>)
+        }
+    }
+}
+
+
 impl_coords!(X, { x });
 impl_coords_deref!(Point1, X);
 
@@ -1036,6 +1777,9 @@ impl_coords_deref!(Point2, XY);
 impl_coords!(XYZ, { x, y, z });
 impl_coords_deref!(Point3, XYZ);
 
+// The four-dimensional point shares its component view with `Vector4`.
+impl_coords_deref!(Point4, XYZW);
+
 
 macro_rules! impl_as_ref_ops {
     ($PointType:ty, $RefType:ty) => {
@@ -1069,6 +1813,9 @@ impl_as_ref_ops!(Point2<S>, [S; 2]);
 impl_as_ref_ops!(Point3<S>, (S, S, S));
 impl_as_ref_ops!(Point3<S>, [S; 3]);
 
+impl_as_ref_ops!(Point4<S>, (S, S, S, S));
+impl_as_ref_ops!(Point4<S>, [S; 4]);
+
 
 macro_rules! impl_point_index_ops {
     ($T:ty, $n:expr, $IndexType:ty, $Output:ty) => {
@@ -1110,6 +1857,30 @@ impl_point_index_ops!(Point3<S>, 3, RangeTo<usize>, [S]);
 impl_point_index_ops!(Point3<S>, 3, RangeFrom<usize>, [S]);
 impl_point_index_ops!(Point3<S>, 3, RangeFull, [S]);
 
+impl_point_index_ops!(Point4<S>, 4, usize, S);
+impl_point_index_ops!(Point4<S>, 4, Range<usize>, [S]);
+impl_point_index_ops!(Point4<S>, 4, RangeTo<usize>, [S]);
+impl_point_index_ops!(Point4<S>, 4, RangeFrom<usize>, [S]);
+impl_point_index_ops!(Point4<S>, 4, RangeFull, [S]);
+
+
+macro_rules! impl_point_default {
+    ($PointN:ident) => {
+        /// The default point is the origin.
+        impl<S> Default for $PointN<S> where S: Scalar {
+            #[inline]
+            fn default() -> Self {
+                $PointN::origin()
+            }
+        }
+    }
+}
+
+impl_point_default!(Point1);
+impl_point_default!(Point2);
+impl_point_default!(Point3);
+impl_point_default!(Point4);
+
 
 macro_rules! impl_point_vector_binary_ops {
     ($OpType:ident, $op:ident, $T1:ty, $T2:ty, $Output:ty, { $($index:expr),* }) => {
@@ -1171,6 +1942,10 @@ impl_point_vector_binary_ops!(Add, add, Point3<S>, Vector3<S>, Point3<S>, { 0, 1
 impl_point_vector_binary_ops!(Sub, sub, Point3<S>, Vector3<S>, Point3<S>, { 0, 1, 2 });
 impl_point_vector_binary_ops!(Sub, sub, Point3<S>, Point3<S>, Vector3<S>, { 0, 1, 2 });
 
+impl_point_vector_binary_ops!(Add, add, Point4<S>, Vector4<S>, Point4<S>, { 0, 1, 2, 3 });
+impl_point_vector_binary_ops!(Sub, sub, Point4<S>, Vector4<S>, Point4<S>, { 0, 1, 2, 3 });
+impl_point_vector_binary_ops!(Sub, sub, Point4<S>, Point4<S>, Vector4<S>, { 0, 1, 2, 3 });
+
 
 macro_rules! impl_point_scalar_binary_ops {
     ($OpType:ident, $op:ident, $T:ty, $Output:ty, { $($index:expr),* }) => {
@@ -1210,6 +1985,10 @@ impl_point_scalar_binary_ops!(Mul, mul, Point3<S>, Point3<S>, { 0, 1, 2 });
 impl_point_scalar_binary_ops!(Div, div, Point3<S>, Point3<S>, { 0, 1, 2 });
 impl_point_scalar_binary_ops!(Rem, rem, Point3<S>, Point3<S>, { 0, 1, 2 });
 
+impl_point_scalar_binary_ops!(Mul, mul, Point4<S>, Point4<S>, { 0, 1, 2, 3 });
+impl_point_scalar_binary_ops!(Div, div, Point4<S>, Point4<S>, { 0, 1, 2, 3 });
+impl_point_scalar_binary_ops!(Rem, rem, Point4<S>, Point4<S>, { 0, 1, 2, 3 });
+
 
 macro_rules! impl_scalar_point_mul_ops {
     ($Lhs:ty, $Rhs:ty, $Output:ty, { $($index:expr),* }) => {
@@ -1278,6 +2057,21 @@ impl_scalar_point_mul_ops!(isize, Point3<isize>, Point3<isize>, { 0, 1, 2 });
 impl_scalar_point_mul_ops!(f32,   Point3<f32>,   Point3<f32>,   { 0, 1, 2 });
 impl_scalar_point_mul_ops!(f64,   Point3<f64>,   Point3<f64>,   { 0, 1, 2 });
 
+impl_scalar_point_mul_ops!(u8,    Point4<u8>,    Point4<u8>,    { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(u16,   Point4<u16>,   Point4<u16>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(u32,   Point4<u32>,   Point4<u32>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(u64,   Point4<u64>,   Point4<u64>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(u128,  Point4<u128>,  Point4<u128>,  { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(usize, Point4<usize>, Point4<usize>, { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(i8,    Point4<i8>,    Point4<i8>,    { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(i16,   Point4<i16>,   Point4<i16>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(i32,   Point4<i32>,   Point4<i32>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(i64,   Point4<i64>,   Point4<i64>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(i128,  Point4<i128>,  Point4<i128>,  { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(isize, Point4<isize>, Point4<isize>, { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(f32,   Point4<f32>,   Point4<f32>,   { 0, 1, 2, 3 });
+impl_scalar_point_mul_ops!(f64,   Point4<f64>,   Point4<f64>,   { 0, 1, 2, 3 });
+
 
 macro_rules! impl_point_unary_ops {
     ($OpType:ident, $op:ident, $T:ty, $Output:ty, { $($index:expr),* }) => {
@@ -1308,6 +2102,7 @@ macro_rules! impl_point_unary_ops {
 impl_point_unary_ops!(Neg, neg, Point1<S>, Point1<S>, { 0 });
 impl_point_unary_ops!(Neg, neg, Point2<S>, Point2<S>, { 0, 1 });
 impl_point_unary_ops!(Neg, neg, Point3<S>, Point3<S>, { 0, 1, 2 });
+impl_point_unary_ops!(Neg, neg, Point4<S>, Point4<S>, { 0, 1, 2, 3 });
 
 
 macro_rules! impl_point_binary_assign_ops {
@@ -1366,6 +2161,7 @@ macro_rules! impl_point_binary_assign_ops {
 impl_point_binary_assign_ops!(Point1<S>, Vector1<S>, { 0 });
 impl_point_binary_assign_ops!(Point2<S>, Vector2<S>, { 0, 1 });
 impl_point_binary_assign_ops!(Point3<S>, Vector3<S>, { 0, 1, 2 });
+impl_point_binary_assign_ops!(Point4<S>, Vector4<S>, { 0, 1, 2, 3 });
 
 
 macro_rules! impl_magnitude {
@@ -1420,6 +2216,7 @@ macro_rules! impl_magnitude {
 impl_magnitude!(Point1);
 impl_magnitude!(Point2);
 impl_magnitude!(Point3);
+impl_magnitude!(Point4);
 
 
 macro_rules! impl_approx_eq_ops {
@@ -1467,6 +2264,7 @@ macro_rules! impl_approx_eq_ops {
 impl_approx_eq_ops!(Point1, Vector1);
 impl_approx_eq_ops!(Point2, Vector2);
 impl_approx_eq_ops!(Point3, Vector3);
+impl_approx_eq_ops!(Point4, Vector4);
 
 
 macro_rules! impl_swizzle {
@@ -1535,3 +2333,49 @@ impl_swizzle!(zzx() => Point3 => Point3 { 2, 2, 0 });
 impl_swizzle!(zzy() => Point3 => Point3 { 2, 2, 1 });
 impl_swizzle!(zzz() => Point3 => Point3 { 2, 2, 2 });
 
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Point2<S>> for Point2<S> {
+    #[inline]
+    fn from(other: mint::Point2<S>) -> Point2<S> {
+        Point2::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Point2<S>> for mint::Point2<S> {
+    #[inline]
+    fn from(other: Point2<S>) -> mint::Point2<S> {
+        let vector: mint::Vector2<S> = other.data.into();
+
+        mint::Point2 { x: vector.x, y: vector.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Point2<S> {
+    type MintType = mint::Point2<S>;
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Point3<S>> for Point3<S> {
+    #[inline]
+    fn from(other: mint::Point3<S>) -> Point3<S> {
+        Point3::new(other.x, other.y, other.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Point3<S>> for mint::Point3<S> {
+    #[inline]
+    fn from(other: Point3<S>) -> mint::Point3<S> {
+        let vector: mint::Vector3<S> = other.data.into();
+
+        mint::Point3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Point3<S> {
+    type MintType = mint::Point3<S>;
+}
@@ -12,7 +12,9 @@ use crate::point::{
     Point3,
 };
 use crate::vector::{
+    Vector2,
     Vector3,
+    Vector4,
 };
 
 use core::fmt;
@@ -85,6 +87,53 @@ where
         }
     }
 
+    /// Construct a perspective projection whose projected points are offset 
+    /// by a fixed amount in normalized device coordinates.
+    ///
+    /// This is the sub-pixel jitter used for temporal anti-aliasing. To shift 
+    /// the image by a fraction of a pixel, use an offset of `2 * dx / width` 
+    /// horizontally and `2 * dy / height` vertically, where `width` and 
+    /// `height` are the dimensions of the viewport in pixels. Shifting the 
+    /// projected points is the same as sliding the `left`, `right`, `bottom`,
+    /// and `top` planes of the frustum, so the result is again a perspective
+    /// projection.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// #     Point3,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-2_f64, 2_f64, -1_f64, 1_f64, 1_f64, 100_f64);
+    /// let jittered = perspective.with_jitter(Vector2::new(0.5_f64, -0.25_f64));
+    /// let point = Point3::new(1_f64, 1_f64, -10_f64);
+    /// let projected = perspective.project_point(&point);
+    /// let expected = Point3::new(projected.x + 0.5_f64, projected.y - 0.25_f64, projected.z);
+    /// let result = jittered.project_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn with_jitter(&self, offset: Vector2<S>) -> Perspective3<S> {
+        let one_half: S = num_traits::cast(0.5_f64).unwrap();
+        let shift_x = offset.x * (self.right - self.left) * one_half;
+        let shift_y = offset.y * (self.top - self.bottom) * one_half;
+
+        Perspective3::new(
+            self.left - shift_x,
+            self.right - shift_x,
+            self.bottom - shift_y,
+            self.top - shift_y,
+            self.near,
+            self.far,
+        )
+    }
+
     /// Get the near plane along the **negative z-axis**.
     #[inline]
     pub fn near_z(&self) -> S {
@@ -99,14 +148,14 @@ where
 
     /// Get the left plane along the **negative x-axis**.
     #[inline]
-    pub fn left_x(&self)-> S {
-        self.near
+    pub fn left_x(&self) -> S {
+        self.left
     }
 
     /// Get the right plane along the **positive x-axis**.
     #[inline]
     pub fn right_x(&self) -> S {
-        self.far
+        self.right
     }
 
     /// Get the bottom plane along the **negative y-axis**.
@@ -188,7 +237,7 @@ where
         
         Point3::new(
             (self.matrix.c0r0 * point.x + self.matrix.c2r0 * point.z) * inverse_w,
-            (self.matrix.c1r1 * point.y + self.matrix.c3r1 * point.z) * inverse_w,
+            (self.matrix.c1r1 * point.y + self.matrix.c2r1 * point.z) * inverse_w,
             (self.matrix.c2r2 * point.z + self.matrix.c3r2) * inverse_w
         )
     }
@@ -370,6 +419,194 @@ where
             c3r2 * inverse_w,
         )
     }
+
+    /// Compute the eight corners of the view frustum in camera view space.
+    ///
+    /// The corners are obtained by unprojecting the corners of the normalized 
+    /// device coordinate cube. The first four corners lie on the near plane 
+    /// and the last four corners lie on the far plane. On each plane the 
+    /// corners are ordered bottom-left, bottom-right, top-right, top-left.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let left = -3_f64;
+    /// let right = 3_f64;
+    /// let bottom = -2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// let corners = perspective.frustum_corners();
+    ///
+    /// for corner in corners[0..4].iter() {
+    ///     assert!(relative_eq!(corner.z, -near, epsilon = 1e-8));
+    /// }
+    /// for corner in corners[4..8].iter() {
+    ///     assert!(relative_eq!(corner.z, -far, epsilon = 1e-8));
+    /// }
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn frustum_corners(&self) -> [Point3<S>; 8] {
+        let one = S::one();
+
+        [
+            self.unproject_point(&Point3::new(-one, -one, -one)),
+            self.unproject_point(&Point3::new( one, -one, -one)),
+            self.unproject_point(&Point3::new( one,  one, -one)),
+            self.unproject_point(&Point3::new(-one,  one, -one)),
+            self.unproject_point(&Point3::new(-one, -one,  one)),
+            self.unproject_point(&Point3::new( one, -one,  one)),
+            self.unproject_point(&Point3::new( one,  one,  one)),
+            self.unproject_point(&Point3::new(-one,  one,  one)),
+        ]
+    }
+
+    /// Compute the six clipping planes of the view frustum in camera view space.
+    ///
+    /// Each plane is returned as the coefficients `(a, b, c, d)` of the plane
+    /// equation `a * x + b * y + c * z + d == 0`, normalized such that
+    /// `a^2 + b^2 + c^2 == 1`. The normals point into the frustum, and the
+    /// planes are ordered left, right, bottom, top, near, far.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let left = -3_f64;
+    /// let right = 3_f64;
+    /// let bottom = -2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// let planes = perspective.frustum_planes();
+    /// let inside = Vector4::new(0_f64, 0_f64, -50_f64, 1_f64);
+    ///
+    /// assert!(planes.iter().all(|plane| plane.dot(&inside) >= 0_f64));
+    /// ```
+    #[inline]
+    pub fn frustum_planes(&self) -> [Vector4<S>; 6] {
+        self.matrix.extract_frustum_planes()
+    }
+
+    /// Map a point in screen space back to camera view space.
+    ///
+    /// The `viewport` is given as `(x, y, width, height)` in pixels, with the
+    /// origin `(x, y)` at the bottom-left corner of the viewport. The `x` and 
+    /// `y` components of `screen` are pixel coordinates, and the `z` component 
+    /// is the depth in normalized device coordinates. The screen point is first 
+    /// mapped to normalized device coordinates and then unprojected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let left = -3_f64;
+    /// let right = 3_f64;
+    /// let bottom = -2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// let viewport = (0_f64, 0_f64, 800_f64, 600_f64);
+    /// let screen = Point3::new(400_f64, 300_f64, -1_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, -near);
+    /// let result = perspective.screen_to_world(&screen, viewport);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn screen_to_world(&self, screen: &Point3<S>, viewport: (S, S, S, S)) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let (x, y, width, height) = viewport;
+        let ndc = Point3::new(
+            two * (screen.x - x) / width - one,
+            two * (screen.y - y) / height - one,
+            screen.z
+        );
+
+        self.unproject_point(&ndc)
+    }
+
+    /// Compute a projection matrix whose near plane is replaced by an 
+    /// arbitrary clipping plane.
+    ///
+    /// This is the oblique near-plane clipping technique of Eric Lengyel, 
+    /// used for rendering planar reflections and portals. The `plane` is 
+    /// given in camera view space as the coefficients `(a, b, c, d)` of 
+    /// the plane equation `a * x + b * y + c * z + d == 0`. Points with 
+    /// `a * x + b * y + c * z + d >= 0` lie in front of the plane and are 
+    /// kept; for the technique to work the camera must lie behind the plane,
+    /// i.e. `d < 0`.
+    ///
+    /// Only the third row of the projection matrix changes. The far plane 
+    /// of the resulting frustum is no longer parallel to the near plane,
+    /// which reduces depth precision, so the result is returned as a bare 
+    /// matrix rather than as a perspective projection described by six
+    /// planes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-1_f64, 1_f64, -1_f64, 1_f64, 1_f64, 100_f64);
+    /// // The plane `z == -5` facing away from the camera.
+    /// let plane = Vector4::new(0_f64, 0_f64, -1_f64, -5_f64);
+    /// let matrix = perspective.clip_to_plane(&plane);
+    /// let on_plane = matrix * Vector4::new(1_f64, 2_f64, -5_f64, 1_f64);
+    ///
+    /// // Points on the clipping plane land on the near plane of clip space.
+    /// assert!(f64::abs(on_plane.z / on_plane.w + 1_f64) < 1e-10);
+    /// ```
+    pub fn clip_to_plane(&self, plane: &Vector4<S>) -> Matrix4x4<S> {
+        let one = S::one();
+        let two = one + one;
+        let mut matrix = self.matrix;
+        // Find the corner of the view frustum opposite the clipping plane in 
+        // clip space, and transform it back into camera view space. The 
+        // perspective projection matrix is sparse enough that the inverse 
+        // transformation only needs the nonzero elements.
+        let q = Vector4::new(
+            (plane.x.signum() + matrix.c2r0) / matrix.c0r0,
+            (plane.y.signum() + matrix.c2r1) / matrix.c1r1,
+            -one,
+            (one + matrix.c2r2) / matrix.c3r2,
+        );
+        // Scale the plane so the new near plane passes through the plane, 
+        // and the far plane passes through the corner `q`.
+        let scaled_plane = plane * (two / plane.dot(&q));
+        matrix.c0r2 = scaled_plane.x - matrix.c0r3;
+        matrix.c1r2 = scaled_plane.y - matrix.c1r3;
+        matrix.c2r2 = scaled_plane.z - matrix.c2r3;
+        matrix.c3r2 = scaled_plane.w - matrix.c3r3;
+
+        matrix
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for Perspective3<S> {
@@ -575,6 +812,35 @@ where
         self.aspect
     }
 
+    /// Construct a new perspective projection with the same field of view angle, 
+    /// near plane and far plane, but with a different aspect ratio.
+    ///
+    /// This is useful for updating a projection when the viewport is resized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     PerspectiveFov3,
+    /// #     Degrees,
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = PerspectiveFov3::new(vfov, aspect, near, far);
+    /// let new_aspect = 1920_f64 / 1080_f64;
+    /// let expected = PerspectiveFov3::new(vfov, new_aspect, near, far);
+    /// let result = perspective.with_aspect(new_aspect);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn with_aspect(&self, aspect: S) -> Self {
+        Self::new(self.vfov, aspect, self.near, self.far)
+    }
+
     /// Get the matrix that implements the perspective projection transformation.
     ///
     /// ## Example
@@ -849,6 +1115,132 @@ where
             c3r2 * inverse_w,
         )
     }
+
+    /// Compute the eight corners of the view frustum in camera view space.
+    ///
+    /// The corners are obtained by unprojecting the corners of the normalized 
+    /// device coordinate cube. The first four corners lie on the near plane 
+    /// and the last four corners lie on the far plane. On each plane the 
+    /// corners are ordered bottom-left, bottom-right, top-right, top-left.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     PerspectiveFov3,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = PerspectiveFov3::new(vfov, aspect, near, far);
+    /// let corners = perspective.frustum_corners();
+    ///
+    /// for corner in corners[0..4].iter() {
+    ///     assert!(relative_eq!(corner.z, -near, epsilon = 1e-8));
+    /// }
+    /// for corner in corners[4..8].iter() {
+    ///     assert!(relative_eq!(corner.z, -far, epsilon = 1e-8));
+    /// }
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn frustum_corners(&self) -> [Point3<S>; 8] {
+        let one = S::one();
+
+        [
+            self.unproject_point(&Point3::new(-one, -one, -one)),
+            self.unproject_point(&Point3::new( one, -one, -one)),
+            self.unproject_point(&Point3::new( one,  one, -one)),
+            self.unproject_point(&Point3::new(-one,  one, -one)),
+            self.unproject_point(&Point3::new(-one, -one,  one)),
+            self.unproject_point(&Point3::new( one, -one,  one)),
+            self.unproject_point(&Point3::new( one,  one,  one)),
+            self.unproject_point(&Point3::new(-one,  one,  one)),
+        ]
+    }
+
+    /// Compute the six clipping planes of the view frustum in camera view space.
+    ///
+    /// Each plane is returned as the coefficients `(a, b, c, d)` of the plane
+    /// equation `a * x + b * y + c * z + d == 0`, normalized such that
+    /// `a^2 + b^2 + c^2 == 1`. The normals point into the frustum, and the
+    /// planes are ordered left, right, bottom, top, near, far.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     PerspectiveFov3,
+    /// #     Degrees,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = PerspectiveFov3::new(vfov, aspect, near, far);
+    /// let planes = perspective.frustum_planes();
+    /// let inside = Vector4::new(0_f64, 0_f64, -50_f64, 1_f64);
+    ///
+    /// assert!(planes.iter().all(|plane| plane.dot(&inside) >= 0_f64));
+    /// ```
+    #[inline]
+    pub fn frustum_planes(&self) -> [Vector4<S>; 6] {
+        self.matrix.extract_frustum_planes()
+    }
+
+    /// Map a point in screen space back to camera view space.
+    ///
+    /// The `viewport` is given as `(x, y, width, height)` in pixels, with the
+    /// origin `(x, y)` at the bottom-left corner of the viewport. The `x` and 
+    /// `y` components of `screen` are pixel coordinates, and the `z` component 
+    /// is the depth in normalized device coordinates. The screen point is first 
+    /// mapped to normalized device coordinates and then unprojected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     PerspectiveFov3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = PerspectiveFov3::new(vfov, aspect, near, far);
+    /// let viewport = (0_f64, 0_f64, 800_f64, 600_f64);
+    /// let screen = Point3::new(400_f64, 300_f64, -1_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, -near);
+    /// let result = perspective.screen_to_world(&screen, viewport);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn screen_to_world(&self, screen: &Point3<S>, viewport: (S, S, S, S)) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let (x, y, width, height) = viewport;
+        let ndc = Point3::new(
+            two * (screen.x - x) / width - one,
+            two * (screen.y - y) / height - one,
+            screen.z
+        );
+
+        self.unproject_point(&ndc)
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for PerspectiveFov3<S> {
@@ -1041,6 +1433,48 @@ where
         }
     }
 
+    /// Construct a new orthographic projection from the center and the 
+    /// dimensions of its view volume.
+    ///
+    /// The `center` is given in camera view space, so its **z-component** is 
+    /// negative for a view volume in front of the camera. The view volume 
+    /// extends `width / 2` to either side of the center along the **x-axis**, 
+    /// `height / 2` along the **y-axis**, and `depth / 2` along the 
+    /// **z-axis**. This is a convenient way to fit a shadow map projection 
+    /// around a bounding volume.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let orthographic = Orthographic3::from_center_size(center, 4_f64, 6_f64, 8_f64);
+    /// let expected = Orthographic3::new(-1_f64, 3_f64, -1_f64, 5_f64, 6_f64, 14_f64);
+    ///
+    /// assert_eq!(orthographic, expected);
+    /// ```
+    pub fn from_center_size(center: Point3<S>, width: S, height: S, depth: S) -> Orthographic3<S> {
+        let one_half: S = num_traits::cast(0.5_f64).unwrap();
+        let half_width = width * one_half;
+        let half_height = height * one_half;
+        let half_depth = depth * one_half;
+        // The near and far planes are distances along the negative z-axis.
+        let distance = -center.z;
+
+        Orthographic3::new(
+            center.x - half_width,
+            center.x + half_width,
+            center.y - half_height,
+            center.y + half_height,
+            distance - half_depth,
+            distance + half_depth,
+        )
+    }
+
     /// Get the near plane along the **negative z-axis**.
     #[inline]
     pub fn near_z(&self) -> S {
@@ -1055,14 +1489,14 @@ where
 
     /// Get the left plane along the **negative x-axis**.
     #[inline]
-    pub fn left_x(&self)-> S {
-        self.near
+    pub fn left_x(&self) -> S {
+        self.left
     }
 
     /// Get the right plane along the **positive x-axis**.
     #[inline]
     pub fn right_x(&self) -> S {
-        self.far
+        self.right
     }
 
     /// Get the bottom plane along the **negative y-axis**.
@@ -1253,6 +1687,135 @@ where
 
         Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
     }
+
+    /// Compute the eight corners of the view frustum in camera view space.
+    ///
+    /// The corners are obtained by unprojecting the corners of the normalized 
+    /// device coordinate cube. The first four corners lie on the near plane 
+    /// and the last four corners lie on the far plane. On each plane the 
+    /// corners are ordered bottom-left, bottom-right, top-right, top-left.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let left = -6_f64;
+    /// let right = 6_f64;
+    /// let bottom = -4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// let corners = orthographic.frustum_corners();
+    ///
+    /// for corner in corners[0..4].iter() {
+    ///     assert!(relative_eq!(corner.z, -near, epsilon = 1e-8));
+    /// }
+    /// for corner in corners[4..8].iter() {
+    ///     assert!(relative_eq!(corner.z, -far, epsilon = 1e-8));
+    /// }
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn frustum_corners(&self) -> [Point3<S>; 8] {
+        let one = S::one();
+
+        [
+            self.unproject_point(&Point3::new(-one, -one, -one)),
+            self.unproject_point(&Point3::new( one, -one, -one)),
+            self.unproject_point(&Point3::new( one,  one, -one)),
+            self.unproject_point(&Point3::new(-one,  one, -one)),
+            self.unproject_point(&Point3::new(-one, -one,  one)),
+            self.unproject_point(&Point3::new( one, -one,  one)),
+            self.unproject_point(&Point3::new( one,  one,  one)),
+            self.unproject_point(&Point3::new(-one,  one,  one)),
+        ]
+    }
+
+    /// Compute the six clipping planes of the view frustum in camera view space.
+    ///
+    /// Each plane is returned as the coefficients `(a, b, c, d)` of the plane
+    /// equation `a * x + b * y + c * z + d == 0`, normalized such that
+    /// `a^2 + b^2 + c^2 == 1`. The normals point into the frustum, and the
+    /// planes are ordered left, right, bottom, top, near, far.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let left = -6_f64;
+    /// let right = 6_f64;
+    /// let bottom = -4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// let planes = orthographic.frustum_planes();
+    /// let inside = Vector4::new(0_f64, 0_f64, -50_f64, 1_f64);
+    ///
+    /// assert!(planes.iter().all(|plane| plane.dot(&inside) >= 0_f64));
+    /// ```
+    #[inline]
+    pub fn frustum_planes(&self) -> [Vector4<S>; 6] {
+        self.matrix.extract_frustum_planes()
+    }
+
+    /// Map a point in screen space back to camera view space.
+    ///
+    /// The `viewport` is given as `(x, y, width, height)` in pixels, with the
+    /// origin `(x, y)` at the bottom-left corner of the viewport. The `x` and 
+    /// `y` components of `screen` are pixel coordinates, and the `z` component 
+    /// is the depth in normalized device coordinates. The screen point is first 
+    /// mapped to normalized device coordinates and then unprojected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let left = -6_f64;
+    /// let right = 6_f64;
+    /// let bottom = -4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// let viewport = (0_f64, 0_f64, 800_f64, 600_f64);
+    /// let screen = Point3::new(400_f64, 300_f64, -1_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, -near);
+    /// let result = orthographic.screen_to_world(&screen, viewport);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn screen_to_world(&self, screen: &Point3<S>, viewport: (S, S, S, S)) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let (x, y, width, height) = viewport;
+        let ndc = Point3::new(
+            two * (screen.x - x) / width - one,
+            two * (screen.y - y) / height - one,
+            screen.z
+        );
+
+        self.unproject_point(&ndc)
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for Orthographic3<S> {
@@ -1462,6 +2025,35 @@ where
         self.aspect
     }
 
+    /// Construct a new orthographic projection with the same field of view angle, 
+    /// near plane and far plane, but with a different aspect ratio.
+    ///
+    /// This is useful for updating a projection when the viewport is resized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     OrthographicFov3,
+    /// #     Degrees,
+    /// # };
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let orthographic = OrthographicFov3::new(vfov, aspect, near, far);
+    /// let new_aspect = 1920_f64 / 1080_f64;
+    /// let expected = OrthographicFov3::new(vfov, new_aspect, near, far);
+    /// let result = orthographic.with_aspect(new_aspect);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn with_aspect(&self, aspect: S) -> Self {
+        Self::new(self.vfov, aspect, self.near, self.far)
+    }
+
     /// Get the underlying matrix implementing the orthographic transformation.
     ///
     /// ## Example
@@ -1664,6 +2256,132 @@ where
 
         Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
     }
+
+    /// Compute the eight corners of the view frustum in camera view space.
+    ///
+    /// The corners are obtained by unprojecting the corners of the normalized 
+    /// device coordinate cube. The first four corners lie on the near plane 
+    /// and the last four corners lie on the far plane. On each plane the 
+    /// corners are ordered bottom-left, bottom-right, top-right, top-left.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     OrthographicFov3,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = OrthographicFov3::new(vfov, aspect, near, far);
+    /// let corners = orthographic.frustum_corners();
+    ///
+    /// for corner in corners[0..4].iter() {
+    ///     assert!(relative_eq!(corner.z, -near, epsilon = 1e-8));
+    /// }
+    /// for corner in corners[4..8].iter() {
+    ///     assert!(relative_eq!(corner.z, -far, epsilon = 1e-8));
+    /// }
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn frustum_corners(&self) -> [Point3<S>; 8] {
+        let one = S::one();
+
+        [
+            self.unproject_point(&Point3::new(-one, -one, -one)),
+            self.unproject_point(&Point3::new( one, -one, -one)),
+            self.unproject_point(&Point3::new( one,  one, -one)),
+            self.unproject_point(&Point3::new(-one,  one, -one)),
+            self.unproject_point(&Point3::new(-one, -one,  one)),
+            self.unproject_point(&Point3::new( one, -one,  one)),
+            self.unproject_point(&Point3::new( one,  one,  one)),
+            self.unproject_point(&Point3::new(-one,  one,  one)),
+        ]
+    }
+
+    /// Compute the six clipping planes of the view frustum in camera view space.
+    ///
+    /// Each plane is returned as the coefficients `(a, b, c, d)` of the plane
+    /// equation `a * x + b * y + c * z + d == 0`, normalized such that
+    /// `a^2 + b^2 + c^2 == 1`. The normals point into the frustum, and the
+    /// planes are ordered left, right, bottom, top, near, far.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     OrthographicFov3,
+    /// #     Degrees,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = OrthographicFov3::new(vfov, aspect, near, far);
+    /// let planes = orthographic.frustum_planes();
+    /// let inside = Vector4::new(0_f64, 0_f64, -50_f64, 1_f64);
+    ///
+    /// assert!(planes.iter().all(|plane| plane.dot(&inside) >= 0_f64));
+    /// ```
+    #[inline]
+    pub fn frustum_planes(&self) -> [Vector4<S>; 6] {
+        self.matrix.extract_frustum_planes()
+    }
+
+    /// Map a point in screen space back to camera view space.
+    ///
+    /// The `viewport` is given as `(x, y, width, height)` in pixels, with the
+    /// origin `(x, y)` at the bottom-left corner of the viewport. The `x` and 
+    /// `y` components of `screen` are pixel coordinates, and the `z` component 
+    /// is the depth in normalized device coordinates. The screen point is first 
+    /// mapped to normalized device coordinates and then unprojected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     OrthographicFov3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = OrthographicFov3::new(vfov, aspect, near, far);
+    /// let viewport = (0_f64, 0_f64, 800_f64, 600_f64);
+    /// let screen = Point3::new(400_f64, 300_f64, -1_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, -near);
+    /// let result = orthographic.screen_to_world(&screen, viewport);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn screen_to_world(&self, screen: &Point3<S>, viewport: (S, S, S, S)) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let (x, y, width, height) = viewport;
+        let ndc = Point3::new(
+            two * (screen.x - x) / width - one,
+            two * (screen.y - y) / height - one,
+            screen.z
+        );
+
+        self.unproject_point(&ndc)
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for OrthographicFov3<S> {
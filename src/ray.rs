@@ -0,0 +1,122 @@
+use crate::base::{
+    ScalarFloat,
+    Unit,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::point::{
+    Point3,
+};
+
+use core::fmt;
+
+
+/// A ray in three dimensions.
+///
+/// A ray is the half-line of points starting at an origin and travelling
+/// along a unit direction vector.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray3<S> {
+    /// The starting point of the ray.
+    pub origin: Point3<S>,
+    /// The direction the ray travels in.
+    pub direction: Unit<Vector3<S>>,
+}
+
+impl<S> Ray3<S> {
+    /// Construct a new ray from its origin and direction.
+    #[inline]
+    pub const fn new(origin: Point3<S>, direction: Unit<Vector3<S>>) -> Ray3<S> {
+        Ray3 {
+            origin: origin,
+            direction: direction,
+        }
+    }
+}
+
+impl<S> Ray3<S>
+where
+    S: ScalarFloat
+{
+    /// Compute the point on a ray at a distance `t` from its origin.
+    ///
+    /// Since the direction of the ray has unit length, the parameter `t`
+    /// is the distance travelled along the ray.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Ray3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Unit::from_value(Vector3::unit_z());
+    /// let ray = Ray3::new(origin, direction);
+    /// let expected = Point3::new(1_f64, 2_f64, 8_f64);
+    /// let result = ray.point_at(5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn point_at(&self, t: S) -> Point3<S> {
+        self.origin + self.direction.into_inner() * t
+    }
+
+    /// Apply a homogeneous transformation to a ray.
+    ///
+    /// The origin transforms as a point and the direction transforms as a
+    /// vector. The transformed direction is normalized again, so scaling
+    /// transformations change the distance the parameter of the ray measures.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Ray3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let ray = Ray3::new(Point3::origin(), Unit::from_value(Vector3::unit_x()));
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(0_f64, 1_f64, 0_f64));
+    /// let expected = Ray3::new(
+    ///     Point3::new(0_f64, 1_f64, 0_f64),
+    ///     Unit::from_value(Vector3::unit_x())
+    /// );
+    /// let result = ray.transform(&matrix);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform(&self, matrix: &Matrix4x4<S>) -> Ray3<S> {
+        let origin = matrix.transform_point(&self.origin);
+        let direction = (matrix * self.direction.into_inner().extend(S::zero())).contract();
+
+        Ray3::new(origin, Unit::from_value(direction))
+    }
+}
+
+impl<S> fmt::Display for Ray3<S>
+where
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Ray3 [origin: {}, direction: {}]",
+            self.origin, self.direction
+        )
+    }
+}
+
@@ -5,6 +5,15 @@ use crate::base::{
 };
 use crate::base::{
     Magnitude,
+    VectorSpace,
+    InnerProductSpace,
+};
+use crate::base::{
+    Unit,
+};
+use crate::angle::{
+    Angle,
+    Radians,
 };
 use crate::{
     impl_coords,
@@ -14,7 +23,10 @@ use num_traits::{
     NumCast,
 };
 
+use core::array::TryFromSliceError;
+use core::convert::TryFrom;
 use core::fmt;
+use core::iter;
 use core::ops;
 use core::ops::*;
 
@@ -293,6 +305,29 @@ where
         self + ((other - self) * amount)
     }
 
+    /// Linearly interpolate between the two vectors `self` and `other`, with
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
+    ///
+    /// Unlike `lerp`, the result never extrapolates past either endpoint.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector1,  
+    /// # };
+    /// #
+    /// let v0 = Vector1::new(0_f64);
+    /// let v1 = Vector1::new(10_f64);
+    ///
+    /// assert_eq!(v0.lerp_clamped(&v1, 2_f64), v1);
+    /// assert_eq!(v0.lerp_clamped(&v1, -1_f64), v0);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: &Vector1<S>, amount: S) -> Vector1<S> {
+        self.lerp(other, amount.max(S::zero()).min(S::one()))
+    }
+
     /// Returns `true` if the elements of this vector are all finite. 
     /// Otherwise, it returns `false`. 
     ///
@@ -328,6 +363,29 @@ where
         self.data[0].is_finite()
     }
 
+    /// Returns `true` if any element of a vector is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector1, 
+    /// # };
+    /// #
+    /// let vector = Vector1::new(f64::NAN);
+    ///
+    /// assert!(vector.is_nan());
+    ///
+    /// let vector = Vector1::new(f64::INFINITY);
+    ///
+    /// assert!(!vector.is_nan());
+    /// assert!(!vector.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0].is_nan()
+    }
+
     /// Compute the projection of the vector `self` onto the vector
     /// `other`.
     ///
@@ -426,6 +484,16 @@ where
     }
 }
 
+impl<S> From<Vector1<S>> for [S; 1] 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: Vector1<S>) -> [S; 1] {
+        v.data
+    }
+}
+
 
 /// A representation of two-dimensional vectors in a Euclidean space.
 #[repr(C)]
@@ -526,6 +594,30 @@ where
         Vector1::new(self.data[0])
     }
 
+    /// Truncate a vector to a vector of one lower dimension by removing its 
+    /// last component.
+    ///
+    /// This is an alias for `contract`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector1,
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let v = Vector2::new(1_f64, 2_f64);
+    /// let expected = Vector1::new(1_f64);
+    /// let result = v.truncate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn truncate(self) -> Vector1<S> {
+        self.contract()
+    }
+
     /// Construct a vector from a fill value.
     ///
     /// Every component of the resulting vector will have the same value
@@ -715,13 +807,15 @@ where
     pub fn dot(self, other: &Vector2<S>) -> S {
         self.data[0] * other.data[0] + self.data[1] * other.data[1]
     }
-}
 
-impl<S> Vector2<S> 
-where 
-    S: ScalarSigned 
-{
-    /// Compute the negation of a vector mutably in place.
+    /// Compute the perpendicular dot product of two two-dimensional vectors.
+    ///
+    /// The perpendicular dot product is the dot product of the vector `self` 
+    /// rotated counterclockwise by a quarter turn with the vector `other`. It is 
+    /// equal to the **z-component** of the three-dimensional cross product of the 
+    /// two vectors extended into the **xy-plane**. The result is positive when 
+    /// `other` lies counterclockwise from `self`, negative when it lies clockwise 
+    /// from `self`, and zero when the two vectors are parallel.
     ///
     /// ## Example
     ///
@@ -730,991 +824,3784 @@ where
     /// #     Vector2, 
     /// # };
     /// #
-    /// let mut result = Vector2::new(1_i32, 2_i32);
-    /// let expected = -result;
-    /// result.neg_mut();
-    ///
-    /// assert_eq!(result, expected);
+    /// let vector1 = Vector2::new(1_i32, 2_i32);
+    /// let vector2 = Vector2::new(3_i32, 4_i32);
+    /// 
+    /// assert_eq!(vector1.perp_dot(&vector2), -2_i32);
+    /// assert_eq!(vector2.perp_dot(&vector1), 2_i32);
+    /// assert_eq!(vector1.perp_dot(&vector1), 0_i32);
     /// ```
     #[inline]
-    pub fn neg_mut(&mut self) {
-        self.data[0] = -self.data[0];
-        self.data[1] = -self.data[1];
+    pub fn perp_dot(&self, other: &Vector2<S>) -> S {
+        self.data[0] * other.data[1] - self.data[1] * other.data[0]
     }
-}
 
-impl<S> Vector2<S> 
-where 
-    S: ScalarFloat
-{
-    /// Linearly interpolate between the two vectors `self` and `other`.
+    /// Compute the component-wise minimum of two vectors.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector2,  
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let v0 = Vector2::new(0_f64, 0_f64);
-    /// let v1 = Vector2::new(10_f64, 20_f64);
-    /// let amount = 0.7;
-    /// let expected = Vector2::new(7_f64, 14_f64);
-    /// let result = v0.lerp(&v1, amount);
+    /// let vector1 = Vector2::new(1_i32, -2_i32);
+    /// let vector2 = Vector2::new(-3_i32, 4_i32);
+    /// let expected = Vector2::new(-3_i32, -2_i32);
+    /// let result = vector1.component_min(&vector2);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn lerp(&self, other: &Vector2<S>, amount: S) -> Vector2<S> {
-        self + ((other - self) * amount)
+    pub fn component_min(&self, other: &Vector2<S>) -> Vector2<S> {
+        let x = if self.data[0] < other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] < other.data[1] { self.data[1] } else { other.data[1] };
+
+        Vector2::new(x, y)
     }
 
-    /// Returns `true` if the elements of a vector are all finite. 
-    /// Otherwise, it returns `false`. 
-    ///
-    /// A vector is finite when all of its elements are finite. This is useful 
-    /// for vector and matrix types working with fixed precision floating point 
-    /// values.
+    /// Compute the component-wise maximum of two vectors.
     ///
-    /// ## Example (Finite Vector)
+    /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #    Vector2,
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let v = Vector2::new(1_f64, 2_f64);
+    /// let vector1 = Vector2::new(1_i32, -2_i32);
+    /// let vector2 = Vector2::new(-3_i32, 4_i32);
+    /// let expected = Vector2::new(1_i32, 4_i32);
+    /// let result = vector1.component_max(&vector2);
     ///
-    /// assert!(v.is_finite());
+    /// assert_eq!(result, expected);
     /// ```
+    #[inline]
+    pub fn component_max(&self, other: &Vector2<S>) -> Vector2<S> {
+        let x = if self.data[0] > other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] > other.data[1] { self.data[1] } else { other.data[1] };
+
+        Vector2::new(x, y)
+    }
+
+    /// Clamp each component of a vector to the range given by the 
+    /// corresponding components of the vectors `min` and `max`.
     ///
-    /// ## Example (Not A Finite Vector)
+    /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector2,
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let w1 = Vector2::new(f64::INFINITY, f64::NAN);
-    /// let w2 = Vector2::new(f64::INFINITY, 2_f64);
+    /// let vector = Vector2::new(-5_i32, 5_i32);
+    /// let min = Vector2::new(-1_i32, 0_i32);
+    /// let max = Vector2::new(1_i32, 1_i32);
+    /// let expected = Vector2::new(-1_i32, 1_i32);
+    /// let result = vector.clamp(&min, &max);
     ///
-    /// assert!(!w1.is_finite());
-    /// assert!(!w2.is_finite());
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn is_finite(&self) -> bool {
-        self.data[0].is_finite() && self.data[1].is_finite()
+    pub fn clamp(&self, min: &Vector2<S>, max: &Vector2<S>) -> Vector2<S> {
+        self.component_max(min).component_min(max)
     }
 
-    /// Compute the projection of the vector `self` onto the vector
-    /// `other`.
+    /// Compute the sum of the components of a vector.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
     /// #     Vector2, 
-    /// #     Magnitude,
     /// # };
-    /// # 
-    /// let vector = 3_f64 * Vector2::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64);
-    /// let unit_x = Vector2::unit_x();
-    /// let unit_y = Vector2::unit_y();
-    /// let projected_x = vector.project(&unit_x);
-    /// let projected_y = vector.project(&unit_y);
+    /// #
+    /// let vector = Vector2::new(2_i32, 3_i32);
     ///
-    /// assert_eq!(projected_x, vector.x * unit_x);
-    /// assert_eq!(projected_y, vector.y * unit_y);
+    /// assert_eq!(vector.sum(), 5_i32);
     /// ```
     #[inline]
-    pub fn project(&self, other: &Vector2<S>) -> Vector2<S> {
-        other * (self.dot(other) / other.magnitude_squared())
-    }
-}
-
-impl<S> fmt::Display for Vector2<S> 
-where 
-    S: fmt::Display 
-{
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter, 
-            "Vector2 [{}, {}]", 
-            self.data[0], self.data[1]
-        )
-    }
-}
-
-impl<S> From<(S, S)> for Vector2<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from((x, y): (S, S)) -> Vector2<S> {
-        Vector2::new(x, y)
-    }
-}
-
-impl<S> From<[S; 2]> for Vector2<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: [S; 2]) -> Vector2<S> {
-        Vector2::new(v[0], v[1])
-    }
-}
-
-impl<S> From<&(S, S)> for Vector2<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &(S, S)) -> Vector2<S> {
-        Vector2::new(v.0, v.1)
+    pub fn sum(&self) -> S {
+        self.data[0] + self.data[1]
     }
-}
 
-impl<S> From<&[S; 2]> for Vector2<S> 
-where 
-    S: Scalar
-{
+    /// Compute the product of the components of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(2_i32, 3_i32);
+    ///
+    /// assert_eq!(vector.product(), 6_i32);
+    /// ```
     #[inline]
-    fn from(v: &[S; 2]) -> Vector2<S> {
-        Vector2::new(v[0], v[1])
+    pub fn product(&self) -> S {
+        self.data[0] * self.data[1]
     }
-}
 
-impl<'a, S> From<&'a [S; 2]> for &'a Vector2<S> 
-where 
-    S: Scalar
-{
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is less than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector1 = Vector2::new(1_i32, 5_i32);
+    /// let vector2 = Vector2::new(2_i32, 2_i32);
+    /// let expected = [true, false];
+    /// let result = vector1.cmp_lt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    fn from(v: &'a [S; 2]) -> &'a Vector2<S> {
-        unsafe { 
-            &*(v as *const [S; 2] as *const Vector2<S>)
-        }
+    pub fn cmp_lt(&self, other: &Vector2<S>) -> [bool; 2] {
+        [self.data[0] < other.data[0], self.data[1] < other.data[1]]
     }
-}
-
-
-/// A representation of three-dimensional vectors in a Euclidean space.
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Vector3<S> {
-    data: [S; 3],
-}
 
-impl<S> Vector3<S> {
-    /// Construct a new vector.
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is greater than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector1 = Vector2::new(1_i32, 5_i32);
+    /// let vector2 = Vector2::new(2_i32, 2_i32);
+    /// let expected = [false, true];
+    /// let result = vector1.cmp_gt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub const fn new(x: S, y: S, z: S) -> Vector3<S> {
-        Vector3 { 
-            data: [x, y, z],
-        }
+    pub fn cmp_gt(&self, other: &Vector2<S>) -> [bool; 2] {
+        [self.data[0] > other.data[0], self.data[1] > other.data[1]]
     }
-}
 
-impl<S> Vector3<S> 
-where 
-    S: NumCast + Copy
-{
-    /// Cast a vector from one type of scalars to another type of scalars.
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is equal to the corresponding 
+    /// component of `other`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,   
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let vector: Vector3<u32> = Vector3::new(1_u32, 2_u32, 3_u32);
-    /// let expected: Option<Vector3<i32>> = Some(Vector3::new(1_i32, 2_i32, 3_i32));
-    /// let result = vector.cast::<i32>();
+    /// let vector1 = Vector2::new(1_i32, 5_i32);
+    /// let vector2 = Vector2::new(1_i32, 2_i32);
+    /// let expected = [true, false];
+    /// let result = vector1.cmp_eq(&vector2);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn cast<T: NumCast>(&self) -> Option<Vector3<T>> {
-        let x = match num_traits::cast(self.data[0]) {
-            Some(value) => value,
-            None => return None,
-        };
-        let y = match num_traits::cast(self.data[1]) {
-            Some(value) => value,
-            None => return None,
-        };
-        let z = match num_traits::cast(self.data[2]) {
-            Some(value) => value,
-            None => return None,
-        };
-
-        Some(Vector3::new(x, y, z))
+    pub fn cmp_eq(&self, other: &Vector2<S>) -> [bool; 2] {
+        [self.data[0] == other.data[0], self.data[1] == other.data[1]]
     }
-}
 
-impl<S> Vector3<S> 
-where 
-    S: Copy
-{
-    /// Extend a three-dimensional vector into a four-dimensional vector using the 
-    /// supplied **w-component**.
+    /// Construct a vector by choosing each component from one of two vectors 
+    /// according to a mask.
+    ///
+    /// Each component of the result is the corresponding component of 
+    /// `if_true` where the mask is `true`, and of `if_false` where the mask is 
+    /// `false`. Together with the component-wise comparison functions, such 
+    /// as `cmp_lt`, this allows branch-free conditional logic on vectors.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
-    /// #     Vector4, 
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
-    /// let expected = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
-    /// let result = v.extend(4_f64);
+    /// let if_true = Vector2::new(1_i32, 2_i32);
+    /// let if_false = Vector2::new(3_i32, 4_i32);
+    /// let mask = [true, false];
+    /// let expected = Vector2::new(1_i32, 4_i32);
+    /// let result = Vector2::select(mask, &if_true, &if_false);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn extend(self, w: S) -> Vector4<S> {
-        Vector4::new(self.data[0], self.data[1], self.data[2], w)
+    pub fn select(mask: [bool; 2], if_true: &Vector2<S>, if_false: &Vector2<S>) -> Vector2<S> {
+        Vector2::new(
+            if mask[0] { if_true.data[0] } else { if_false.data[0] },
+            if mask[1] { if_true.data[1] } else { if_false.data[1] }
+        )
     }
+}
 
-    /// Contract a three-dimensional vector to a two-dimensional vector
-    /// by removing the **z-component**.
+impl<S> Vector2<S> 
+where 
+    S: ScalarSigned 
+{
+    /// Compute the negation of a vector mutably in place.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector2,
-    /// #     Vector3, 
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
-    /// let expected = Vector2::new(1_f64, 2_f64);
-    /// let result = v.contract();
+    /// let mut result = Vector2::new(1_i32, 2_i32);
+    /// let expected = -result;
+    /// result.neg_mut();
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn contract(self) -> Vector2<S> {
-        Vector2::new(self.data[0], self.data[1])
+    pub fn neg_mut(&mut self) {
+        self.data[0] = -self.data[0];
+        self.data[1] = -self.data[1];
     }
 
-    /// Construct a vector from a fill value.
-    ///
-    /// Every component of the resulting vector will have the same value
-    /// supplied by the `value` argument.
+    /// Compute the vector perpendicular to `self` obtained by rotating `self` 
+    /// counterclockwise by a quarter turn.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,   
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let fill_value = 3_f64;
-    /// let result = Vector3::from_fill(fill_value);
-    /// let expected = Vector3::new(3_f64, 3_f64, 3_f64);
+    /// let vector = Vector2::new(1_i32, 2_i32);
+    /// let expected = Vector2::new(-2_i32, 1_i32);
+    /// let result = vector.perp();
     ///
     /// assert_eq!(result, expected);
+    /// assert_eq!(result.dot(&vector), 0_i32);
     /// ```
     #[inline]
-    pub fn from_fill(value: S) -> Vector3<S> {
-        Vector3::new(value, value, value)
-    }
-
-    /// The length of the the underlying array storing the vector components.
-    #[inline]
-    pub fn len(&self) -> usize {
-        3
-    }
-
-    /// The shape of the underlying array storing the vector components.
-    ///
-    /// The shape is the equivalent number of columns and rows of the 
-    /// array as though it represents a matrix. The order of the descriptions 
-    /// of the shape of the array is **(rows, columns)**.
-    #[inline]
-    pub fn shape(&self) -> (usize, usize) {
-        (3, 1)
-    }
-
-    /// Get a pointer to the underlying array.
-    #[inline]
-    pub fn as_ptr(&self) -> *const S {
-        &self.data[0]
-    }
-
-    /// Get a mutable pointer to the underlying array.
-    #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut S {
-        &mut self.data[0]
-    }
-
-    /// Get a slice of the underlying elements of the data type.
-    #[inline]
-    pub fn as_slice(&self) -> &[S] {
-        <Self as AsRef<[S; 3]>>::as_ref(self)
+    pub fn perp(&self) -> Vector2<S> {
+        Vector2::new(-self.data[1], self.data[0])
     }
 
-    /// Map an operation on that acts on the coordinates of a vector, returning 
-    /// a vector whose coordinates are of the new scalar type.
+    /// Compute the component-wise absolute value of a vector.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,  
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let vector: Vector3<u32> = Vector3::new(1_u32, 2_u32, 3_u32);
-    /// let expected: Vector3<i32> = Vector3::new(2_i32, 3_i32, 4_i32);
-    /// let result: Vector3<i32> = vector.map(|comp| (comp + 1) as i32);
+    /// let vector = Vector2::new(-1_i32, 2_i32);
+    /// let expected = Vector2::new(1_i32, 2_i32);
+    /// let result = vector.abs();
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn map<T, F>(self, mut op: F) -> Vector3<T> 
-    where 
-        F: FnMut(S) -> T
-    {
-        Vector3::new(op(self.data[0]), op(self.data[1]), op(self.data[2]))
+    pub fn abs(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].abs(), self.data[1].abs())
     }
 }
 
-impl<S> Vector3<S> 
+impl<S> Vector2<S> 
 where 
-    S: Scalar
+    S: ScalarFloat
 {
-    /// Returns the **x-axis** unit vector, a unit vector with the **x-component**
-    /// component as a `1` and the rest of the components are zero.
-    #[inline]
-    pub fn unit_x() -> Vector3<S> {
-        Vector3::new(S::one(), S::zero(), S::zero())
-    }
-
-    /// Returns the **y-axis** unit vector, a unit vector with the **y-component**
-    /// component as a `1` and the rest of the components are zero.
-    #[inline]
-    pub fn unit_y() -> Vector3<S> {
-        Vector3::new(S::zero(), S::one(), S::zero())
-    }
-    
-    /// Returns the **z-axis** unit vector, a unit vector with the **z-component**
-    /// component as a `1` and the rest of the components are zero.
-    #[inline]
-    pub fn unit_z() -> Vector3<S> {
-        Vector3::new(S::zero(), S::zero(), S::one())
-    }
-
-    /// Compute the zero vector.
+    /// Linearly interpolate between the two vectors `self` and `other`.
     ///
-    /// The zero vector is the vector in which all of its elements are zero.
-    #[inline]
-    pub fn zero() -> Vector3<S> {
-        Vector3::new(S::zero(), S::zero(), S::zero())
-    }
-    
-    /// Determine whether a vector is the zero vector.
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2,  
+    /// # };
+    /// #
+    /// let v0 = Vector2::new(0_f64, 0_f64);
+    /// let v1 = Vector2::new(10_f64, 20_f64);
+    /// let amount = 0.7;
+    /// let expected = Vector2::new(7_f64, 14_f64);
+    /// let result = v0.lerp(&v1, amount);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0].is_zero() && 
-        self.data[1].is_zero() && 
-        self.data[2].is_zero()
+    pub fn lerp(&self, other: &Vector2<S>, amount: S) -> Vector2<S> {
+        self + ((other - self) * amount)
     }
 
-    /// Compute the coordinates of a vector in projective space.
+    /// Linearly interpolate between the two vectors `self` and `other`, with
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
     ///
-    /// The function appends a `0` to the vector.
+    /// Unlike `lerp`, the result never extrapolates past either endpoint.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
-    /// #     Vector4, 
+    /// #     Vector2,  
     /// # };
     /// #
-    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
-    /// let expected = Vector4::new(1_i32, 2_i32, 3_i32, 0_i32);
-    /// let result = vector.to_homogeneous();
+    /// let v0 = Vector2::new(0_f64, 0_f64);
+    /// let v1 = Vector2::new(10_f64, 20_f64);
     ///
-    /// assert_eq!(result, expected);
+    /// assert_eq!(v0.lerp_clamped(&v1, 2_f64), v1);
+    /// assert_eq!(v0.lerp_clamped(&v1, -1_f64), v0);
     /// ```
     #[inline]
-    pub fn to_homogeneous(&self) -> Vector4<S> {
-        self.extend(S::zero())
+    pub fn lerp_clamped(&self, other: &Vector2<S>, amount: S) -> Vector2<S> {
+        self.lerp(other, amount.max(S::zero()).min(S::one()))
     }
 
-    /// Compute the coordinates of a projective vector in Euclidean space.
+    /// Rotate a vector in the **xy-plane** by the angle `angle`.
     ///
-    /// The function removes a `0` from the end of the vector, otherwise it
-    /// returns `None`.
+    /// Positive angles rotate counterclockwise, from the **positive x-axis** 
+    /// towards the **positive y-axis**. The result is the same as multiplying 
+    /// by `Matrix2x2::from_angle(angle)`, without constructing the matrix.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Radians,
     /// #     Vector2,
-    /// #     Vector3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
     /// # };
     /// #
-    /// let vector = Vector3::new(1_i32, 2_i32, 0_i32);
-    /// let expected = Some(Vector2::new(1_i32, 2_i32));
-    /// let result = vector.from_homogeneous();
+    /// let vector: Vector2<f64> = Vector2::unit_x();
+    /// let expected = Vector2::unit_y();
+    /// let result = vector.rotate(Radians::full_turn_div_4());
     ///
-    /// assert_eq!(result, expected);
-    ///
-    /// let vector = Vector3::new(1_i32, 2_i32, 1_i32);
-    /// let expected: Option<Vector2<i32>> = None;
-    /// let result = vector.from_homogeneous();
-    ///
-    /// assert!(result.is_none());
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[inline]
-    pub fn from_homogeneous(&self) -> Option<Vector2<S>> {
-        if self.data[2].is_zero() {
-            Some(self.contract())
-        } else {
-            None
-        }
+    pub fn rotate<A: Into<Radians<S>>>(&self, angle: A) -> Vector2<S> {
+        let (sin_angle, cos_angle) = angle.into().sin_cos();
+
+        Vector2::new(
+            self.data[0] * cos_angle - self.data[1] * sin_angle,
+            self.data[0] * sin_angle + self.data[1] * cos_angle,
+        )
     }
 
-    /// Compute the Euclidean dot product (inner product) of two vectors.
+    /// Returns `true` if the elements of a vector are all finite. 
+    /// Otherwise, it returns `false`. 
     ///
-    /// ## Example
+    /// A vector is finite when all of its elements are finite. This is useful 
+    /// for vector and matrix types working with fixed precision floating point 
+    /// values.
+    ///
+    /// ## Example (Finite Vector)
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3, 
+    /// #    Vector2,
     /// # };
     /// #
-    /// let vector1 = Vector3::new(1_f64, 2_f64, 3_f64);
-    /// let vector2 = Vector3::new(4_f64, 5_f64, 6_f64);
-    /// 
-    /// assert_eq!(vector1.dot(&vector2), 32_f64);
+    /// let v = Vector2::new(1_f64, 2_f64);
+    ///
+    /// assert!(v.is_finite());
     /// ```
-    #[inline]
-    pub fn dot(self, other: &Vector3<S>) -> S {
-        self.data[0] * other.data[0] + 
-        self.data[1] * other.data[1] + 
-        self.data[2] * other.data[2]
-    }
-}
-
-impl<S> Vector3<S> 
-where 
-    S: ScalarSigned
-{
-    /// Compute the negation of a vector mutably in place.
     ///
-    /// ## Example
+    /// ## Example (Not A Finite Vector)
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3, 
+    /// #     Vector2,
     /// # };
     /// #
-    /// let mut result = Vector3::new(1_i32, 2_i32, 3_i32);
-    /// let expected = -result;
-    /// result.neg_mut();
+    /// let w1 = Vector2::new(f64::INFINITY, f64::NAN);
+    /// let w2 = Vector2::new(f64::INFINITY, 2_f64);
     ///
-    /// assert_eq!(result, expected);
+    /// assert!(!w1.is_finite());
+    /// assert!(!w2.is_finite());
     /// ```
     #[inline]
-    pub fn neg_mut(&mut self) {
-        self.data[0] = -self.data[0];
-        self.data[1] = -self.data[1];
-        self.data[2] = -self.data[2];
+    pub fn is_finite(&self) -> bool {
+        self.data[0].is_finite() && self.data[1].is_finite()
     }
 
-    /// Compute the cross product of two three-dimensional vectors. 
-    ///
-    /// For the vector dimensions used in computer graphics 
-    /// (up to four dimensions), the cross product is well-defined only in 
-    /// three dimensions. The cross product is a form of vector 
-    /// multiplication that computes a vector normal to the plane swept out by 
-    /// the two vectors. The magnitude of this vector is the area of the 
-    /// parallelogram swept out by the two vectors. 
+    /// Returns `true` if any element of a vector is `NaN`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,  
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let vector1 = Vector3::new(1_f64, 2_f64, 3_f64);
-    /// let vector2 = Vector3::new(4_f64, 5_f64, 6_f64);
-    /// let cross = vector1.cross(&vector2);
+    /// let vector = Vector2::new(1_f64, f64::NAN);
     ///
-    /// assert_eq!(cross, Vector3::new(-3_f64, 6_f64, -3_f64));
-    /// // The cross product is perpendicular to the two input vectors.
-    /// assert_eq!(cross.dot(&vector1), 0_f64);
-    /// assert_eq!(cross.dot(&vector2), 0_f64);
+    /// assert!(vector.is_nan());
     ///
-    /// // The cross product of a vector with itself is zero.
-    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let vector = Vector2::new(1_f64, f64::INFINITY);
     ///
-    /// assert_eq!(vector.cross(&vector), Vector3::zero());
+    /// assert!(!vector.is_nan());
+    /// assert!(!vector.is_finite());
     /// ```
     #[inline]
-    pub fn cross(&self, other: &Vector3<S>) -> Self {
-        let x = self.data[1] * other.data[2] - self.data[2] * other.data[1];
-        let y = self.data[2] * other.data[0] - self.data[0] * other.data[2];
-        let z = self.data[0] * other.data[1] - self.data[1] * other.data[0];
-    
-        Vector3::new(x, y, z)
+    pub fn is_nan(&self) -> bool {
+        self.data[0].is_nan() || self.data[1].is_nan()
     }
-}
 
-impl<S> Vector3<S> 
-where 
-    S: ScalarFloat
-{
-    /// Linearly interpolate between the two vectors `self` and `other`.
+    /// Compute the projection of the vector `self` onto the vector
+    /// `other`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,  
+    /// #     Vector2, 
+    /// #     Magnitude,
     /// # };
-    /// #
-    /// let v0 = Vector3::new(0_f64, 0_f64, 0_f64);
-    /// let v1 = Vector3::new(10_f64, 20_f64, 30_f64);
-    /// let amount = 0.7;
-    /// let expected = Vector3::new(7_f64, 14_f64, 21_f64);
-    /// let result = v0.lerp(&v1, amount);
+    /// # 
+    /// let vector = 3_f64 * Vector2::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64);
+    /// let unit_x = Vector2::unit_x();
+    /// let unit_y = Vector2::unit_y();
+    /// let projected_x = vector.project(&unit_x);
+    /// let projected_y = vector.project(&unit_y);
     ///
-    /// assert_eq!(result, expected);
+    /// assert_eq!(projected_x, vector.x * unit_x);
+    /// assert_eq!(projected_y, vector.y * unit_y);
     /// ```
     #[inline]
-    pub fn lerp(&self, other: &Vector3<S>, amount: S) -> Vector3<S> {
-        self + ((other - self) * amount)
+    pub fn project(&self, other: &Vector2<S>) -> Vector2<S> {
+        other * (self.dot(other) / other.magnitude_squared())
     }
 
-    /// Returns `true` if the elements of a vector are all finite. 
-    /// Otherwise, it returns `false`. 
+    /// Compute the projection of the vector `self` onto the vector `other`.
     ///
-    /// A vector is finite when all of its elements are finite. This is useful 
-    /// for vector and matrix types working with fixed precision floating point 
-    /// values.
+    /// This is the same as `project`, under the name that pairs with 
+    /// `reject_from`.
     ///
-    /// ## Example (Finite Vector)
+    /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
+    /// #     Vector2, 
     /// # };
-    /// #
-    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// # 
+    /// let vector = Vector2::new(1_f64, 2_f64);
+    /// let other = Vector2::new(3_f64, 4_f64);
     ///
-    /// assert!(v.is_finite()); 
+    /// assert_eq!(vector.project_onto(&other), vector.project(&other));
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: &Vector2<S>) -> Vector2<S> {
+        self.project(other)
+    }
+
+    /// Compute the rejection of the vector `self` from the vector `other`.
+    ///
+    /// The rejection of `self` from `other` is the component of `self` 
+    /// perpendicular to `other`. Together with the projection, it decomposes 
+    /// `self` into components parallel and perpendicular to `other`
+    /// ```text
+    /// self == self.project_onto(other) + self.reject_from(other)
     /// ```
     ///
-    /// ## Example (Not A Finite Vector)
-    /// 
+    /// ## Example
+    ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
+    /// #     Vector2, 
     /// # };
-    /// #
-    /// let w = Vector3::new(1_f64, f64::NAN, f64::NEG_INFINITY);
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # 
+    /// let vector = Vector2::new(1_f64, 2_f64);
+    /// let other = Vector2::new(3_f64, 4_f64);
+    /// let projected = vector.project_onto(&other);
+    /// let rejected = vector.reject_from(&other);
     ///
-    /// assert!(!w.is_finite()); 
+    /// assert!(relative_eq!(rejected.dot(&other), 0_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(projected + rejected, vector, epsilon = 1e-10));
     /// ```
     #[inline]
-    pub fn is_finite(&self) -> bool {
-        self.data[0].is_finite() && 
-        self.data[1].is_finite() && 
-        self.data[2].is_finite()
+    pub fn reject_from(&self, other: &Vector2<S>) -> Vector2<S> {
+        self - self.project_onto(other)
     }
 
-    /// Compute the projection of the vector `self` onto the vector
-    /// `other`.
+    /// Reflect the vector `self` about the plane (line in two dimensions) 
+    /// with the unit normal vector `normal`.
+    ///
+    /// The reflected vector is given by
+    /// ```text
+    /// reflect(v, n) = v - 2 * (v . n) * n
+    /// ```
+    /// where `v` is the vector `self` and `n` is the unit normal vector 
+    /// `normal`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3, 
-    /// #     Magnitude,
+    /// #     Vector2,
+    /// #     Unit,
     /// # };
-    /// # 
-    /// let vector = Vector3::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64, 2_f64);
-    /// let unit_x = Vector3::unit_x();
-    /// let unit_y = Vector3::unit_y();
-    /// let unit_z = Vector3::unit_z();
-    /// let projected_x = vector.project(&unit_x);
-    /// let projected_y = vector.project(&unit_y);
-    /// let projected_z = vector.project(&unit_z);
+    /// #
+    /// let vector = Vector2::new(1_f64, -1_f64);
+    /// let normal: Unit<Vector2<f64>> = Unit::from_value(Vector2::unit_y());
+    /// let expected = Vector2::new(1_f64, 1_f64);
+    /// let result = vector.reflect(&normal);
     ///
-    /// assert_eq!(projected_x, vector.x * unit_x);
-    /// assert_eq!(projected_y, vector.y * unit_y);
-    /// assert_eq!(projected_z, vector.z * unit_z);
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn project(&self, other: &Vector3<S>) -> Vector3<S> {
-        other * (self.dot(other) / other.magnitude_squared())
-    }
-}
-
-impl<S> fmt::Display for Vector3<S> 
-where 
-    S: fmt::Display
-{
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter, 
-            "Vector3 [{}, {}, {}]", 
-            self.data[0], self.data[1], self.data[2]
-        )
-    }
-}
-
-impl<S> From<(S, S, S)> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from((x, y, z): (S, S, S)) -> Vector3<S> {
-        Vector3::new(x, y, z)
-    }
-}
-
-impl<S> From<[S; 3]> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: [S; 3]) -> Vector3<S> {
-        Vector3::new(v[0], v[1], v[2])
-    }
-}
-
-impl<S> From<&[S; 3]> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &[S; 3]) -> Vector3<S> {
-        Vector3::new(v[0], v[1], v[2])
-    }
-}
-
-impl<S> From<&(S, S, S)> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &(S, S, S)) -> Vector3<S> {
-        Vector3::new(v.0, v.1, v.2)
-    }
-}
-
-impl<S> From<Vector4<S>> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: Vector4<S>) -> Vector3<S> {
-        Vector3::new(v.data[0], v.data[1], v.data[2])
-    }
-}
-
-impl<S> From<&Vector4<S>> for Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &Vector4<S>) -> Vector3<S> {
-        Vector3::new(v.data[0], v.data[1], v.data[2])
-    }
-}
-
-impl<'a, S> From<&'a [S; 3]> for &'a Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &'a [S; 3]) -> &'a Vector3<S> {
-        unsafe { 
-            &*(v as *const [S; 3] as *const Vector3<S>)
-        }
-    }
-}
+    pub fn reflect(&self, normal: &Unit<Vector2<S>>) -> Vector2<S> {
+        let two = S::one() + S::one();
 
-impl<'a, S> From<&'a (S, S, S)> for &'a Vector3<S> 
-where 
-    S: Scalar
-{
-    #[inline]
-    fn from(v: &'a (S, S, S)) -> &'a Vector3<S> {
-        unsafe { 
-            &*(v as *const (S, S, S) as *const Vector3<S>)
-        }
+        self - normal.as_ref() * (two * self.dot(normal))
     }
-}
-
-
-/// A representation of four-dimensional vectors in a Euclidean space.
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Vector4<S> {
-    data: [S; 4],
-}
 
-impl<S> Vector4<S> {
-    /// Construct a new four-dimensional vector.
+    /// Compute the signed angle of rotation from the vector `self` to the 
+    /// vector `other`.
+    ///
+    /// The angle is positive when `other` lies counterclockwise from `self`, 
+    /// and negative when `other` lies clockwise from `self`. The resulting angle 
+    /// lies in the range `[-pi, pi]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2,
+    /// #     Radians,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let unit_x: Vector2<f64> = Vector2::unit_x();
+    /// let unit_y: Vector2<f64> = Vector2::unit_y();
+    ///
+    /// assert_eq!(unit_x.angle_signed(&unit_y), Radians(f64::consts::FRAC_PI_2));
+    /// assert_eq!(unit_x.angle_signed(&(-unit_y)), Radians(-f64::consts::FRAC_PI_2));
+    /// ```
     #[inline]
-    pub const fn new(x: S, y: S, z: S, w: S) -> Vector4<S> {
-        Vector4 { 
-            data: [x, y, z, w],
-        }
+    pub fn angle_signed(&self, other: &Vector2<S>) -> Radians<S> {
+        Radians::atan2(self.perp_dot(other), self.dot(other))
     }
-}
 
-impl<S> Vector4<S> 
-where 
-    S: Copy
-{
-    /// Contract a four-dimensional vector to a three-dimensional vector
-    /// by removing the **w-component**.
+    /// Round each component of a vector down to the nearest integer.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
-    /// #     Vector4, 
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let v = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
-    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
-    /// let result = v.contract();
+    /// let vector = Vector2::new(1.5_f64, -1.5_f64);
+    /// let expected = Vector2::new(1_f64, -2_f64);
+    /// let result = vector.floor();
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn contract(self) -> Vector3<S> {
-        Vector3::new(self.data[0], self.data[1], self.data[2])
+    pub fn floor(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].floor(), self.data[1].floor())
     }
 
-    /// Construct a vector from a fill value.
-    ///
-    /// Every component of the resulting vector will have the same value
-    /// supplied by the `value` argument.
+    /// Round each component of a vector up to the nearest integer.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,   
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let fill_value = 3_f64;
-    /// let result = Vector4::from_fill(fill_value);
-    /// let expected = Vector4::new(3_f64, 3_f64, 3_f64, 3_f64);
+    /// let vector = Vector2::new(1.5_f64, -1.5_f64);
+    /// let expected = Vector2::new(2_f64, -1_f64);
+    /// let result = vector.ceil();
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn from_fill(value: S) -> Vector4<S> {
-        Vector4::new(value, value, value, value)
+    pub fn ceil(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].ceil(), self.data[1].ceil())
     }
 
-    /// The length of the the underlying array storing the vector components.
-    #[inline]
-    pub fn len(&self) -> usize {
-        4
-    }
+    /// Round each component of a vector to the nearest integer, rounding half-way 
+    /// cases away from zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(1.5_f64, -1.5_f64);
+    /// let expected = Vector2::new(2_f64, -2_f64);
+    /// let result = vector.round();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].round(), self.data[1].round())
+    }
 
-    /// The shape of the underlying array storing the vector components.
+    /// Clamp the magnitude of a vector to at most `max`.
     ///
-    /// The shape is the equivalent number of columns and rows of the 
-    /// array as though it represents a matrix. The order of the descriptions 
-    /// of the shape of the array is **(rows, columns)**.
+    /// If the magnitude of the vector `self` exceeds `max`, the function returns 
+    /// the vector scaled down to have a magnitude of `max` pointing in the same 
+    /// direction. Otherwise, the function returns the vector unchanged. In 
+    /// particular, the zero vector is left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(6_f64, 8_f64);
+    /// let expected = Vector2::new(3_f64, 4_f64);
+    /// let result = vector.clamp_magnitude(5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let vector = Vector2::new(0.6_f64, 0.8_f64);
+    /// let expected = vector;
+    /// let result = vector.clamp_magnitude(5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub fn shape(&self) -> (usize, usize) {
-        (4, 1)
+    pub fn clamp_magnitude(&self, max: S) -> Vector2<S> {
+        let magnitude_squared = self.magnitude_squared();
+
+        if magnitude_squared > max * max {
+            self * (max / magnitude_squared.sqrt())
+        } else {
+            *self
+        }
     }
 
-    /// Get a pointer to the underlying array.
+    /// Compute the reciprocal of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(2_f64, 4_f64);
+    /// let expected = Vector2::new(0.5_f64, 0.25_f64);
+    /// let result = vector.recip();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub fn as_ptr(&self) -> *const S {
-        &self.data[0]
+    pub fn recip(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].recip(), self.data[1].recip())
     }
 
-    /// Get a mutable pointer to the underlying array.
+    /// Compute the square root of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(4_f64, 9_f64);
+    /// let expected = Vector2::new(2_f64, 3_f64);
+    /// let result = vector.sqrt();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut S {
-        &mut self.data[0]
+    pub fn sqrt(&self) -> Vector2<S> {
+        Vector2::new(self.data[0].sqrt(), self.data[1].sqrt())
     }
 
-    /// Get a slice of the underlying elements of the data type.
+    /// Raise each component of a vector to the floating point power `exponent`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(1_f64, 2_f64);
+    /// let expected = Vector2::new(1_f64, 8_f64);
+    /// let result = vector.powf(3_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
     #[inline]
-    pub fn as_slice(&self) -> &[S] {
-        <Self as AsRef<[S; 4]>>::as_ref(self)
+    pub fn powf(&self, exponent: S) -> Vector2<S> {
+        Vector2::new(self.data[0].powf(exponent), self.data[1].powf(exponent))
     }
 
-    /// Map an operation on that acts on the coordinates of a vector, returning 
-    /// a vector whose coordinates are of the new scalar type.
+    /// Compute the sign of each component of a vector.
+    ///
+    /// Each component of the result is `-1` if the corresponding component is 
+    /// negative, `1` if it is positive, and `0` if it is zero. This differs 
+    /// from the scalar `signum` function, which maps zero to `1`, so that 
+    /// zero components do not acquire a spurious direction. A `NaN` component 
+    /// stays `NaN`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,  
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let vector: Vector4<u32> = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
-    /// let expected: Vector4<i32> = Vector4::new(2_i32, 3_i32, 4_i32, 5_i32);
-    /// let result: Vector4<i32> = vector.map(|comp| (comp + 1) as i32);
+    /// let vector = Vector2::new(-2_f64, 0_f64);
+    /// let expected = Vector2::new(-1_f64, 0_f64);
+    /// let result = vector.signum();
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn map<T, F>(self, mut op: F) -> Vector4<T> 
-    where 
-        F: FnMut(S) -> T
-    {
-        Vector4::new(
-            op(self.data[0]),
-            op(self.data[1]),
-            op(self.data[2]),
-            op(self.data[3]),
-        )
+    pub fn signum(&self) -> Vector2<S> {
+        let signum = |x: S| if x.is_zero() { S::zero() } else { x.signum() };
+
+        Vector2::new(signum(self.data[0]), signum(self.data[1]))
     }
-}
 
-impl<S> Vector4<S> 
-where 
-    S: NumCast + Copy
-{
-    /// Cast a vector from one type of scalars to another type of scalars.
+    /// Compute a vector with the magnitude of each component of `self` and the 
+    /// sign of the corresponding component of `sign`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,   
+    /// #     Vector2, 
     /// # };
     /// #
-    /// let vector: Vector4<u32> = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
-    /// let expected: Option<Vector4<i32>> = Some(Vector4::new(1_i32, 2_i32, 3_i32, 4_i32));
-    /// let result = vector.cast::<i32>();
+    /// let vector = Vector2::new(2_f64, -3_f64);
+    /// let sign = Vector2::new(-1_f64, 1_f64);
+    /// let expected = Vector2::new(-2_f64, 3_f64);
+    /// let result = vector.copysign(&sign);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn cast<T: NumCast>(&self) -> Option<Vector4<T>> {
-        let x = match num_traits::cast(self.data[0]) {
-            Some(value) => value,
-            None => return None,
-        };
-        let y = match num_traits::cast(self.data[1]) {
-            Some(value) => value,
-            None => return None,
-        };
-        let z = match num_traits::cast(self.data[2]) {
-            Some(value) => value,
-            None => return None,
+    pub fn copysign(&self, sign: &Vector2<S>) -> Vector2<S> {
+        let copysign = |x: S, sign: S| if sign.is_sign_negative() { -x.abs() } else { x.abs() };
+
+        Vector2::new(copysign(self.data[0], sign.data[0]), copysign(self.data[1], sign.data[1]))
+    }
+
+    /// Compute the GLSL step function of each component of a vector.
+    ///
+    /// Each component of the result is `0` if the corresponding component of
+    /// `x` is less than the corresponding component of `edge`, and `1` 
+    /// otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let edge = Vector2::new(1_f64, 2_f64);
+    /// let x = Vector2::new(0.5_f64, 2_f64);
+    /// let expected = Vector2::new(0_f64, 1_f64);
+    /// let result = Vector2::step(&edge, &x);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn step(edge: &Vector2<S>, x: &Vector2<S>) -> Vector2<S> {
+        let step = |edge_i: S, x_i: S| {
+            if x_i < edge_i { S::zero() } else { S::one() }
         };
-        let w = match num_traits::cast(self.data[3]) {
-            Some(value) => value,
-            None => return None,
+
+        Vector2::new(
+            step(edge.data[0], x.data[0]),
+            step(edge.data[1], x.data[1]),
+        )
+    }
+
+    /// Compute the GLSL smoothstep function of each component of a vector.
+    ///
+    /// Each component of the result performs a smooth Hermite interpolation 
+    /// between `0` and `1` when the corresponding component of `x` lies between 
+    /// the corresponding components of `edge0` and `edge1`. That is, for each 
+    /// component
+    /// ```text
+    /// t := clamp((x - edge0) / (edge1 - edge0), 0, 1)
+    /// smoothstep(edge0, edge1, x) := t * t * (3 - 2 * t)
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let edge0 = Vector2::new(0_f64, 0_f64);
+    /// let edge1 = Vector2::new(1_f64, 1_f64);
+    /// let x = Vector2::new(-1_f64, 0.5_f64);
+    /// let expected = Vector2::new(0_f64, 0.5_f64);
+    /// let result = Vector2::smoothstep(&edge0, &edge1, &x);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn smoothstep(edge0: &Vector2<S>, edge1: &Vector2<S>, x: &Vector2<S>) -> Vector2<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let three = two + one;
+        let smoothstep = |edge0_i: S, edge1_i: S, x_i: S| {
+            let t = ((x_i - edge0_i) / (edge1_i - edge0_i)).max(zero).min(one);
+            t * t * (three - two * t)
         };
 
-        Some(Vector4::new(x, y, z, w))
+        Vector2::new(
+            smoothstep(edge0.data[0], edge1.data[0], x.data[0]),
+            smoothstep(edge0.data[1], edge1.data[1], x.data[1]),
+        )
     }
-}
 
-impl<S> Vector4<S> 
-where 
-    S: Scalar
-{
-    /// Returns the **x-axis** unit vector, a unit vector with the **x-component**
-    /// component as a `1` and the rest of the components are zero.
+    /// Linearly blend between the two vectors `self` and `other` using the 
+    /// GLSL mix function.
+    ///
+    /// This is the same operation as `lerp`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let v0 = Vector2::new(0_f64, 0_f64);
+    /// let v1 = Vector2::new(10_f64, 20_f64);
+    /// let expected = Vector2::new(5_f64, 10_f64);
+    /// let result = v0.mix(&v1, 0.5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
     #[inline]
-    pub fn unit_x() -> Vector4<S> {
-        Vector4::new(S::one(), S::zero(), S::zero(), S::zero())
+    pub fn mix(&self, other: &Vector2<S>, amount: S) -> Vector2<S> {
+        self.lerp(other, amount)
     }
 
-    /// Returns the **y-axis** unit vector, a unit vector with the **y-component**
-    /// component as a `1` and the rest of the components are zero.
+    /// Compute the **L1** norm of a vector.
+    ///
+    /// The **L1** norm of a vector is the sum of the absolute values of its 
+    /// components. It is also known as the taxicab or Manhattan norm.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(-3_f64, 4_f64);
+    ///
+    /// assert_eq!(vector.l1_norm(), 7_f64);
+    /// ```
     #[inline]
-    pub fn unit_y() -> Vector4<S> {
-        Vector4::new(S::zero(), S::one(), S::zero(), S::zero())
+    pub fn l1_norm(&self) -> S {
+        self.data[0].abs() + self.data[1].abs()
     }
-    
-    /// Returns the **z-axis** unit vector, a unit vector with the **z-component**
-    /// component as a `1` and the rest of the components are zero.
+
+    /// Compute the **L-infinity** norm of a vector.
+    ///
+    /// The **L-infinity** norm of a vector is the largest absolute value of 
+    /// its components. It is also known as the maximum or Chebyshev norm.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2, 
+    /// # };
+    /// #
+    /// let vector = Vector2::new(-3_f64, 4_f64);
+    ///
+    /// assert_eq!(vector.linf_norm(), 4_f64);
+    /// ```
     #[inline]
-    pub fn unit_z() -> Vector4<S> {
-        Vector4::new(S::zero(), S::zero(), S::one(), S::zero())
+    pub fn linf_norm(&self) -> S {
+        self.data[0].abs().max(self.data[1].abs())
     }
+}
 
-    /// Returns the **w-axis** unit vector, a unit vector with the **w-component**
-    /// component as a `1` and the rest of the components are zero.
+impl<S> fmt::Display for Vector2<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter, 
+            "Vector2 [{}, {}]", 
+            self.data[0], self.data[1]
+        )
+    }
+}
+
+impl<S> From<(S, S)> for Vector2<S> 
+where 
+    S: Scalar
+{
     #[inline]
-    pub fn unit_w() -> Vector4<S> {
-        Vector4::new(S::zero(), S::zero(), S::zero(), S::one())
+    fn from((x, y): (S, S)) -> Vector2<S> {
+        Vector2::new(x, y)
     }
+}
 
-    /// Compute the zero vector.
-    ///
-    /// The zero vector is the vector in which all of its elements are zero.
+impl<S> From<[S; 2]> for Vector2<S> 
+where 
+    S: Scalar
+{
     #[inline]
-    pub fn zero() -> Vector4<S> {
-        Vector4::new(S::zero(), S::zero(), S::zero(), S::zero())
+    fn from(v: [S; 2]) -> Vector2<S> {
+        Vector2::new(v[0], v[1])
     }
-    
-    /// Determine whether a vector is the zero vector.
+}
+
+impl<S> From<&(S, S)> for Vector2<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &(S, S)) -> Vector2<S> {
+        Vector2::new(v.0, v.1)
+    }
+}
+
+impl<S> From<&[S; 2]> for Vector2<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &[S; 2]) -> Vector2<S> {
+        Vector2::new(v[0], v[1])
+    }
+}
+
+impl<'a, S> From<&'a [S; 2]> for &'a Vector2<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &'a [S; 2]) -> &'a Vector2<S> {
+        unsafe { 
+            &*(v as *const [S; 2] as *const Vector2<S>)
+        }
+    }
+}
+
+impl<S> From<Vector2<S>> for [S; 2] 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: Vector2<S>) -> [S; 2] {
+        v.data
+    }
+}
+
+
+/// A representation of three-dimensional vectors in a Euclidean space.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector3<S> {
+    data: [S; 3],
+}
+
+impl<S> Vector3<S> {
+    /// Construct a new vector.
+    #[inline]
+    pub const fn new(x: S, y: S, z: S) -> Vector3<S> {
+        Vector3 { 
+            data: [x, y, z],
+        }
+    }
+}
+
+impl<S> Vector3<S> 
+where 
+    S: NumCast + Copy
+{
+    /// Cast a vector from one type of scalars to another type of scalars.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,   
+    /// # };
+    /// #
+    /// let vector: Vector3<u32> = Vector3::new(1_u32, 2_u32, 3_u32);
+    /// let expected: Option<Vector3<i32>> = Some(Vector3::new(1_i32, 2_i32, 3_i32));
+    /// let result = vector.cast::<i32>();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Vector3<T>> {
+        let x = match num_traits::cast(self.data[0]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let y = match num_traits::cast(self.data[1]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let z = match num_traits::cast(self.data[2]) {
+            Some(value) => value,
+            None => return None,
+        };
+
+        Some(Vector3::new(x, y, z))
+    }
+}
+
+impl<S> Vector3<S> 
+where 
+    S: Copy
+{
+    /// Extend a three-dimensional vector into a four-dimensional vector using the 
+    /// supplied **w-component**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let result = v.extend(4_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn extend(self, w: S) -> Vector4<S> {
+        Vector4::new(self.data[0], self.data[1], self.data[2], w)
+    }
+
+    /// Contract a three-dimensional vector to a two-dimensional vector
+    /// by removing the **z-component**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2,
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Vector2::new(1_f64, 2_f64);
+    /// let result = v.contract();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn contract(self) -> Vector2<S> {
+        Vector2::new(self.data[0], self.data[1])
+    }
+
+    /// Truncate a vector to a vector of one lower dimension by removing its 
+    /// last component.
+    ///
+    /// This is an alias for `contract`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2,
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Vector2::new(1_f64, 2_f64);
+    /// let result = v.truncate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn truncate(self) -> Vector2<S> {
+        self.contract()
+    }
+
+    /// Construct a vector from a fill value.
+    ///
+    /// Every component of the resulting vector will have the same value
+    /// supplied by the `value` argument.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,   
+    /// # };
+    /// #
+    /// let fill_value = 3_f64;
+    /// let result = Vector3::from_fill(fill_value);
+    /// let expected = Vector3::new(3_f64, 3_f64, 3_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Vector3<S> {
+        Vector3::new(value, value, value)
+    }
+
+    /// The length of the the underlying array storing the vector components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        3
+    }
+
+    /// The shape of the underlying array storing the vector components.
+    ///
+    /// The shape is the equivalent number of columns and rows of the 
+    /// array as though it represents a matrix. The order of the descriptions 
+    /// of the shape of the array is **(rows, columns)**.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (3, 1)
+    }
+
+    /// Get a pointer to the underlying array.
+    #[inline]
+    pub fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 3]>>::as_ref(self)
+    }
+
+    /// Map an operation on that acts on the coordinates of a vector, returning 
+    /// a vector whose coordinates are of the new scalar type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,  
+    /// # };
+    /// #
+    /// let vector: Vector3<u32> = Vector3::new(1_u32, 2_u32, 3_u32);
+    /// let expected: Vector3<i32> = Vector3::new(2_i32, 3_i32, 4_i32);
+    /// let result: Vector3<i32> = vector.map(|comp| (comp + 1) as i32);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn map<T, F>(self, mut op: F) -> Vector3<T> 
+    where 
+        F: FnMut(S) -> T
+    {
+        Vector3::new(op(self.data[0]), op(self.data[1]), op(self.data[2]))
+    }
+}
+
+impl<S> Vector3<S> 
+where 
+    S: Scalar
+{
+    /// Returns the **x-axis** unit vector, a unit vector with the **x-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_x() -> Vector3<S> {
+        Vector3::new(S::one(), S::zero(), S::zero())
+    }
+
+    /// Returns the **y-axis** unit vector, a unit vector with the **y-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_y() -> Vector3<S> {
+        Vector3::new(S::zero(), S::one(), S::zero())
+    }
+    
+    /// Returns the **z-axis** unit vector, a unit vector with the **z-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_z() -> Vector3<S> {
+        Vector3::new(S::zero(), S::zero(), S::one())
+    }
+
+    /// Compute the zero vector.
+    ///
+    /// The zero vector is the vector in which all of its elements are zero.
+    #[inline]
+    pub fn zero() -> Vector3<S> {
+        Vector3::new(S::zero(), S::zero(), S::zero())
+    }
+    
+    /// Determine whether a vector is the zero vector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero() && 
+        self.data[1].is_zero() && 
+        self.data[2].is_zero()
+    }
+
+    /// Compute the coordinates of a vector in projective space.
+    ///
+    /// The function appends a `0` to the vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let expected = Vector4::new(1_i32, 2_i32, 3_i32, 0_i32);
+    /// let result = vector.to_homogeneous();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector4<S> {
+        self.extend(S::zero())
+    }
+
+    /// Compute the coordinates of a projective vector in Euclidean space.
+    ///
+    /// The function removes a `0` from the end of the vector, otherwise it
+    /// returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector2,
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 0_i32);
+    /// let expected = Some(Vector2::new(1_i32, 2_i32));
+    /// let result = vector.from_homogeneous();
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let vector = Vector3::new(1_i32, 2_i32, 1_i32);
+    /// let expected: Option<Vector2<i32>> = None;
+    /// let result = vector.from_homogeneous();
+    ///
+    /// assert!(result.is_none());
+    /// ```
+    #[inline]
+    pub fn from_homogeneous(&self) -> Option<Vector2<S>> {
+        if self.data[2].is_zero() {
+            Some(self.contract())
+        } else {
+            None
+        }
+    }
+
+    /// Compute the Euclidean dot product (inner product) of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let vector2 = Vector3::new(4_f64, 5_f64, 6_f64);
+    /// 
+    /// assert_eq!(vector1.dot(&vector2), 32_f64);
+    /// ```
+    #[inline]
+    pub fn dot(self, other: &Vector3<S>) -> S {
+        self.data[0] * other.data[0] + 
+        self.data[1] * other.data[1] + 
+        self.data[2] * other.data[2]
+    }
+
+    /// Compute the component-wise minimum of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_i32, -2_i32, 3_i32);
+    /// let vector2 = Vector3::new(-3_i32, 4_i32, 0_i32);
+    /// let expected = Vector3::new(-3_i32, -2_i32, 0_i32);
+    /// let result = vector1.component_min(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn component_min(&self, other: &Vector3<S>) -> Vector3<S> {
+        let x = if self.data[0] < other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] < other.data[1] { self.data[1] } else { other.data[1] };
+        let z = if self.data[2] < other.data[2] { self.data[2] } else { other.data[2] };
+
+        Vector3::new(x, y, z)
+    }
+
+    /// Compute the component-wise maximum of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_i32, -2_i32, 3_i32);
+    /// let vector2 = Vector3::new(-3_i32, 4_i32, 0_i32);
+    /// let expected = Vector3::new(1_i32, 4_i32, 3_i32);
+    /// let result = vector1.component_max(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn component_max(&self, other: &Vector3<S>) -> Vector3<S> {
+        let x = if self.data[0] > other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] > other.data[1] { self.data[1] } else { other.data[1] };
+        let z = if self.data[2] > other.data[2] { self.data[2] } else { other.data[2] };
+
+        Vector3::new(x, y, z)
+    }
+
+    /// Clamp each component of a vector to the range given by the 
+    /// corresponding components of the vectors `min` and `max`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(-5_i32, 5_i32, 0_i32);
+    /// let min = Vector3::new(-1_i32, 0_i32, 1_i32);
+    /// let max = Vector3::new(1_i32, 1_i32, 1_i32);
+    /// let expected = Vector3::new(-1_i32, 1_i32, 1_i32);
+    /// let result = vector.clamp(&min, &max);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn clamp(&self, min: &Vector3<S>, max: &Vector3<S>) -> Vector3<S> {
+        self.component_max(min).component_min(max)
+    }
+
+    /// Compute the largest component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 5_i32, 3_i32);
+    ///
+    /// assert_eq!(vector.max_element(), 5_i32);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> S {
+        self.data[self.argmax()]
+    }
+
+    /// Compute the smallest component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 5_i32, -3_i32);
+    ///
+    /// assert_eq!(vector.min_element(), -3_i32);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> S {
+        self.data[self.argmin()]
+    }
+
+    /// Compute the index of the largest component of a vector.
+    ///
+    /// If several components are equal to the largest component, the 
+    /// function returns the smallest such index.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 5_i32, 3_i32);
+    ///
+    /// assert_eq!(vector.argmax(), 1);
+    /// ```
+    #[inline]
+    pub fn argmax(&self) -> usize {
+        let mut index = 0;
+        for i in 1..3 {
+            if self.data[i] > self.data[index] {
+                index = i;
+            }
+        }
+
+        index
+    }
+
+    /// Compute the index of the smallest component of a vector.
+    ///
+    /// If several components are equal to the smallest component, the 
+    /// function returns the smallest such index.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 5_i32, -3_i32);
+    ///
+    /// assert_eq!(vector.argmin(), 2);
+    /// ```
+    #[inline]
+    pub fn argmin(&self) -> usize {
+        let mut index = 0;
+        for i in 1..3 {
+            if self.data[i] < self.data[index] {
+                index = i;
+            }
+        }
+
+        index
+    }
+
+    /// Compute the sum of the components of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+    ///
+    /// assert_eq!(vector.sum(), 6_i32);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> S {
+        self.data[0] + self.data[1] + self.data[2]
+    }
+
+    /// Compute the product of the components of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+    ///
+    /// assert_eq!(vector.product(), 6_i32);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> S {
+        self.data[0] * self.data[1] * self.data[2]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is less than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_i32, 5_i32, 3_i32);
+    /// let vector2 = Vector3::new(2_i32, 2_i32, 2_i32);
+    /// let expected = [true, false, false];
+    /// let result = vector1.cmp_lt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_lt(&self, other: &Vector3<S>) -> [bool; 3] {
+        [self.data[0] < other.data[0], self.data[1] < other.data[1], self.data[2] < other.data[2]]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is greater than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_i32, 5_i32, 3_i32);
+    /// let vector2 = Vector3::new(2_i32, 2_i32, 2_i32);
+    /// let expected = [false, true, true];
+    /// let result = vector1.cmp_gt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_gt(&self, other: &Vector3<S>) -> [bool; 3] {
+        [self.data[0] > other.data[0], self.data[1] > other.data[1], self.data[2] > other.data[2]]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is equal to the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_i32, 5_i32, 3_i32);
+    /// let vector2 = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let expected = [true, false, true];
+    /// let result = vector1.cmp_eq(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_eq(&self, other: &Vector3<S>) -> [bool; 3] {
+        [self.data[0] == other.data[0], self.data[1] == other.data[1], self.data[2] == other.data[2]]
+    }
+
+    /// Construct a vector by choosing each component from one of two vectors 
+    /// according to a mask.
+    ///
+    /// Each component of the result is the corresponding component of 
+    /// `if_true` where the mask is `true`, and of `if_false` where the mask is 
+    /// `false`. Together with the component-wise comparison functions, such 
+    /// as `cmp_lt`, this allows branch-free conditional logic on vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let if_true = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let if_false = Vector3::new(4_i32, 5_i32, 6_i32);
+    /// let mask = [true, false, true];
+    /// let expected = Vector3::new(1_i32, 5_i32, 3_i32);
+    /// let result = Vector3::select(mask, &if_true, &if_false);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn select(mask: [bool; 3], if_true: &Vector3<S>, if_false: &Vector3<S>) -> Vector3<S> {
+        Vector3::new(
+            if mask[0] { if_true.data[0] } else { if_false.data[0] },
+            if mask[1] { if_true.data[1] } else { if_false.data[1] },
+            if mask[2] { if_true.data[2] } else { if_false.data[2] }
+        )
+    }
+}
+
+impl<S> Vector3<S> 
+where 
+    S: ScalarSigned
+{
+    /// Compute the negation of a vector mutably in place.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let mut result = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let expected = -result;
+    /// result.neg_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn neg_mut(&mut self) {
+        self.data[0] = -self.data[0];
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+    }
+
+    /// Compute the cross product of two three-dimensional vectors. 
+    ///
+    /// For the vector dimensions used in computer graphics 
+    /// (up to four dimensions), the cross product is well-defined only in 
+    /// three dimensions. The cross product is a form of vector 
+    /// multiplication that computes a vector normal to the plane swept out by 
+    /// the two vectors. The magnitude of this vector is the area of the 
+    /// parallelogram swept out by the two vectors. 
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,  
+    /// # };
+    /// #
+    /// let vector1 = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let vector2 = Vector3::new(4_f64, 5_f64, 6_f64);
+    /// let cross = vector1.cross(&vector2);
+    ///
+    /// assert_eq!(cross, Vector3::new(-3_f64, 6_f64, -3_f64));
+    /// // The cross product is perpendicular to the two input vectors.
+    /// assert_eq!(cross.dot(&vector1), 0_f64);
+    /// assert_eq!(cross.dot(&vector2), 0_f64);
+    ///
+    /// // The cross product of a vector with itself is zero.
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(vector.cross(&vector), Vector3::zero());
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: &Vector3<S>) -> Self {
+        let x = self.data[1] * other.data[2] - self.data[2] * other.data[1];
+        let y = self.data[2] * other.data[0] - self.data[0] * other.data[2];
+        let z = self.data[0] * other.data[1] - self.data[1] * other.data[0];
+    
+        Vector3::new(x, y, z)
+    }
+
+    /// Compute the scalar triple product of three vectors.
+    ///
+    /// The scalar triple product is defined by
+    /// ```text
+    /// triple_product(a, b, c) := a . (b x c)
+    /// ```
+    /// Its absolute value is the volume of the parallelepiped spanned by the 
+    /// three vectors, and its sign gives the orientation of the three vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,  
+    /// # };
+    /// #
+    /// let a = Vector3::new(2_f64, 0_f64, 0_f64);
+    /// let b = Vector3::new(0_f64, 3_f64, 0_f64);
+    /// let c = Vector3::new(1_f64, 1_f64, 4_f64);
+    ///
+    /// assert_eq!(Vector3::triple_product(&a, &b, &c), 24_f64);
+    /// assert_eq!(Vector3::triple_product(&b, &a, &c), -24_f64);
+    /// ```
+    #[inline]
+    pub fn triple_product(a: &Vector3<S>, b: &Vector3<S>, c: &Vector3<S>) -> S {
+        a.dot(&b.cross(c))
+    }
+
+    /// Compute the component-wise absolute value of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(-1_i32, 2_i32, -3_i32);
+    /// let expected = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let result = vector.abs();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn abs(&self) -> Vector3<S> {
+        Vector3::new(self.data[0].abs(), self.data[1].abs(), self.data[2].abs())
+    }
+}
+
+impl<S> Vector3<S> 
+where 
+    S: ScalarFloat
+{
+    /// Linearly interpolate between the two vectors `self` and `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,  
+    /// # };
+    /// #
+    /// let v0 = Vector3::new(0_f64, 0_f64, 0_f64);
+    /// let v1 = Vector3::new(10_f64, 20_f64, 30_f64);
+    /// let amount = 0.7;
+    /// let expected = Vector3::new(7_f64, 14_f64, 21_f64);
+    /// let result = v0.lerp(&v1, amount);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Vector3<S>, amount: S) -> Vector3<S> {
+        self + ((other - self) * amount)
+    }
+
+    /// Linearly interpolate between the two vectors `self` and `other`, with
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
+    ///
+    /// Unlike `lerp`, the result never extrapolates past either endpoint.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,  
+    /// # };
+    /// #
+    /// let v0 = Vector3::new(0_f64, 0_f64, 0_f64);
+    /// let v1 = Vector3::new(10_f64, 20_f64, 30_f64);
+    ///
+    /// assert_eq!(v0.lerp_clamped(&v1, 2_f64), v1);
+    /// assert_eq!(v0.lerp_clamped(&v1, -1_f64), v0);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: &Vector3<S>, amount: S) -> Vector3<S> {
+        self.lerp(other, amount.max(S::zero()).min(S::one()))
+    }
+
+    /// Returns `true` if the elements of a vector are all finite. 
+    /// Otherwise, it returns `false`. 
+    ///
+    /// A vector is finite when all of its elements are finite. This is useful 
+    /// for vector and matrix types working with fixed precision floating point 
+    /// values.
+    ///
+    /// ## Example (Finite Vector)
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let v = Vector3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert!(v.is_finite()); 
+    /// ```
+    ///
+    /// ## Example (Not A Finite Vector)
+    /// 
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let w = Vector3::new(1_f64, f64::NAN, f64::NEG_INFINITY);
+    ///
+    /// assert!(!w.is_finite()); 
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data[0].is_finite() && 
+        self.data[1].is_finite() && 
+        self.data[2].is_finite()
+    }
+
+    /// Returns `true` if any element of a vector is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_f64, f64::NAN, 3_f64);
+    ///
+    /// assert!(vector.is_nan());
+    ///
+    /// let vector = Vector3::new(1_f64, f64::INFINITY, 3_f64);
+    ///
+    /// assert!(!vector.is_nan());
+    /// assert!(!vector.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0].is_nan() || 
+        self.data[1].is_nan() || 
+        self.data[2].is_nan()
+    }
+
+    /// Compute the projection of the vector `self` onto the vector
+    /// `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// #     Magnitude,
+    /// # };
+    /// # 
+    /// let vector = Vector3::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64, 2_f64);
+    /// let unit_x = Vector3::unit_x();
+    /// let unit_y = Vector3::unit_y();
+    /// let unit_z = Vector3::unit_z();
+    /// let projected_x = vector.project(&unit_x);
+    /// let projected_y = vector.project(&unit_y);
+    /// let projected_z = vector.project(&unit_z);
+    ///
+    /// assert_eq!(projected_x, vector.x * unit_x);
+    /// assert_eq!(projected_y, vector.y * unit_y);
+    /// assert_eq!(projected_z, vector.z * unit_z);
+    /// ```
+    #[inline]
+    pub fn project(&self, other: &Vector3<S>) -> Vector3<S> {
+        other * (self.dot(other) / other.magnitude_squared())
+    }
+
+    /// Compute the projection of the vector `self` onto the vector `other`.
+    ///
+    /// This is the same as `project`, under the name that pairs with 
+    /// `reject_from`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// # 
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let other = Vector3::new(3_f64, 4_f64, 5_f64);
+    ///
+    /// assert_eq!(vector.project_onto(&other), vector.project(&other));
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: &Vector3<S>) -> Vector3<S> {
+        self.project(other)
+    }
+
+    /// Compute the rejection of the vector `self` from the vector `other`.
+    ///
+    /// The rejection of `self` from `other` is the component of `self` 
+    /// perpendicular to `other`. Together with the projection, it decomposes 
+    /// `self` into components parallel and perpendicular to `other`
+    /// ```text
+    /// self == self.project_onto(other) + self.reject_from(other)
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # 
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let other = Vector3::new(4_f64, 5_f64, 6_f64);
+    /// let projected = vector.project_onto(&other);
+    /// let rejected = vector.reject_from(&other);
+    ///
+    /// assert!(relative_eq!(rejected.dot(&other), 0_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(projected + rejected, vector, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn reject_from(&self, other: &Vector3<S>) -> Vector3<S> {
+        self - self.project_onto(other)
+    }
+
+    /// Reflect the vector `self` about the plane through the origin with the 
+    /// unit normal vector `normal`.
+    ///
+    /// The component of `self` along `normal` changes sign, and the component 
+    /// lying in the plane is unchanged. The reflected vector is given by
+    /// ```text
+    /// reflect(v, n) = v - 2 * (v . n) * n
+    /// ```
+    /// where `v` is the vector `self` and `n` is the unit normal vector 
+    /// `normal`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Unit,
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_f64, -1_f64, 0_f64);
+    /// let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+    /// let expected = Vector3::new(1_f64, 1_f64, 0_f64);
+    /// let result = vector.reflect(&normal);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reflect(&self, normal: &Unit<Vector3<S>>) -> Vector3<S> {
+        let two = S::one() + S::one();
+
+        self - normal.as_ref() * (two * self.dot(normal))
+    }
+
+    /// Refract the vector `self` through a surface with the unit normal 
+    /// vector `normal` using Snell's law.
+    ///
+    /// The vector `self` is the incident direction, and is assumed to be a unit 
+    /// vector pointing towards the surface. The argument `eta` is the ratio of the 
+    /// index of refraction of the incident medium to the index of refraction of 
+    /// the transmitting medium. The function returns `None` when the incident 
+    /// vector undergoes total internal reflection.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Unit,
+    /// #     Magnitude,
+    /// # };
+    /// #
+    /// let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+    ///
+    /// // A ray hitting the surface head on is not bent.
+    /// let vector = -Vector3::unit_y();
+    /// let expected = Some(vector);
+    /// let result = vector.refract(&normal, 1.5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// // A grazing ray past the critical angle is totally internally reflected.
+    /// let vector = Vector3::new(1_f64, -0.1_f64, 0_f64).normalize();
+    /// let result = vector.refract(&normal, 1.5_f64);
+    ///
+    /// assert!(result.is_none());
+    /// ```
+    #[inline]
+    pub fn refract(&self, normal: &Unit<Vector3<S>>, eta: S) -> Option<Vector3<S>> {
+        let one = S::one();
+        let cos_theta_i = self.dot(normal);
+        let k = one - eta * eta * (one - cos_theta_i * cos_theta_i);
+
+        if k < S::zero() {
+            None
+        } else {
+            Some(self * eta - normal.as_ref() * (eta * cos_theta_i + k.sqrt()))
+        }
+    }
+
+    /// Round each component of a vector down to the nearest integer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1.5_f64, -1.5_f64, 2.4_f64);
+    /// let expected = Vector3::new(1_f64, -2_f64, 2_f64);
+    /// let result = vector.floor();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].floor(),
+            self.data[1].floor(),
+            self.data[2].floor(),
+        )
+    }
+
+    /// Round each component of a vector up to the nearest integer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1.5_f64, -1.5_f64, 2.4_f64);
+    /// let expected = Vector3::new(2_f64, -1_f64, 3_f64);
+    /// let result = vector.ceil();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn ceil(&self) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].ceil(),
+            self.data[1].ceil(),
+            self.data[2].ceil(),
+        )
+    }
+
+    /// Round each component of a vector to the nearest integer, rounding half-way 
+    /// cases away from zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1.5_f64, -1.5_f64, 2.4_f64);
+    /// let expected = Vector3::new(2_f64, -2_f64, 2_f64);
+    /// let result = vector.round();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].round(),
+            self.data[1].round(),
+            self.data[2].round(),
+        )
+    }
+
+    /// Clamp the magnitude of a vector to at most `max`.
+    ///
+    /// If the magnitude of the vector `self` exceeds `max`, the function returns 
+    /// the vector scaled down to have a magnitude of `max` pointing in the same 
+    /// direction. Otherwise, the function returns the vector unchanged. In 
+    /// particular, the zero vector is left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(0_f64, 6_f64, 8_f64);
+    /// let expected = Vector3::new(0_f64, 3_f64, 4_f64);
+    /// let result = vector.clamp_magnitude(5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let vector = Vector3::new(0_f64, 0.6_f64, 0.8_f64);
+    /// let expected = vector;
+    /// let result = vector.clamp_magnitude(5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn clamp_magnitude(&self, max: S) -> Vector3<S> {
+        let magnitude_squared = self.magnitude_squared();
+
+        if magnitude_squared > max * max {
+            self * (max / magnitude_squared.sqrt())
+        } else {
+            *self
+        }
+    }
+
+    /// Construct a vector from spherical coordinates.
+    ///
+    /// The spherical coordinates use the physics convention with the 
+    /// **positive y-axis** as the polar axis. The polar angle `theta` is 
+    /// measured from the **positive y-axis**, and the azimuthal angle `phi`
+    /// is measured in the **zx-plane** from the **positive x-axis** towards 
+    /// the **positive z-axis**. That is
+    /// ```text
+    /// x == radius * sin(theta) * cos(phi)
+    /// y == radius * cos(theta)
+    /// z == radius * sin(theta) * sin(phi)
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Radians,
+    /// #     Angle,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let radius = 2_f64;
+    /// let theta = Radians::full_turn_div_4();
+    /// let phi = Radians::full_turn_div_4();
+    /// let expected = Vector3::new(0_f64, 0_f64, 2_f64);
+    /// let result = Vector3::from_spherical(radius, theta, phi);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_spherical(radius: S, theta: Radians<S>, phi: Radians<S>) -> Vector3<S> {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        Vector3::new(
+            radius * sin_theta * cos_phi,
+            radius * cos_theta,
+            radius * sin_theta * sin_phi,
+        )
+    }
+
+    /// Compute the spherical coordinates `(radius, theta, phi)` of a vector.
+    ///
+    /// This is the inverse operation of `from_spherical`, using the same 
+    /// convention. The polar angle `theta` lies in the range `[0, pi]`, and 
+    /// the azimuthal angle `phi` lies in the range `(-pi, pi]`. The zero 
+    /// vector maps to zero radius and zero angles.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Radians,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(0_f64, 3_f64, 0_f64);
+    /// let (radius, theta, phi) = vector.to_spherical();
+    ///
+    /// assert!(relative_eq!(radius, 3_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(theta, Radians(0_f64), epsilon = 1e-10));
+    /// assert!(relative_eq!(phi, Radians(0_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn to_spherical(&self) -> (S, Radians<S>, Radians<S>) {
+        let radius = self.magnitude();
+        if radius.is_zero() {
+            return (S::zero(), Radians(S::zero()), Radians(S::zero()));
+        }
+
+        let cos_theta = self.data[1] / radius;
+        let cos_theta_clamped = cos_theta.max(-S::one()).min(S::one());
+        let theta = Radians::acos(cos_theta_clamped);
+        let phi = Radians::atan2(self.data[2], self.data[0]);
+
+        (radius, theta, phi)
+    }
+
+    /// Compute the reciprocal of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(2_f64, 4_f64, 8_f64);
+    /// let expected = Vector3::new(0.5_f64, 0.25_f64, 0.125_f64);
+    /// let result = vector.recip();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].recip(),
+            self.data[1].recip(),
+            self.data[2].recip(),
+        )
+    }
+
+    /// Compute the square root of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(4_f64, 9_f64, 16_f64);
+    /// let expected = Vector3::new(2_f64, 3_f64, 4_f64);
+    /// let result = vector.sqrt();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].sqrt(),
+            self.data[1].sqrt(),
+            self.data[2].sqrt(),
+        )
+    }
+
+    /// Raise each component of a vector to the floating point power `exponent`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Vector3::new(1_f64, 8_f64, 27_f64);
+    /// let result = vector.powf(3_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn powf(&self, exponent: S) -> Vector3<S> {
+        Vector3::new(
+            self.data[0].powf(exponent),
+            self.data[1].powf(exponent),
+            self.data[2].powf(exponent),
+        )
+    }
+
+    /// Compute the sign of each component of a vector.
+    ///
+    /// Each component of the result is `-1` if the corresponding component is 
+    /// negative, `1` if it is positive, and `0` if it is zero. This differs 
+    /// from the scalar `signum` function, which maps zero to `1`, so that 
+    /// zero components do not acquire a spurious direction. A `NaN` component 
+    /// stays `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(-2_f64, 0_f64, 5_f64);
+    /// let expected = Vector3::new(-1_f64, 0_f64, 1_f64);
+    /// let result = vector.signum();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn signum(&self) -> Vector3<S> {
+        let signum = |x: S| if x.is_zero() { S::zero() } else { x.signum() };
+
+        Vector3::new(
+            signum(self.data[0]),
+            signum(self.data[1]),
+            signum(self.data[2]),
+        )
+    }
+
+    /// Compute a vector with the magnitude of each component of `self` and the 
+    /// sign of the corresponding component of `sign`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(2_f64, -3_f64, 4_f64);
+    /// let sign = Vector3::new(-1_f64, 1_f64, -0_f64);
+    /// let expected = Vector3::new(-2_f64, 3_f64, -4_f64);
+    /// let result = vector.copysign(&sign);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn copysign(&self, sign: &Vector3<S>) -> Vector3<S> {
+        let copysign = |x: S, sign: S| if sign.is_sign_negative() { -x.abs() } else { x.abs() };
+
+        Vector3::new(
+            copysign(self.data[0], sign.data[0]),
+            copysign(self.data[1], sign.data[1]),
+            copysign(self.data[2], sign.data[2]),
+        )
+    }
+
+    /// Compute the GLSL step function of each component of a vector.
+    ///
+    /// Each component of the result is `0` if the corresponding component of
+    /// `x` is less than the corresponding component of `edge`, and `1` 
+    /// otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let edge = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let x = Vector3::new(0.5_f64, 2_f64, 4_f64);
+    /// let expected = Vector3::new(0_f64, 1_f64, 1_f64);
+    /// let result = Vector3::step(&edge, &x);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn step(edge: &Vector3<S>, x: &Vector3<S>) -> Vector3<S> {
+        let step = |edge_i: S, x_i: S| {
+            if x_i < edge_i { S::zero() } else { S::one() }
+        };
+
+        Vector3::new(
+            step(edge.data[0], x.data[0]),
+            step(edge.data[1], x.data[1]),
+            step(edge.data[2], x.data[2]),
+        )
+    }
+
+    /// Compute the GLSL smoothstep function of each component of a vector.
+    ///
+    /// Each component of the result performs a smooth Hermite interpolation 
+    /// between `0` and `1` when the corresponding component of `x` lies between 
+    /// the corresponding components of `edge0` and `edge1`. That is, for each 
+    /// component
+    /// ```text
+    /// t := clamp((x - edge0) / (edge1 - edge0), 0, 1)
+    /// smoothstep(edge0, edge1, x) := t * t * (3 - 2 * t)
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let edge0 = Vector3::new(0_f64, 0_f64, 0_f64);
+    /// let edge1 = Vector3::new(1_f64, 1_f64, 1_f64);
+    /// let x = Vector3::new(-1_f64, 0.5_f64, 2_f64);
+    /// let expected = Vector3::new(0_f64, 0.5_f64, 1_f64);
+    /// let result = Vector3::smoothstep(&edge0, &edge1, &x);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn smoothstep(edge0: &Vector3<S>, edge1: &Vector3<S>, x: &Vector3<S>) -> Vector3<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let three = two + one;
+        let smoothstep = |edge0_i: S, edge1_i: S, x_i: S| {
+            let t = ((x_i - edge0_i) / (edge1_i - edge0_i)).max(zero).min(one);
+            t * t * (three - two * t)
+        };
+
+        Vector3::new(
+            smoothstep(edge0.data[0], edge1.data[0], x.data[0]),
+            smoothstep(edge0.data[1], edge1.data[1], x.data[1]),
+            smoothstep(edge0.data[2], edge1.data[2], x.data[2]),
+        )
+    }
+
+    /// Linearly blend between the two vectors `self` and `other` using the 
+    /// GLSL mix function.
+    ///
+    /// This is the same operation as `lerp`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let v0 = Vector3::new(0_f64, 0_f64, 0_f64);
+    /// let v1 = Vector3::new(10_f64, 20_f64, 30_f64);
+    /// let expected = Vector3::new(5_f64, 10_f64, 15_f64);
+    /// let result = v0.mix(&v1, 0.5_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn mix(&self, other: &Vector3<S>, amount: S) -> Vector3<S> {
+        self.lerp(other, amount)
+    }
+
+    /// Compute the **L1** norm of a vector.
+    ///
+    /// The **L1** norm of a vector is the sum of the absolute values of its 
+    /// components. It is also known as the taxicab or Manhattan norm.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(-3_f64, 4_f64, -5_f64);
+    ///
+    /// assert_eq!(vector.l1_norm(), 12_f64);
+    /// ```
+    #[inline]
+    pub fn l1_norm(&self) -> S {
+        self.data[0].abs() + self.data[1].abs() + self.data[2].abs()
+    }
+
+    /// Compute the **L-infinity** norm of a vector.
+    ///
+    /// The **L-infinity** norm of a vector is the largest absolute value of 
+    /// its components. It is also known as the maximum or Chebyshev norm.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3, 
+    /// # };
+    /// #
+    /// let vector = Vector3::new(-3_f64, 4_f64, -5_f64);
+    ///
+    /// assert_eq!(vector.linf_norm(), 5_f64);
+    /// ```
+    #[inline]
+    pub fn linf_norm(&self) -> S {
+        self.data[0].abs().max(self.data[1].abs()).max(self.data[2].abs())
+    }
+
+    /// Compute a vector perpendicular to a vector.
+    ///
+    /// The function crosses the vector with the coordinate axis along which 
+    /// the vector has its smallest component in absolute value. That axis is 
+    /// the one furthest from being parallel to the vector, which keeps the 
+    /// result well away from zero. The result is nonzero for every nonzero 
+    /// input vector, but it is not normalized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Magnitude,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let perpendicular = vector.any_perpendicular();
+    ///
+    /// assert_eq!(vector.dot(&perpendicular), 0_f64);
+    /// assert!(perpendicular.magnitude() > 0_f64);
+    /// ```
+    #[inline]
+    pub fn any_perpendicular(&self) -> Vector3<S> {
+        let abs_x = self.data[0].abs();
+        let abs_y = self.data[1].abs();
+        let abs_z = self.data[2].abs();
+        let axis = if abs_x <= abs_y && abs_x <= abs_z {
+            Vector3::unit_x()
+        } else if abs_y <= abs_z {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+
+        self.cross(&axis)
+    }
+}
+
+impl<S> Unit<Vector3<S>> 
+where 
+    S: ScalarFloat
+{
+    /// Complete a unit vector to an orthonormal basis.
+    ///
+    /// The function returns three mutually orthogonal unit vectors whose first 
+    /// element is the input vector. The basis is right-handed, i.e. the cross 
+    /// product of the first two vectors is the third one. The construction 
+    /// follows Duff et al., *Building an Orthonormal Basis, Revisited* (2017), 
+    /// which stays numerically stable for every input direction, including 
+    /// directions at or near the poles.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Magnitude,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let normal = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+    /// let (first, second, third) = normal.orthonormal_basis();
+    ///
+    /// assert_eq!(first, normal.into_inner());
+    /// assert!(relative_eq!(second.magnitude(), 1_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(third.magnitude(), 1_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(first.dot(&second), 0_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(first.dot(&third), 0_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(second.dot(&third), 0_f64, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn orthonormal_basis(&self) -> (Vector3<S>, Vector3<S>, Vector3<S>) {
+        let normal = self.as_ref();
+        let sign = if normal.z >= S::zero() { S::one() } else { -S::one() };
+        let a = -S::one() / (sign + normal.z);
+        let b = normal.x * normal.y * a;
+        let tangent = Vector3::new(
+            S::one() + sign * normal.x * normal.x * a, 
+            sign * b, 
+            -sign * normal.x
+        );
+        let bitangent = Vector3::new(
+            b, 
+            sign + normal.y * normal.y * a, 
+            -normal.y
+        );
+
+        (*normal, tangent, bitangent)
+    }
+
+    /// Compute the normalized linear interpolation between two unit vectors.
+    ///
+    /// The function linearly interpolates between the two vectors and 
+    /// normalizes the result. It is cheaper than spherical interpolation, but 
+    /// does not move at a constant angular speed, so it is best suited to 
+    /// vectors with a small angle between them. The result is undefined when 
+    /// the interpolated vector passes through zero, which happens for 
+    /// opposite vectors at `amount == 1 / 2`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector1 = Unit::from_value(Vector3::unit_x());
+    /// let vector2 = Unit::from_value(Vector3::unit_y());
+    /// let expected = Vector3::new(1_f64, 1_f64, 0_f64) / f64::sqrt(2_f64);
+    /// let result = vector1.nlerp(&vector2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result.into_inner(), expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn nlerp(&self, other: &Unit<Vector3<S>>, amount: S) -> Unit<Vector3<S>> {
+        Unit::from_value(self.as_ref().lerp(other.as_ref(), amount))
+    }
+
+    /// Compute the spherical linear interpolation between two unit vectors.
+    ///
+    /// The function interpolates along the shorter great circle arc between 
+    /// the two vectors with constant angular speed. When the vectors are 
+    /// nearly parallel, it falls back to normalized linear interpolation. When 
+    /// they are exactly opposite, every great circle through them is equally 
+    /// short, so the function picks an arc passing through an arbitrary 
+    /// direction perpendicular to `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector1 = Unit::from_value(Vector3::unit_x());
+    /// let vector2 = Unit::from_value(Vector3::unit_y());
+    /// let expected = Vector3::new(f64::sqrt(3_f64) / 2_f64, 1_f64 / 2_f64, 0_f64);
+    /// let result = vector1.slerp(&vector2, 1_f64 / 3_f64);
+    ///
+    /// assert!(relative_eq!(result.into_inner(), expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: &Unit<Vector3<S>>, amount: S) -> Unit<Vector3<S>> {
+        const SIN_ANGLE_THRESHOLD: f64 = 0.001;
+        let one = S::one();
+        let cos_angle = self.dot(other).min(one).max(-one);
+        if cos_angle >= one {
+            return *self;
+        }
+
+        // For very small angles, `sin(angle)` is close to zero and dividing 
+        // by it is inaccurate, so we switch to normalized linear interpolation.
+        let sin_angle = S::sqrt(one - cos_angle * cos_angle);
+        let angle = S::acos(cos_angle);
+        let threshold = S::from(SIN_ANGLE_THRESHOLD).unwrap();
+        if sin_angle < threshold {
+            if cos_angle > S::zero() {
+                return self.nlerp(other, amount);
+            }
+
+            // For nearly opposite vectors, dividing by `sin(angle)` is just as 
+            // inaccurate, so we rotate `self` towards the component of `other` 
+            // perpendicular to it instead. The arc then ends at `other`. Only 
+            // when the vectors are opposite up to rounding error is there no 
+            // such component, and any perpendicular direction will do.
+            let residual = other.as_ref() - self.as_ref() * cos_angle;
+            let residual = residual - self.as_ref() * self.dot(&residual);
+            let perpendicular = residual.try_normalize(S::default_epsilon())
+                .unwrap_or_else(|| {
+                    let (_, perpendicular, _) = self.orthonormal_basis();
+                    perpendicular
+                });
+            let (sin_amount_angle, cos_amount_angle) = S::sin_cos(amount * angle);
+            let result = self.as_ref() * cos_amount_angle + perpendicular * sin_amount_angle;
+
+            return Unit::from_value(result);
+        }
+
+        let a = S::sin((one - amount) * angle) / sin_angle;
+        let b = S::sin(amount * angle) / sin_angle;
+        let result = self.as_ref() * a + other.as_ref() * b;
+
+        Unit::from_value_unchecked(result)
+    }
+}
+
+impl<S> fmt::Display for Vector3<S> 
+where 
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter, 
+            "Vector3 [{}, {}, {}]", 
+            self.data[0], self.data[1], self.data[2]
+        )
+    }
+}
+
+impl<S> From<(S, S, S)> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from((x, y, z): (S, S, S)) -> Vector3<S> {
+        Vector3::new(x, y, z)
+    }
+}
+
+impl<S> From<[S; 3]> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: [S; 3]) -> Vector3<S> {
+        Vector3::new(v[0], v[1], v[2])
+    }
+}
+
+impl<S> From<&[S; 3]> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &[S; 3]) -> Vector3<S> {
+        Vector3::new(v[0], v[1], v[2])
+    }
+}
+
+impl<S> From<&(S, S, S)> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &(S, S, S)) -> Vector3<S> {
+        Vector3::new(v.0, v.1, v.2)
+    }
+}
+
+impl<S> From<Vector4<S>> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: Vector4<S>) -> Vector3<S> {
+        Vector3::new(v.data[0], v.data[1], v.data[2])
+    }
+}
+
+impl<S> From<&Vector4<S>> for Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &Vector4<S>) -> Vector3<S> {
+        Vector3::new(v.data[0], v.data[1], v.data[2])
+    }
+}
+
+impl<'a, S> From<&'a [S; 3]> for &'a Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &'a [S; 3]) -> &'a Vector3<S> {
+        unsafe { 
+            &*(v as *const [S; 3] as *const Vector3<S>)
+        }
+    }
+}
+
+impl<S> From<Vector3<S>> for [S; 3] 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: Vector3<S>) -> [S; 3] {
+        v.data
+    }
+}
+
+impl<'a, S> From<&'a (S, S, S)> for &'a Vector3<S> 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: &'a (S, S, S)) -> &'a Vector3<S> {
+        unsafe { 
+            &*(v as *const (S, S, S) as *const Vector3<S>)
+        }
+    }
+}
+
+
+/// A representation of four-dimensional vectors in a Euclidean space.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector4<S> {
+    data: [S; 4],
+}
+
+impl<S> Vector4<S> {
+    /// Construct a new four-dimensional vector.
+    #[inline]
+    pub const fn new(x: S, y: S, z: S, w: S) -> Vector4<S> {
+        Vector4 { 
+            data: [x, y, z, w],
+        }
+    }
+}
+
+impl<S> Vector4<S> 
+where 
+    S: Copy
+{
+    /// Contract a four-dimensional vector to a three-dimensional vector
+    /// by removing the **w-component**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let v = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let result = v.contract();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn contract(self) -> Vector3<S> {
+        Vector3::new(self.data[0], self.data[1], self.data[2])
+    }
+
+    /// Truncate a vector to a vector of one lower dimension by removing its 
+    /// last component.
+    ///
+    /// This is an alias for `contract`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let v = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let result = v.truncate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn truncate(self) -> Vector3<S> {
+        self.contract()
+    }
+
+    /// Construct a vector from a fill value.
+    ///
+    /// Every component of the resulting vector will have the same value
+    /// supplied by the `value` argument.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,   
+    /// # };
+    /// #
+    /// let fill_value = 3_f64;
+    /// let result = Vector4::from_fill(fill_value);
+    /// let expected = Vector4::new(3_f64, 3_f64, 3_f64, 3_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Vector4<S> {
+        Vector4::new(value, value, value, value)
+    }
+
+    /// The length of the the underlying array storing the vector components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        4
+    }
+
+    /// The shape of the underlying array storing the vector components.
+    ///
+    /// The shape is the equivalent number of columns and rows of the 
+    /// array as though it represents a matrix. The order of the descriptions 
+    /// of the shape of the array is **(rows, columns)**.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (4, 1)
+    }
+
+    /// Get a pointer to the underlying array.
+    #[inline]
+    pub fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 4]>>::as_ref(self)
+    }
+
+    /// Map an operation on that acts on the coordinates of a vector, returning 
+    /// a vector whose coordinates are of the new scalar type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,  
+    /// # };
+    /// #
+    /// let vector: Vector4<u32> = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected: Vector4<i32> = Vector4::new(2_i32, 3_i32, 4_i32, 5_i32);
+    /// let result: Vector4<i32> = vector.map(|comp| (comp + 1) as i32);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn map<T, F>(self, mut op: F) -> Vector4<T> 
+    where 
+        F: FnMut(S) -> T
+    {
+        Vector4::new(
+            op(self.data[0]),
+            op(self.data[1]),
+            op(self.data[2]),
+            op(self.data[3]),
+        )
+    }
+}
+
+impl<S> Vector4<S> 
+where 
+    S: NumCast + Copy
+{
+    /// Cast a vector from one type of scalars to another type of scalars.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,   
+    /// # };
+    /// #
+    /// let vector: Vector4<u32> = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
+    /// let expected: Option<Vector4<i32>> = Some(Vector4::new(1_i32, 2_i32, 3_i32, 4_i32));
+    /// let result = vector.cast::<i32>();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Vector4<T>> {
+        let x = match num_traits::cast(self.data[0]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let y = match num_traits::cast(self.data[1]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let z = match num_traits::cast(self.data[2]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let w = match num_traits::cast(self.data[3]) {
+            Some(value) => value,
+            None => return None,
+        };
+
+        Some(Vector4::new(x, y, z, w))
+    }
+}
+
+impl<S> Vector4<S> 
+where 
+    S: Scalar
+{
+    /// Returns the **x-axis** unit vector, a unit vector with the **x-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_x() -> Vector4<S> {
+        Vector4::new(S::one(), S::zero(), S::zero(), S::zero())
+    }
+
+    /// Returns the **y-axis** unit vector, a unit vector with the **y-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_y() -> Vector4<S> {
+        Vector4::new(S::zero(), S::one(), S::zero(), S::zero())
+    }
+    
+    /// Returns the **z-axis** unit vector, a unit vector with the **z-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_z() -> Vector4<S> {
+        Vector4::new(S::zero(), S::zero(), S::one(), S::zero())
+    }
+
+    /// Returns the **w-axis** unit vector, a unit vector with the **w-component**
+    /// component as a `1` and the rest of the components are zero.
+    #[inline]
+    pub fn unit_w() -> Vector4<S> {
+        Vector4::new(S::zero(), S::zero(), S::zero(), S::one())
+    }
+
+    /// Compute the zero vector.
+    ///
+    /// The zero vector is the vector in which all of its elements are zero.
+    #[inline]
+    pub fn zero() -> Vector4<S> {
+        Vector4::new(S::zero(), S::zero(), S::zero(), S::zero())
+    }
+    
+    /// Determine whether a vector is the zero vector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero() && 
+        self.data[1].is_zero() && 
+        self.data[2].is_zero() && 
+        self.data[3].is_zero()
+    }
+
+    /// Compute the coordinates of a projective vector in Euclidean space.
+    ///
+    /// The function removes a `0` from the end of the vector, otherwise it
+    /// returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector3,
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 0_i32);
+    /// let expected = Some(Vector3::new(1_i32, 2_i32, 3_i32));
+    /// let result = vector.from_homogeneous();
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 1_i32);
+    /// let expected: Option<Vector3<i32>> = None;
+    /// let result = vector.from_homogeneous();
+    ///
+    /// assert!(result.is_none());
+    /// ```
+    #[inline]
+    pub fn from_homogeneous(&self) -> Option<Vector3<S>> {
+        if self.data[3].is_zero() {
+            Some(self.contract())
+        } else {
+            None
+        }
+    }
+    
+    /// Compute the Euclidean dot product (inner product) of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let vector2 = Vector4::new(5_f64, 6_f64, 7_f64, 8_f64);
+    /// 
+    /// assert_eq!(vector1.dot(&vector2), 70_f64);
+    /// ```
+    #[inline]
+    pub fn dot(self, other: &Vector4<S>) -> S {
+        self.data[0] * other.data[0] + 
+        self.data[1] * other.data[1] + 
+        self.data[2] * other.data[2] + 
+        self.data[3] * other.data[3]
+    }
+
+    /// Compute the component-wise minimum of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_i32, -2_i32, 3_i32, -4_i32);
+    /// let vector2 = Vector4::new(-3_i32, 4_i32, 0_i32, -1_i32);
+    /// let expected = Vector4::new(-3_i32, -2_i32, 0_i32, -4_i32);
+    /// let result = vector1.component_min(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn component_min(&self, other: &Vector4<S>) -> Vector4<S> {
+        let x = if self.data[0] < other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] < other.data[1] { self.data[1] } else { other.data[1] };
+        let z = if self.data[2] < other.data[2] { self.data[2] } else { other.data[2] };
+        let w = if self.data[3] < other.data[3] { self.data[3] } else { other.data[3] };
+
+        Vector4::new(x, y, z, w)
+    }
+
+    /// Compute the component-wise maximum of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_i32, -2_i32, 3_i32, -4_i32);
+    /// let vector2 = Vector4::new(-3_i32, 4_i32, 0_i32, -1_i32);
+    /// let expected = Vector4::new(1_i32, 4_i32, 3_i32, -1_i32);
+    /// let result = vector1.component_max(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn component_max(&self, other: &Vector4<S>) -> Vector4<S> {
+        let x = if self.data[0] > other.data[0] { self.data[0] } else { other.data[0] };
+        let y = if self.data[1] > other.data[1] { self.data[1] } else { other.data[1] };
+        let z = if self.data[2] > other.data[2] { self.data[2] } else { other.data[2] };
+        let w = if self.data[3] > other.data[3] { self.data[3] } else { other.data[3] };
+
+        Vector4::new(x, y, z, w)
+    }
+
+    /// Clamp each component of a vector to the range given by the 
+    /// corresponding components of the vectors `min` and `max`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(-5_i32, 5_i32, 0_i32, 7_i32);
+    /// let min = Vector4::new(-1_i32, 0_i32, 1_i32, -1_i32);
+    /// let max = Vector4::new(1_i32, 1_i32, 1_i32, 1_i32);
+    /// let expected = Vector4::new(-1_i32, 1_i32, 1_i32, 1_i32);
+    /// let result = vector.clamp(&min, &max);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn clamp(&self, min: &Vector4<S>, max: &Vector4<S>) -> Vector4<S> {
+        self.component_max(min).component_min(max)
+    }
+
+    /// Compute the sum of the components of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    ///
+    /// assert_eq!(vector.sum(), 10_i32);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> S {
+        self.data[0] + self.data[1] + self.data[2] + self.data[3]
+    }
+
+    /// Compute the product of the components of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    ///
+    /// assert_eq!(vector.product(), 24_i32);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> S {
+        self.data[0] * self.data[1] * self.data[2] * self.data[3]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is less than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_i32, 5_i32, 3_i32, 2_i32);
+    /// let vector2 = Vector4::new(2_i32, 2_i32, 2_i32, 2_i32);
+    /// let expected = [true, false, false, false];
+    /// let result = vector1.cmp_lt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_lt(&self, other: &Vector4<S>) -> [bool; 4] {
+        [self.data[0] < other.data[0], self.data[1] < other.data[1], self.data[2] < other.data[2], self.data[3] < other.data[3]]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is greater than the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_i32, 5_i32, 3_i32, 2_i32);
+    /// let vector2 = Vector4::new(2_i32, 2_i32, 2_i32, 2_i32);
+    /// let expected = [false, true, true, false];
+    /// let result = vector1.cmp_gt(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_gt(&self, other: &Vector4<S>) -> [bool; 4] {
+        [self.data[0] > other.data[0], self.data[1] > other.data[1], self.data[2] > other.data[2], self.data[3] > other.data[3]]
+    }
+
+    /// Compare two vectors component-wise, returning a mask whose components 
+    /// are `true` where the component of `self` is equal to the corresponding 
+    /// component of `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector1 = Vector4::new(1_i32, 5_i32, 3_i32, 2_i32);
+    /// let vector2 = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = [true, false, true, false];
+    /// let result = vector1.cmp_eq(&vector2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cmp_eq(&self, other: &Vector4<S>) -> [bool; 4] {
+        [self.data[0] == other.data[0], self.data[1] == other.data[1], self.data[2] == other.data[2], self.data[3] == other.data[3]]
+    }
+
+    /// Construct a vector by choosing each component from one of two vectors 
+    /// according to a mask.
+    ///
+    /// Each component of the result is the corresponding component of 
+    /// `if_true` where the mask is `true`, and of `if_false` where the mask is 
+    /// `false`. Together with the component-wise comparison functions, such 
+    /// as `cmp_lt`, this allows branch-free conditional logic on vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let if_true = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let if_false = Vector4::new(5_i32, 6_i32, 7_i32, 8_i32);
+    /// let mask = [true, false, true, false];
+    /// let expected = Vector4::new(1_i32, 6_i32, 3_i32, 8_i32);
+    /// let result = Vector4::select(mask, &if_true, &if_false);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn select(mask: [bool; 4], if_true: &Vector4<S>, if_false: &Vector4<S>) -> Vector4<S> {
+        Vector4::new(
+            if mask[0] { if_true.data[0] } else { if_false.data[0] },
+            if mask[1] { if_true.data[1] } else { if_false.data[1] },
+            if mask[2] { if_true.data[2] } else { if_false.data[2] },
+            if mask[3] { if_true.data[3] } else { if_false.data[3] }
+        )
+    }
+}
+
+impl<S> Vector4<S> 
+where 
+    S: ScalarSigned
+{
+    /// Compute the negation of a vector mutably in place.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let mut result = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = -result;
+    /// result.neg_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn neg_mut(&mut self) {
+        self.data[0] = -self.data[0];
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+    }
+
+    /// Compute the component-wise absolute value of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(-1_i32, 2_i32, -3_i32, 0_i32);
+    /// let expected = Vector4::new(1_i32, 2_i32, 3_i32, 0_i32);
+    /// let result = vector.abs();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn abs(&self) -> Vector4<S> {
+        Vector4::new(self.data[0].abs(), self.data[1].abs(), self.data[2].abs(), self.data[3].abs())
+    }
+}
+
+impl<S> Vector4<S> 
+where 
+    S: ScalarFloat
+{
+    /// Linearly interpolate between the two vectors `self` and `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,  
+    /// # };
+    /// #
+    /// let v0 = Vector4::new(0_f64, 0_f64, 0_f64, 0_f64);
+    /// let v1 = Vector4::new(10_f64, 20_f64, 30_f64, 40_f64);
+    /// let amount = 0.7;
+    /// let expected = Vector4::new(7_f64, 14_f64, 21_f64, 28_f64);
+    /// let result = v0.lerp(&v1, amount);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Vector4<S>, amount: S) -> Vector4<S> {
+        self + ((other - self) * amount)
+    }
+
+    /// Linearly interpolate between the two vectors `self` and `other`, with
+    /// the interpolation parameter clamped to the interval `[0, 1]`.
+    ///
+    /// Unlike `lerp`, the result never extrapolates past either endpoint.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,  
+    /// # };
+    /// #
+    /// let v0 = Vector4::new(0_f64, 0_f64, 0_f64, 0_f64);
+    /// let v1 = Vector4::new(10_f64, 20_f64, 30_f64, 40_f64);
+    ///
+    /// assert_eq!(v0.lerp_clamped(&v1, 2_f64), v1);
+    /// assert_eq!(v0.lerp_clamped(&v1, -1_f64), v0);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: &Vector4<S>, amount: S) -> Vector4<S> {
+        self.lerp(other, amount.max(S::zero()).min(S::one()))
+    }
+
+    /// Returns `true` if the elements of a vector are all finite. 
+    /// Otherwise, it returns `false`. 
+    ///
+    /// A vector is finite when all of its elements are finite. This is useful 
+    /// for vector and matrix types working with fixed precision floating point 
+    /// values.
+    ///
+    /// ## Example (Finite Vector)
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let v = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    ///
+    /// assert!(v.is_finite()); 
+    /// ```
+    ///
+    /// ## Example (Not A Finite Vector)
+    /// 
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let w = Vector4::new(1_f64, f64::NAN, f64::NEG_INFINITY, 4_f64);
+    ///
+    /// assert!(!w.is_finite()); 
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data[0].is_finite() && 
+        self.data[1].is_finite() && 
+        self.data[2].is_finite() && 
+        self.data[3].is_finite()
+    }
+
+    /// Returns `true` if any element of a vector is `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_f64, f64::NAN, 3_f64, 4_f64);
+    ///
+    /// assert!(vector.is_nan());
+    ///
+    /// let vector = Vector4::new(1_f64, f64::INFINITY, 3_f64, 4_f64);
+    ///
+    /// assert!(!vector.is_nan());
+    /// assert!(!vector.is_finite());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.data[0].is_nan() || 
+        self.data[1].is_nan() || 
+        self.data[2].is_nan() || 
+        self.data[3].is_nan()
+    }
+
+    /// Compute the projection of the vector `self` onto the vector
+    /// `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// #     Magnitude,
+    /// # };
+    /// # 
+    /// let vector = Vector4::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64, 2_f64, 1_f64);
+    /// let unit_x = Vector4::unit_x();
+    /// let unit_y = Vector4::unit_y();
+    /// let unit_z = Vector4::unit_z();
+    /// let unit_w = Vector4::unit_w();
+    /// let projected_x = vector.project(&unit_x);
+    /// let projected_y = vector.project(&unit_y);
+    /// let projected_z = vector.project(&unit_z);
+    /// let projected_w = vector.project(&unit_w);
+    ///
+    /// assert_eq!(projected_x, vector.x * unit_x);
+    /// assert_eq!(projected_y, vector.y * unit_y);
+    /// assert_eq!(projected_z, vector.z * unit_z);
+    /// assert_eq!(projected_w, vector.w * unit_w);
+    /// ```
+    #[inline]
+    pub fn project(&self, other: &Vector4<S>) -> Vector4<S> {
+        other * (self.dot(other) / other.magnitude_squared())
+    }
+
+    /// Compute the projection of the vector `self` onto the vector `other`.
+    ///
+    /// This is the same as `project`, under the name that pairs with 
+    /// `reject_from`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// # 
+    /// let vector = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let other = Vector4::new(3_f64, 4_f64, 5_f64, 6_f64);
+    ///
+    /// assert_eq!(vector.project_onto(&other), vector.project(&other));
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: &Vector4<S>) -> Vector4<S> {
+        self.project(other)
+    }
+
+    /// Compute the rejection of the vector `self` from the vector `other`.
+    ///
+    /// The rejection of `self` from `other` is the component of `self` 
+    /// perpendicular to `other`. Together with the projection, it decomposes 
+    /// `self` into components parallel and perpendicular to `other`
+    /// ```text
+    /// self == self.project_onto(other) + self.reject_from(other)
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # 
+    /// let vector = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let other = Vector4::new(5_f64, 6_f64, 7_f64, 8_f64);
+    /// let projected = vector.project_onto(&other);
+    /// let rejected = vector.reject_from(&other);
+    ///
+    /// assert!(relative_eq!(rejected.dot(&other), 0_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(projected + rejected, vector, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn reject_from(&self, other: &Vector4<S>) -> Vector4<S> {
+        self - self.project_onto(other)
+    }
+
+    /// Round each component of a vector down to the nearest integer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1.5_f64, -1.5_f64, 2.4_f64, -2.6_f64);
+    /// let expected = Vector4::new(1_f64, -2_f64, 2_f64, -3_f64);
+    /// let result = vector.floor();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].floor(),
+            self.data[1].floor(),
+            self.data[2].floor(),
+            self.data[3].floor(),
+        )
+    }
+
+    /// Round each component of a vector up to the nearest integer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1.5_f64, -1.5_f64, 2.4_f64, -2.6_f64);
+    /// let expected = Vector4::new(2_f64, -1_f64, 3_f64, -2_f64);
+    /// let result = vector.ceil();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn ceil(&self) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].ceil(),
+            self.data[1].ceil(),
+            self.data[2].ceil(),
+            self.data[3].ceil(),
+        )
+    }
+
+    /// Round each component of a vector to the nearest integer, rounding half-way 
+    /// cases away from zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1.5_f64, -1.5_f64, 2.4_f64, -2.6_f64);
+    /// let expected = Vector4::new(2_f64, -2_f64, 2_f64, -3_f64);
+    /// let result = vector.round();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].round(),
+            self.data[1].round(),
+            self.data[2].round(),
+            self.data[3].round(),
+        )
+    }
+
+    /// Compute the reciprocal of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(2_f64, 4_f64, 8_f64, 16_f64);
+    /// let expected = Vector4::new(0.5_f64, 0.25_f64, 0.125_f64, 0.0625_f64);
+    /// let result = vector.recip();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].recip(),
+            self.data[1].recip(),
+            self.data[2].recip(),
+            self.data[3].recip(),
+        )
+    }
+
+    /// Compute the square root of each component of a vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(4_f64, 9_f64, 16_f64, 25_f64);
+    /// let expected = Vector4::new(2_f64, 3_f64, 4_f64, 5_f64);
+    /// let result = vector.sqrt();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].sqrt(),
+            self.data[1].sqrt(),
+            self.data[2].sqrt(),
+            self.data[3].sqrt(),
+        )
+    }
+
+    /// Raise each component of a vector to the floating point power `exponent`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let expected = Vector4::new(1_f64, 8_f64, 27_f64, 64_f64);
+    /// let result = vector.powf(3_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        self.data[0].is_zero() && 
-        self.data[1].is_zero() && 
-        self.data[2].is_zero() && 
-        self.data[3].is_zero()
+    pub fn powf(&self, exponent: S) -> Vector4<S> {
+        Vector4::new(
+            self.data[0].powf(exponent),
+            self.data[1].powf(exponent),
+            self.data[2].powf(exponent),
+            self.data[3].powf(exponent),
+        )
     }
 
-    /// Compute the coordinates of a projective vector in Euclidean space.
+    /// Compute the sign of each component of a vector.
     ///
-    /// The function removes a `0` from the end of the vector, otherwise it
-    /// returns `None`.
+    /// Each component of the result is `-1` if the corresponding component is 
+    /// negative, `1` if it is positive, and `0` if it is zero. This differs 
+    /// from the scalar `signum` function, which maps zero to `1`, so that 
+    /// zero components do not acquire a spurious direction. A `NaN` component 
+    /// stays `NaN`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector3,
     /// #     Vector4, 
     /// # };
     /// #
-    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 0_i32);
-    /// let expected = Some(Vector3::new(1_i32, 2_i32, 3_i32));
-    /// let result = vector.from_homogeneous();
+    /// let vector = Vector4::new(-2_f64, 0_f64, 5_f64, -0.5_f64);
+    /// let expected = Vector4::new(-1_f64, 0_f64, 1_f64, -1_f64);
+    /// let result = vector.signum();
     ///
     /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn signum(&self) -> Vector4<S> {
+        let signum = |x: S| if x.is_zero() { S::zero() } else { x.signum() };
+
+        Vector4::new(
+            signum(self.data[0]),
+            signum(self.data[1]),
+            signum(self.data[2]),
+            signum(self.data[3]),
+        )
+    }
+
+    /// Compute a vector with the magnitude of each component of `self` and the 
+    /// sign of the corresponding component of `sign`.
     ///
-    /// let vector = Vector4::new(1_i32, 2_i32, 3_i32, 1_i32);
-    /// let expected: Option<Vector3<i32>> = None;
-    /// let result = vector.from_homogeneous();
+    /// ## Example
     ///
-    /// assert!(result.is_none());
+    /// ```
+    /// # use cglinalg::{
+    /// #     Vector4, 
+    /// # };
+    /// #
+    /// let vector = Vector4::new(2_f64, -3_f64, 4_f64, 5_f64);
+    /// let sign = Vector4::new(-1_f64, 1_f64, -0_f64, 1_f64);
+    /// let expected = Vector4::new(-2_f64, 3_f64, -4_f64, 5_f64);
+    /// let result = vector.copysign(&sign);
+    ///
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn from_homogeneous(&self) -> Option<Vector3<S>> {
-        if self.data[3].is_zero() {
-            Some(self.contract())
-        } else {
-            None
-        }
+    pub fn copysign(&self, sign: &Vector4<S>) -> Vector4<S> {
+        let copysign = |x: S, sign: S| if sign.is_sign_negative() { -x.abs() } else { x.abs() };
+
+        Vector4::new(
+            copysign(self.data[0], sign.data[0]),
+            copysign(self.data[1], sign.data[1]),
+            copysign(self.data[2], sign.data[2]),
+            copysign(self.data[3], sign.data[3]),
+        )
     }
-    
-    /// Compute the Euclidean dot product (inner product) of two vectors.
+
+    /// Compute the GLSL step function of each component of a vector.
+    ///
+    /// Each component of the result is `0` if the corresponding component of
+    /// `x` is less than the corresponding component of `edge`, and `1` 
+    /// otherwise.
     ///
     /// ## Example
     ///
@@ -1723,25 +4610,37 @@ where
     /// #     Vector4, 
     /// # };
     /// #
-    /// let vector1 = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
-    /// let vector2 = Vector4::new(5_f64, 6_f64, 7_f64, 8_f64);
-    /// 
-    /// assert_eq!(vector1.dot(&vector2), 70_f64);
+    /// let edge = Vector4::new(1_f64, 2_f64, 3_f64, 0_f64);
+    /// let x = Vector4::new(0.5_f64, 2_f64, 4_f64, -1_f64);
+    /// let expected = Vector4::new(0_f64, 1_f64, 1_f64, 0_f64);
+    /// let result = Vector4::step(&edge, &x);
+    ///
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn dot(self, other: &Vector4<S>) -> S {
-        self.data[0] * other.data[0] + 
-        self.data[1] * other.data[1] + 
-        self.data[2] * other.data[2] + 
-        self.data[3] * other.data[3]
+    pub fn step(edge: &Vector4<S>, x: &Vector4<S>) -> Vector4<S> {
+        let step = |edge_i: S, x_i: S| {
+            if x_i < edge_i { S::zero() } else { S::one() }
+        };
+
+        Vector4::new(
+            step(edge.data[0], x.data[0]),
+            step(edge.data[1], x.data[1]),
+            step(edge.data[2], x.data[2]),
+            step(edge.data[3], x.data[3]),
+        )
     }
-}
 
-impl<S> Vector4<S> 
-where 
-    S: ScalarSigned
-{
-    /// Compute the negation of a vector mutably in place.
+    /// Compute the GLSL smoothstep function of each component of a vector.
+    ///
+    /// Each component of the result performs a smooth Hermite interpolation 
+    /// between `0` and `1` when the corresponding component of `x` lies between 
+    /// the corresponding components of `edge0` and `edge1`. That is, for each 
+    /// component
+    /// ```text
+    /// t := clamp((x - edge0) / (edge1 - edge0), 0, 1)
+    /// smoothstep(edge0, edge1, x) := t * t * (3 - 2 * t)
+    /// ```
     ///
     /// ## Example
     ///
@@ -1750,114 +4649,126 @@ where
     /// #     Vector4, 
     /// # };
     /// #
-    /// let mut result = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
-    /// let expected = -result;
-    /// result.neg_mut();
+    /// let edge0 = Vector4::new(0_f64, 0_f64, 0_f64, 0_f64);
+    /// let edge1 = Vector4::new(1_f64, 1_f64, 1_f64, 1_f64);
+    /// let x = Vector4::new(-1_f64, 0.5_f64, 2_f64, 0.25_f64);
+    /// let expected = Vector4::new(0_f64, 0.5_f64, 1_f64, 0.15625_f64);
+    /// let result = Vector4::smoothstep(&edge0, &edge1, &x);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn neg_mut(&mut self) {
-        self.data[0] = -self.data[0];
-        self.data[1] = -self.data[1];
-        self.data[2] = -self.data[2];
-        self.data[3] = -self.data[3];
+    pub fn smoothstep(edge0: &Vector4<S>, edge1: &Vector4<S>, x: &Vector4<S>) -> Vector4<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let three = two + one;
+        let smoothstep = |edge0_i: S, edge1_i: S, x_i: S| {
+            let t = ((x_i - edge0_i) / (edge1_i - edge0_i)).max(zero).min(one);
+            t * t * (three - two * t)
+        };
+
+        Vector4::new(
+            smoothstep(edge0.data[0], edge1.data[0], x.data[0]),
+            smoothstep(edge0.data[1], edge1.data[1], x.data[1]),
+            smoothstep(edge0.data[2], edge1.data[2], x.data[2]),
+            smoothstep(edge0.data[3], edge1.data[3], x.data[3]),
+        )
     }
-}
 
-impl<S> Vector4<S> 
-where 
-    S: ScalarFloat
-{
-    /// Linearly interpolate between the two vectors `self` and `other`.
+    /// Linearly blend between the two vectors `self` and `other` using the 
+    /// GLSL mix function.
+    ///
+    /// This is the same operation as `lerp`.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,  
+    /// #     Vector4, 
     /// # };
     /// #
     /// let v0 = Vector4::new(0_f64, 0_f64, 0_f64, 0_f64);
     /// let v1 = Vector4::new(10_f64, 20_f64, 30_f64, 40_f64);
-    /// let amount = 0.7;
-    /// let expected = Vector4::new(7_f64, 14_f64, 21_f64, 28_f64);
-    /// let result = v0.lerp(&v1, amount);
+    /// let expected = Vector4::new(5_f64, 10_f64, 15_f64, 20_f64);
+    /// let result = v0.mix(&v1, 0.5_f64);
     ///
     /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn lerp(&self, other: &Vector4<S>, amount: S) -> Vector4<S> {
-        self + ((other - self) * amount)
+    pub fn mix(&self, other: &Vector4<S>, amount: S) -> Vector4<S> {
+        self.lerp(other, amount)
     }
 
-    /// Returns `true` if the elements of a vector are all finite. 
-    /// Otherwise, it returns `false`. 
+    /// Compute the **L1** norm of a vector.
     ///
-    /// A vector is finite when all of its elements are finite. This is useful 
-    /// for vector and matrix types working with fixed precision floating point 
-    /// values.
+    /// The **L1** norm of a vector is the sum of the absolute values of its 
+    /// components. It is also known as the taxicab or Manhattan norm.
     ///
-    /// ## Example (Finite Vector)
+    /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,
+    /// #     Vector4, 
     /// # };
     /// #
-    /// let v = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let vector = Vector4::new(-3_f64, 4_f64, -5_f64, 1_f64);
     ///
-    /// assert!(v.is_finite()); 
+    /// assert_eq!(vector.l1_norm(), 13_f64);
     /// ```
+    #[inline]
+    pub fn l1_norm(&self) -> S {
+        self.data[0].abs() + self.data[1].abs() + self.data[2].abs() + self.data[3].abs()
+    }
+
+    /// Compute the **L-infinity** norm of a vector.
+    ///
+    /// The **L-infinity** norm of a vector is the largest absolute value of 
+    /// its components. It is also known as the maximum or Chebyshev norm.
+    ///
+    /// ## Example
     ///
-    /// ## Example (Not A Finite Vector)
-    /// 
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4,
+    /// #     Vector4, 
     /// # };
     /// #
-    /// let w = Vector4::new(1_f64, f64::NAN, f64::NEG_INFINITY, 4_f64);
+    /// let vector = Vector4::new(-3_f64, 4_f64, -5_f64, 1_f64);
     ///
-    /// assert!(!w.is_finite()); 
+    /// assert_eq!(vector.linf_norm(), 5_f64);
     /// ```
     #[inline]
-    pub fn is_finite(&self) -> bool {
-        self.data[0].is_finite() && 
-        self.data[1].is_finite() && 
-        self.data[2].is_finite() && 
-        self.data[3].is_finite()
+    pub fn linf_norm(&self) -> S {
+        self.data[0].abs().max(self.data[1].abs()).max(self.data[2].abs()).max(self.data[3].abs())
     }
 
-    /// Compute the projection of the vector `self` onto the vector
-    /// `other`.
+    /// Divide the first three components of a vector by its last component.
+    ///
+    /// This is the perspective divide taking a point in clip space to
+    /// normalized device coordinates. The function does not check the last
+    /// component: when `w == 0` the vector represents a point at infinity,
+    /// and the result has infinite components, or `NaN` components where the
+    /// corresponding component is also zero.
     ///
     /// ## Example
     ///
     /// ```
     /// # use cglinalg::{
-    /// #     Vector4, 
-    /// #     Magnitude,
+    /// #     Vector3,
+    /// #     Vector4,
     /// # };
-    /// # 
-    /// let vector = Vector4::new(1_f64 / 2_f64, f64::sqrt(3_f64) / 2_f64, 2_f64, 1_f64);
-    /// let unit_x = Vector4::unit_x();
-    /// let unit_y = Vector4::unit_y();
-    /// let unit_z = Vector4::unit_z();
-    /// let unit_w = Vector4::unit_w();
-    /// let projected_x = vector.project(&unit_x);
-    /// let projected_y = vector.project(&unit_y);
-    /// let projected_z = vector.project(&unit_z);
-    /// let projected_w = vector.project(&unit_w);
+    /// #
+    /// let vector = Vector4::new(2_f64, 4_f64, 6_f64, 2_f64);
+    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let result = vector.perspective_divide();
     ///
-    /// assert_eq!(projected_x, vector.x * unit_x);
-    /// assert_eq!(projected_y, vector.y * unit_y);
-    /// assert_eq!(projected_z, vector.z * unit_z);
-    /// assert_eq!(projected_w, vector.w * unit_w);
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
-    pub fn project(&self, other: &Vector4<S>) -> Vector4<S> {
-        other * (self.dot(other) / other.magnitude_squared())
+    pub fn perspective_divide(&self) -> Vector3<S> {
+        let w = self.data[3];
+
+        Vector3::new(self.data[0] / w, self.data[1] / w, self.data[2] / w)
     }
 }
 
@@ -1926,6 +4837,16 @@ where
     }
 }
 
+impl<S> From<Vector4<S>> for [S; 4] 
+where 
+    S: Scalar
+{
+    #[inline]
+    fn from(v: Vector4<S>) -> [S; 4] {
+        v.data
+    }
+}
+
 impl<'a, S> From<&'a (S, S, S, S)> for &'a Vector4<S> 
 where 
     S: Scalar 
@@ -2310,6 +5231,141 @@ impl_as_ref_ops!(Vector4<S>, [S; 4]);
 impl_as_ref_ops!(Vector4<S>, [[S; 4]; 1]);
 
 
+macro_rules! impl_vector_iter_ops {
+    ($VectorN:ident, $n:expr) => {
+        impl<S> IntoIterator for $VectorN<S> {
+            type Item = S;
+            type IntoIter = core::array::IntoIter<S, $n>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIterator::into_iter(self.data)
+            }
+        }
+
+        /// Construct a vector from an iterator over its components.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if the iterator does not yield exactly as many
+        /// items as the vector has components.
+        impl<S> iter::FromIterator<S> for $VectorN<S> 
+        where 
+            S: Scalar
+        {
+            fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> $VectorN<S> {
+                let mut iter = iter.into_iter();
+                let mut data = [S::zero(); $n];
+                for component in data.iter_mut() {
+                    *component = iter.next()
+                        .expect("Iterator yielded too few components for the vector");
+                }
+                assert!(
+                    iter.next().is_none(), 
+                    "Iterator yielded too many components for the vector"
+                );
+
+                $VectorN { data: data }
+            }
+        }
+    }
+}
+
+impl_vector_iter_ops!(Vector1, 1);
+impl_vector_iter_ops!(Vector2, 2);
+impl_vector_iter_ops!(Vector3, 3);
+impl_vector_iter_ops!(Vector4, 4);
+
+
+macro_rules! impl_vector_default {
+    ($VectorN:ident) => {
+        /// The default vector is the zero vector.
+        impl<S> Default for $VectorN<S> where S: Scalar {
+            #[inline]
+            fn default() -> Self {
+                $VectorN::zero()
+            }
+        }
+    }
+}
+
+impl_vector_default!(Vector1);
+impl_vector_default!(Vector2);
+impl_vector_default!(Vector3);
+impl_vector_default!(Vector4);
+
+
+macro_rules! impl_vector_sum_product {
+    ($VectorN:ident) => {
+        /// Sum an iterator of vectors component-wise. The sum of an empty 
+        /// iterator is the zero vector.
+        impl<S> iter::Sum<$VectorN<S>> for $VectorN<S> where S: Scalar {
+            #[inline]
+            fn sum<I: Iterator<Item = $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), |acc, vector| acc + vector)
+            }
+        }
+
+        impl<'a, S> iter::Sum<&'a $VectorN<S>> for $VectorN<S> where S: Scalar {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), |acc, vector| acc + vector)
+            }
+        }
+
+        /// Multiply an iterator of vectors component-wise. The product of an 
+        /// empty iterator is the vector whose components are all one.
+        impl<S> iter::Product<$VectorN<S>> for $VectorN<S> where S: Scalar {
+            #[inline]
+            fn product<I: Iterator<Item = $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::from_fill(S::one()), |mut acc, vector| {
+                    for (acc_i, vector_i) in acc.data.iter_mut().zip(vector.data.iter()) {
+                        *acc_i *= *vector_i;
+                    }
+
+                    acc
+                })
+            }
+        }
+
+        impl<'a, S> iter::Product<&'a $VectorN<S>> for $VectorN<S> where S: Scalar {
+            #[inline]
+            fn product<I: Iterator<Item = &'a $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.copied().product()
+            }
+        }
+    }
+}
+
+impl_vector_sum_product!(Vector1);
+impl_vector_sum_product!(Vector2);
+impl_vector_sum_product!(Vector3);
+impl_vector_sum_product!(Vector4);
+
+
+macro_rules! impl_vector_try_from_slice {
+    ($VectorN:ident, $n:expr) => {
+        /// Construct a vector from a slice of its components.
+        ///
+        /// The conversion fails if the length of the slice is not the number 
+        /// of components of the vector.
+        impl<S> TryFrom<&[S]> for $VectorN<S> where S: Scalar {
+            type Error = TryFromSliceError;
+
+            #[inline]
+            fn try_from(slice: &[S]) -> Result<Self, Self::Error> {
+                <[S; $n]>::try_from(slice).map($VectorN::from)
+            }
+        }
+    }
+}
+
+impl_vector_try_from_slice!(Vector1, 1);
+impl_vector_try_from_slice!(Vector2, 2);
+impl_vector_try_from_slice!(Vector3, 3);
+impl_vector_try_from_slice!(Vector4, 4);
+
+
 macro_rules! impl_magnitude {
     ($VectorN:ident) => {
         impl<S> Magnitude for $VectorN<S> where S: ScalarFloat {
@@ -2365,6 +5421,32 @@ impl_magnitude!(Vector3);
 impl_magnitude!(Vector4);
 
 
+macro_rules! impl_vector_space {
+    ($VectorN:ident) => {
+        impl<S> VectorSpace for $VectorN<S> where S: Scalar {
+            type Element = S;
+
+            #[inline]
+            fn zero() -> Self {
+                $VectorN::zero()
+            }
+        }
+
+        impl<S> InnerProductSpace for $VectorN<S> where S: ScalarFloat {
+            #[inline]
+            fn dot(&self, other: &Self) -> Self::Element {
+                $VectorN::dot(*self, other)
+            }
+        }
+    }
+}
+
+impl_vector_space!(Vector1);
+impl_vector_space!(Vector2);
+impl_vector_space!(Vector3);
+impl_vector_space!(Vector4);
+
+
 macro_rules! impl_approx_eq_ops {
     ($T:ident, { $($index:expr),* }) => {
         impl<S> approx::AbsDiffEq for $T<S> where S: ScalarFloat {
@@ -2823,3 +5905,66 @@ impl_swizzle!(wwwy() => Vector4 => Vector4 { 3, 3, 3, 1 });
 impl_swizzle!(wwwz() => Vector4 => Vector4 { 3, 3, 3, 2 });
 impl_swizzle!(wwww() => Vector4 => Vector4 { 3, 3, 3, 3 });
 
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Vector2<S>> for Vector2<S> {
+    #[inline]
+    fn from(other: mint::Vector2<S>) -> Vector2<S> {
+        Vector2 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Vector2<S>> for mint::Vector2<S> {
+    #[inline]
+    fn from(other: Vector2<S>) -> mint::Vector2<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Vector2<S> {
+    type MintType = mint::Vector2<S>;
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Vector3<S>> for Vector3<S> {
+    #[inline]
+    fn from(other: mint::Vector3<S>) -> Vector3<S> {
+        Vector3 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Vector3<S>> for mint::Vector3<S> {
+    #[inline]
+    fn from(other: Vector3<S>) -> mint::Vector3<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Vector3<S> {
+    type MintType = mint::Vector3<S>;
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Vector4<S>> for Vector4<S> {
+    #[inline]
+    fn from(other: mint::Vector4<S>) -> Vector4<S> {
+        Vector4 { data: other.into() }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<Vector4<S>> for mint::Vector4<S> {
+    #[inline]
+    fn from(other: Vector4<S>) -> mint::Vector4<S> {
+        other.data.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Vector4<S> {
+    type MintType = mint::Vector4<S>;
+}
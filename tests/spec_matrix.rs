@@ -891,6 +891,19 @@ macro_rules! exact_transposition_props {
 
                 prop_assert_eq!((m1 * m2).transpose(), m2.transpose() * m1.transpose());
             }
+
+            /// The Gram matrix of a matrix is symmetric.
+            ///
+            /// Given a matrix `m`
+            /// ```text
+            /// transpose(gram(m)) = gram(m)
+            /// ```
+            #[test]
+            fn prop_gram_symmetric(m in $Generator::<$ScalarType>()) {
+                let gram = m.gram();
+
+                prop_assert_eq!(gram.transpose(), gram);
+            }
         }
     }
     }
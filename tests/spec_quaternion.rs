@@ -58,6 +58,13 @@ macro_rules! index_props {
 
 
         proptest! {
+            // The out of bounds test panics on purpose, and proptest would 
+            // otherwise save every one of those panics as a regression seed.
+            #![proptest_config(ProptestConfig {
+                failure_persistence: None,
+                .. ProptestConfig::default()
+            })]
+
             /// When a quaternion is treated like an array, it should accept all indices
             /// below the length of the array.
             ///
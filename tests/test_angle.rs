@@ -70,6 +70,26 @@ mod conversion_tests {
             assert_eq!(result, expected);
         })
     }
+
+    /// Converting an angle in degrees to a raw value in radians should yield 
+    /// the same value as converting it to typed radians.
+    #[test]
+    fn convert_degrees_to_radians_value() {
+        let pi = f64::consts::PI;
+        let result = Degrees(180_f64).to_radians_value();
+
+        assert!((result - pi).abs() <= f64::EPSILON);
+    }
+
+    /// Converting a typed angle in radians to a raw value and back should
+    /// yield the original angle.
+    #[test]
+    fn convert_radians_value_round_trip() {
+        let angle = Radians(1.25_f64);
+        let result = Radians::from_radians_value(angle.to_radians_value());
+
+        assert_eq!(result, angle);
+    }
 }
 
 #[cfg(test)]
@@ -528,6 +548,70 @@ mod radian_angle_tests {
 
         assert!(relative_eq!(result, expected, epsilon = 1e-10));
     }
+
+    #[test]
+    fn test_normalize_three_half_turns() {
+        let angle = Radians(3_f64 * f64::consts::PI);
+        let expected = Radians(f64::consts::PI);
+        let result = angle.normalize();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_normalize_negative_angle() {
+        let angle = Radians(-f64::consts::PI / 2_f64);
+        let expected = Radians(3_f64 * f64::consts::PI / 2_f64);
+        let result = angle.normalize();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_normalize_signed_three_quarter_turn() {
+        let angle = Radians(3_f64 * f64::consts::PI / 2_f64);
+        let expected = Radians(-f64::consts::PI / 2_f64);
+        let result = angle.normalize_signed();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_across_seam() {
+        let angle1 = Radians(-3_f64 * f64::consts::PI / 4_f64);
+        let angle2 = Radians(3_f64 * f64::consts::PI / 4_f64);
+        let expected = Radians(f64::consts::PI);
+        let result = angle1.lerp(angle2, 0.5_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sin_cos_full_turn_div_4() {
+        let angle: Radians<f64> = Radians::full_turn_div_4();
+        let (sin_angle, cos_angle) = angle.sin_cos();
+
+        assert!(relative_eq!(sin_angle, angle.sin(), epsilon = 1e-10));
+        assert!(relative_eq!(cos_angle, angle.cos(), epsilon = 1e-10));
+        assert!(relative_eq!(sin_angle, 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(cos_angle, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2_positive_y_axis() {
+        let expected = Radians(f64::consts::FRAC_PI_2);
+        let result = Radians::atan2(1_f64, 0_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2_third_quadrant() {
+        let expected = Radians(-3_f64 * f64::consts::FRAC_PI_4);
+        let result = Radians::atan2(-1_f64, -1_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
 }
 
 #[cfg(test)]
@@ -705,4 +789,186 @@ mod degree_angle_tests {
 
         assert!(relative_eq!(result, expected, epsilon = 1e-10));
     }
+
+    #[test]
+    fn test_normalize_three_half_turns() {
+        let angle = Degrees(540_f64);
+        let expected = Degrees(180_f64);
+        let result = angle.normalize();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_normalize_signed_three_quarter_turn() {
+        let angle = Degrees(270_f64);
+        let expected = Degrees(-90_f64);
+        let result = angle.normalize_signed();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_across_seam() {
+        let angle1 = Degrees(350_f64);
+        let angle2 = Degrees(10_f64);
+        let expected = Degrees(0_f64);
+        let result = angle1.lerp(angle2, 0.5_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let angle1 = Degrees(30_f64);
+        let angle2 = Degrees(120_f64);
+
+        assert!(relative_eq!(angle1.lerp(angle2, 0_f64), angle1, epsilon = 1e-10));
+        assert!(relative_eq!(angle1.lerp(angle2, 1_f64), angle2, epsilon = 1e-10));
+        assert!(relative_eq!(angle1.lerp(angle2, 0.5_f64), Degrees(75_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sin_cos_full_turn_div_4() {
+        let angle: Degrees<f64> = Degrees::full_turn_div_4();
+        let (sin_angle, cos_angle) = angle.sin_cos();
+
+        assert!(relative_eq!(sin_angle, angle.sin(), epsilon = 1e-10));
+        assert!(relative_eq!(cos_angle, angle.cos(), epsilon = 1e-10));
+        assert!(relative_eq!(sin_angle, 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(cos_angle, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2_positive_y_axis() {
+        let expected = Degrees(90_f64);
+        let result = Degrees::atan2(1_f64, 0_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2_third_quadrant() {
+        let expected = Degrees(-135_f64);
+        let result = Degrees::atan2(-1_f64, -1_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_clamp_above_max() {
+        let pitch = Degrees(100_f64);
+        let expected = Degrees(89_f64);
+        let result = pitch.clamp(Degrees(-89_f64), Degrees(89_f64));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp_below_min() {
+        let pitch = Degrees(-100_f64);
+        let expected = Degrees(-89_f64);
+        let result = pitch.clamp(Degrees(-89_f64), Degrees(89_f64));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp_within_bounds() {
+        let pitch = Degrees(30_f64);
+        let result = pitch.clamp(Degrees(-89_f64), Degrees(89_f64));
+
+        assert_eq!(result, pitch);
+    }
 }
+
+#[cfg(test)]
+mod turn_angle_tests {
+    use cglinalg::{
+        Radians,
+        Degrees,
+        Turns,
+        Angle,
+    };
+    use approx::relative_eq;
+    use core::f64;
+
+
+    #[test]
+    fn test_full_turn() {
+        let expected = Turns(1_f64);
+        let result = Turns::full_turn();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_convert_turns_to_radians() {
+        let expected = Radians(f64::consts::FRAC_PI_2);
+        let result = Radians::from(Turns(0.25_f64));
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_convert_turns_to_radians_round_trip() {
+        let angle = Turns(0.25_f64);
+        let result = Turns::from(Radians::from(angle));
+
+        assert!(relative_eq!(result, angle, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_convert_turns_to_degrees() {
+        let expected = Degrees(90_f64);
+        let result: Degrees<f64> = Turns(0.25_f64).into();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_convert_degrees_to_turns() {
+        let expected = Turns(0.75_f64);
+        let result = Turns::from(Degrees(270_f64));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        let angle = Turns(0.125_f64);
+        let (sin_angle, cos_angle) = angle.sin_cos();
+
+        assert!(relative_eq!(sin_angle, f64::consts::FRAC_1_SQRT_2, epsilon = 1e-10));
+        assert!(relative_eq!(cos_angle, f64::consts::FRAC_1_SQRT_2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2() {
+        let expected = Turns(0.25_f64);
+        let result = Turns::atan2(1_f64, 0_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let expected = Turns(0.25_f64);
+        let result = Turns(2.25_f64).normalize();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let angle1 = Turns(0.5_f64);
+        let angle2 = Turns(0.25_f64);
+
+        assert_eq!(angle1 + angle2, Turns(0.75_f64));
+        assert_eq!(angle1 - angle2, Turns(0.25_f64));
+        assert_eq!(angle1 * 2_f64, Turns(1_f64));
+        assert_eq!(angle1 / angle2, 2_f64);
+        assert_eq!(-angle1, Turns(-0.5_f64));
+    }
+}
+
@@ -0,0 +1,126 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod aabb3_tests {
+    use cglinalg::{
+        Aabb3,
+        Matrix4x4,
+        Point3,
+        Vector3,
+    };
+
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Point3::new(1_f64, 2_f64, 3_f64),
+            Point3::new(-1_f64, 4_f64, 0_f64),
+            Point3::new(0_f64, -3_f64, 5_f64),
+            Point3::new(2_f64, 1_f64, -2_f64),
+        ];
+        let expected = Aabb3::new(
+            Point3::new(-1_f64, -3_f64, -2_f64),
+            Point3::new(2_f64, 4_f64, 5_f64),
+        );
+        let result = Aabb3::from_points(&points).unwrap();
+
+        assert_eq!(result, expected);
+        for point in points.iter() {
+            assert!(result.contains(point));
+        }
+    }
+
+    #[test]
+    fn test_from_points_single_point() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let expected = Aabb3::new(point, point);
+        let result = Aabb3::from_points(&[point]).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_points_empty() {
+        let points: [Point3<f64>; 0] = [];
+
+        assert!(Aabb3::from_points(&points).is_none());
+    }
+
+    #[test]
+    fn test_contains() {
+        let aabb = Aabb3::new(
+            Point3::new(-1_f64, -1_f64, -1_f64),
+            Point3::new(1_f64, 1_f64, 1_f64),
+        );
+
+        assert!(aabb.contains(&Point3::new(0_f64, 0_f64, 0_f64)));
+        assert!(aabb.contains(&Point3::new(1_f64, -1_f64, 0_f64)));
+        assert!(!aabb.contains(&Point3::new(0_f64, 0_f64, 1.5_f64)));
+        assert!(!aabb.contains(&Point3::new(-2_f64, 0_f64, 0_f64)));
+    }
+
+    #[test]
+    fn test_center_extents() {
+        let aabb = Aabb3::new(
+            Point3::new(-2_f64, 0_f64, 1_f64),
+            Point3::new(4_f64, 2_f64, 5_f64),
+        );
+
+        assert_eq!(aabb.center(), Point3::new(1_f64, 1_f64, 3_f64));
+        assert_eq!(aabb.extents(), Vector3::new(3_f64, 1_f64, 2_f64));
+        assert_eq!(aabb.center() - aabb.extents(), aabb.min);
+        assert_eq!(aabb.center() + aabb.extents(), aabb.max);
+    }
+
+    #[test]
+    fn test_merge() {
+        let aabb1 = Aabb3::new(
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 1_f64, 1_f64),
+        );
+        let aabb2 = Aabb3::new(
+            Point3::new(2_f64, -1_f64, 0.5_f64),
+            Point3::new(3_f64, 0.5_f64, 4_f64),
+        );
+        let expected = Aabb3::new(
+            Point3::new(0_f64, -1_f64, 0_f64),
+            Point3::new(3_f64, 1_f64, 4_f64),
+        );
+
+        assert_eq!(aabb1.merge(&aabb2), expected);
+        assert_eq!(aabb2.merge(&aabb1), expected);
+    }
+
+    #[test]
+    fn test_transform_translation() {
+        let aabb = Aabb3::new(
+            Point3::new(-1_f64, -2_f64, -3_f64),
+            Point3::new(1_f64, 2_f64, 3_f64),
+        );
+        let distance = Vector3::new(5_f64, -5_f64, 10_f64);
+        let matrix = Matrix4x4::from_affine_translation(&distance);
+        let expected = Aabb3::new(aabb.min + distance, aabb.max + distance);
+        let result = aabb.transform(&matrix);
+
+        assert_eq!(result, expected);
+        assert_eq!(result.extents(), aabb.extents());
+    }
+
+    #[test]
+    fn test_transform_scale_reflection() {
+        let aabb = Aabb3::new(
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 2_f64, 3_f64),
+        );
+        let matrix = Matrix4x4::from_affine_nonuniform_scale(-2_f64, 1_f64, 1_f64);
+        let expected = Aabb3::new(
+            Point3::new(-2_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 2_f64, 3_f64),
+        );
+        let result = aabb.transform(&matrix);
+
+        assert_eq!(result, expected);
+    }
+}
+
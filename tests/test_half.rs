@@ -0,0 +1,63 @@
+#![cfg(feature = "half")]
+extern crate cglinalg;
+
+
+use cglinalg::{
+    Matrix4x4,
+    Vector3,
+};
+use half::f16;
+
+
+#[test]
+fn test_vector3_f16_addition() {
+    let vector1 = Vector3::new(f16::from_f32(1_f32), f16::from_f32(2_f32), f16::from_f32(3_f32));
+    let vector2 = Vector3::new(f16::from_f32(0.5_f32), f16::from_f32(-1_f32), f16::from_f32(4_f32));
+    let expected = Vector3::new(f16::from_f32(1.5_f32), f16::from_f32(1_f32), f16::from_f32(7_f32));
+    let result = vector1 + vector2;
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_vector3_f16_dot() {
+    let vector1 = Vector3::new(f16::from_f32(1_f32), f16::from_f32(2_f32), f16::from_f32(3_f32));
+    let vector2 = Vector3::new(f16::from_f32(4_f32), f16::from_f32(-5_f32), f16::from_f32(6_f32));
+    let expected = f16::from_f32(12_f32);
+    let result = vector1.dot(&vector2);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_vector3_f16_cast_round_trip() {
+    let vector = Vector3::new(1.5_f32, -2.25_f32, 1024_f32);
+    let vector_f16: Vector3<f16> = vector.cast().unwrap();
+    let result: Vector3<f32> = vector_f16.cast().unwrap();
+
+    assert_eq!(vector_f16.x, f16::from_f32(1.5_f32));
+    assert_eq!(result, vector);
+}
+
+#[test]
+fn test_vector3_f16_cast_rounds() {
+    // Half precision floating point numbers have an eleven bit significand, so 
+    // 2049 is not representable and rounds to an even neighbour.
+    let vector = Vector3::new(2049_f32, 0.1_f32, 0_f32);
+    let result: Vector3<f16> = vector.cast().unwrap();
+
+    assert_eq!(result.x.to_f32(), 2048_f32);
+    assert_ne!(result.y.to_f32(), 0.1_f32);
+}
+
+#[test]
+fn test_matrix4x4_f16_multiplication() {
+    let one = f16::from_f32(1_f32);
+    let two = f16::from_f32(2_f32);
+    let identity: Matrix4x4<f16> = Matrix4x4::identity();
+    let matrix = Matrix4x4::from_fill(two);
+    let expected = Matrix4x4::from_fill(two);
+
+    assert_eq!(identity * matrix, expected);
+    assert_eq!(matrix * one, matrix);
+}
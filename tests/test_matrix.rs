@@ -8,6 +8,7 @@ mod matrix2x2_tests {
     use cglinalg::{
         Vector2,
         Matrix2x2,
+        Matrix4x4,
         Radians,
         Angle,
         Unit,
@@ -602,6 +603,135 @@ mod matrix2x2_tests {
 
         assert!(relative_eq!(result, expected, epsilon = 1e-7));
     }
+
+    #[test]
+    fn test_kronecker_identity_identity() {
+        let identity: Matrix2x2<f64> = Matrix2x2::identity();
+        let expected = Matrix4x4::identity();
+        let result = identity.kronecker(&identity);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let matrix = Matrix2x2::new(
+            1_i32, 3_i32,
+            2_i32, 4_i32
+        );
+        let other = Matrix2x2::new(
+            0_i32, 6_i32,
+            5_i32, 7_i32
+        );
+        let expected = Matrix4x4::new(
+            0_i32,  6_i32,  0_i32,  18_i32,
+            5_i32,  7_i32,  15_i32, 21_i32,
+            0_i32,  12_i32, 0_i32,  24_i32,
+            10_i32, 14_i32, 20_i32, 28_i32
+        );
+        let result = matrix.kronecker(&other);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_mul() {
+        let matrix = Matrix2x2::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32
+        );
+        let other = Matrix2x2::new(
+            5_i32, 6_i32,
+            7_i32, 8_i32
+        );
+        let expected = Matrix2x2::new(
+            5_i32,  12_i32,
+            21_i32, 32_i32
+        );
+        let result = matrix.component_mul(&other);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_div_component_mul() {
+        let matrix = Matrix2x2::new(
+            1.5_f64, -2.25_f64,
+            3.1_f64, 4.7_f64
+        );
+        let other = Matrix2x2::new(
+            0.3_f64, 7_f64,
+            -1.9_f64, 11.3_f64
+        );
+        let result = matrix.component_mul(&other).component_div(&other);
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_component_div_by_zero_float() {
+        let matrix = Matrix2x2::new(
+            1_f64, -1_f64,
+            0_f64, 2_f64
+        );
+        let other = Matrix2x2::new(
+            0_f64, 0_f64,
+            0_f64, 1_f64
+        );
+        let result = matrix.component_div(&other);
+
+        assert_eq!(result[0][0], f64::INFINITY);
+        assert_eq!(result[0][1], f64::NEG_INFINITY);
+        assert!(result[1][0].is_nan());
+        assert_eq!(result[1][1], 2_f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_component_div_by_zero_integer() {
+        let matrix = Matrix2x2::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32
+        );
+        let other = Matrix2x2::new(
+            1_i32, 0_i32,
+            1_i32, 1_i32
+        );
+        let _ = matrix.component_div(&other);
+    }
+
+    #[test]
+    fn test_to_angle_round_trip() {
+        for i in -15..=15 {
+            let angle = Radians(0.2_f64 * (i as f64));
+            let matrix = Matrix2x2::from_angle(angle);
+            let result = matrix.to_angle();
+
+            assert!(relative_eq!(result, angle, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_to_angle_normalizes() {
+        let angle = Radians(7_f64);
+        let matrix = Matrix2x2::from_angle(angle);
+        let expected = Radians(7_f64 - 2_f64 * core::f64::consts::PI);
+        let result = matrix.to_angle();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_column_array() {
+        let columns = [
+            Vector2::new(1_f64, 2_f64),
+            Vector2::new(3_f64, 4_f64),
+        ];
+        let expected = Matrix2x2::from_columns(columns[0], columns[1]);
+        let result = Matrix2x2::from_column_array(columns);
+
+        assert_eq!(result, expected);
+    }
 }
 
 
@@ -611,6 +741,7 @@ mod matrix3x3_tests {
         Vector2,
         Vector3,
         Magnitude,
+        Matrix2x2,
         Matrix3x3,
         Angle,
         Radians,
@@ -1579,208 +1710,771 @@ mod matrix3x3_tests {
 
         assert!(relative_eq!(result, expected, epsilon = 1e-7));
     }
-}
-
-#[cfg(test)]
-mod matrix4x4_tests {
-    use cglinalg::{
-        Vector3,
-        Vector4,
-        Magnitude,
-        Matrix4x4,
-        Radians,
-        Degrees,
-        Angle,
-        Unit,
-        Point3,
-    };
-    use approx::{
-        relative_eq,
-    };
-    use core::slice::Iter;
 
+    #[test]
+    fn test_rotation_between_anti_parallel_vectors() {
+        let unit_x: Vector3<f64> = Vector3::unit_x();
+        let matrix = Matrix3x3::rotation_between(&unit_x, &(-unit_x)).unwrap();
+        let expected = -unit_x;
+        let result = matrix * unit_x;
 
-    struct TestCase {
-        a_mat: Matrix4x4<f64>,
-        b_mat: Matrix4x4<f64>,
-        expected: Matrix4x4<f64>,
+        assert!(relative_eq!(result, expected, epsilon = 1e-6));
     }
 
-    struct Test {
-        tests: Vec<TestCase>,
-    }
+    #[test]
+    fn test_rotation_between_anti_parallel_vectors_is_rotation() {
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+        let matrix = Matrix3x3::rotation_between(&vector, &(-vector * 2_f64)).unwrap();
+        let result = matrix * vector;
 
-    impl Test {
-        fn iter(&self) -> TestIter {
-            TestIter {
-                inner: self.tests.iter()
-            }
-        }
+        assert!(relative_eq!(result, -vector, epsilon = 1e-6));
+        assert!(relative_eq!(matrix * matrix.transpose(), Matrix3x3::identity(), epsilon = 1e-6));
+        assert!(relative_eq!(matrix.determinant(), 1_f64, epsilon = 1e-6));
     }
 
-    struct TestIter<'a> {
-        inner: Iter<'a, TestCase>,
+    #[test]
+    fn test_rotation_between_axis_anti_parallel_unit_vectors() {
+        let unit_z: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_z());
+        let minus_unit_z: Unit<Vector3<f64>> = Unit::from_value(-Vector3::unit_z());
+        let matrix = Matrix3x3::rotation_between_axis(&unit_z, &minus_unit_z).unwrap();
+        let result = matrix * unit_z.into_inner();
+
+        assert!(relative_eq!(result, -Vector3::unit_z(), epsilon = 1e-6));
     }
 
-    impl<'a> Iterator for TestIter<'a> {
-        type Item = &'a TestCase;
+    #[test]
+    fn test_look_at_rh_direction_parallel_to_up() {
+        let direction: Vector3<f64> = Vector3::unit_y();
+        let up: Vector3<f64> = Vector3::unit_y();
+        let look_at = Matrix3x3::look_at_rh(&direction, &up);
 
-        fn next(&mut self) -> Option<Self::Item> {
-            self.inner.next()
-        }
+        assert!(look_at.is_finite());
+        assert!(relative_eq!(look_at * look_at.transpose(), Matrix3x3::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(look_at * direction, -Vector3::unit_z(), epsilon = 1e-10));
     }
 
-    fn test_cases() -> Test {
-        Test {
-            tests: vec![
-                TestCase {
-                    a_mat: Matrix4x4::new(
-                        80.0,  23.43,  43.56, 6.74, 
-                        426.1, 23.57,  27.61, 13.90,
-                        4.22,  258.08, 31.70, 42.17, 
-                        70.0,  49.0,   95.0,  89.91
-                    ),
-                    b_mat: Matrix4x4::new(
-                        36.84, 427.46, 882.19, 89.50, 
-                        7.04,  61.89,  56.31,  89.0, 
-                        72.0,  936.5,  413.80, 50.31,  
-                        37.69, 311.8,  60.81,  73.83
-                    ),
-                    expected: Matrix4x4::new(
-                        195075.7478, 242999.4886, 49874.8440, 51438.8929,
-                        33402.1572,  20517.1793,  12255.4723, 11284.3033,
-                        410070.5860, 133018.9590, 46889.9950, 35475.9481,
-                        141297.8982, 27543.7175,  19192.1014, 13790.4636
-                    ),
-                },
-                TestCase {
-                    a_mat: Matrix4x4::identity(),
-                    b_mat: Matrix4x4::identity(),
-                    expected: Matrix4x4::identity(),
-                },
-                TestCase {
-                    a_mat: Matrix4x4::zero(),
-                    b_mat: Matrix4x4::zero(),
-                    expected: Matrix4x4::zero(),
-                },
-                TestCase {
-                    a_mat: Matrix4x4::new(
-                        68.32, 0.0,    0.0,   0.0,
-                        0.0,   37.397, 0.0,   0.0,
-                        0.0,   0.0,    9.483, 0.0,
-                        0.0,   0.0,    0.0,   887.710
-                    ),
-                    b_mat: Matrix4x4::new(
-                        57.72, 0.0,    0.0,     0.0, 
-                        0.0,   9.5433, 0.0,     0.0, 
-                        0.0,   0.0,    86.7312, 0.0,
-                        0.0,   0.0,    0.0,     269.1134
-                    ),
-                    expected: Matrix4x4::new(
-                        3943.4304, 0.0,         0.0,         0.0,
-                        0.0,       356.8907901, 0.0,         0.0,
-                        0.0,       0.0,         822.4719696, 0.0,
-                        0.0,       0.0,         0.0,         238894.656314
-                    ),
-                },
-            ]
-        }
-    }
+    #[test]
+    fn test_look_at_lh_direction_antiparallel_to_up() {
+        let direction: Vector3<f64> = -Vector3::unit_z();
+        let up: Vector3<f64> = Vector3::unit_z();
+        let look_at = Matrix3x3::look_at_lh(&direction, &up);
 
+        assert!(look_at.is_finite());
+        assert!(relative_eq!(look_at * look_at.transpose(), Matrix3x3::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(look_at * direction, Vector3::unit_z(), epsilon = 1e-10));
+    }
 
     #[test]
-    fn test_matrix_components1() {
-        let matrix = Matrix4x4::new(
-            1_i32,  2_i32,  3_i32,  4_i32,
-            5_i32,  6_i32,  7_i32,  8_i32,
-            9_i32,  10_i32, 11_i32, 12_i32,
-            13_i32, 14_i32, 15_i32, 16_i32
-        );
+    fn test_nearly_singular_matrix_is_not_invertible_eps() {
+        let matrix = Matrix3x3::from_diagonal(&Vector3::new(1e-10_f64, 1e-10_f64, 1_f64));
 
-        assert_eq!(matrix[0][0], 1_i32);
-        assert_eq!(matrix[0][1], 2_i32);
-        assert_eq!(matrix[0][2], 3_i32);
-        assert_eq!(matrix[0][3], 4_i32);
-        assert_eq!(matrix[1][0], 5_i32);
-        assert_eq!(matrix[1][1], 6_i32);
-        assert_eq!(matrix[1][2], 7_i32);
-        assert_eq!(matrix[1][3], 8_i32);
-        assert_eq!(matrix[2][0], 9_i32);
-        assert_eq!(matrix[2][1], 10_i32);
-        assert_eq!(matrix[2][2], 11_i32);
-        assert_eq!(matrix[2][3], 12_i32);
-        assert_eq!(matrix[3][0], 13_i32);
-        assert_eq!(matrix[3][1], 14_i32);
-        assert_eq!(matrix[3][2], 15_i32);
-        assert_eq!(matrix[3][3], 16_i32);
+        assert!(relative_eq!(matrix.determinant(), 1e-20_f64, epsilon = 1e-30));
+        assert!(matrix.inverse().is_some());
+        assert!(!matrix.is_invertible_eps(1e-12_f64));
+        assert!(matrix.inverse_eps(1e-12_f64).is_none());
     }
 
     #[test]
-    fn test_matrix_components2() {
-        let matrix = Matrix4x4::new(
-            1_i32,  2_i32,  3_i32,  4_i32,
-            5_i32,  6_i32,  7_i32,  8_i32,
-            9_i32,  10_i32, 11_i32, 12_i32,
-            13_i32, 14_i32, 15_i32, 16_i32
+    fn test_well_conditioned_matrix_is_invertible_eps() {
+        let matrix = Matrix3x3::new(
+            2_f64, 0_f64, 0_f64,
+            0_f64, 3_f64, 0_f64,
+            0_f64, 0_f64, 4_f64
         );
 
-        assert_eq!(matrix.c0r0, matrix[0][0]);
-        assert_eq!(matrix.c0r1, matrix[0][1]);
-        assert_eq!(matrix.c0r2, matrix[0][2]);
-        assert_eq!(matrix.c0r3, matrix[0][3]);
-        assert_eq!(matrix.c1r0, matrix[1][0]);
-        assert_eq!(matrix.c1r1, matrix[1][1]);
-        assert_eq!(matrix.c1r2, matrix[1][2]);
-        assert_eq!(matrix.c1r3, matrix[1][3]);
-        assert_eq!(matrix.c2r0, matrix[2][0]);
-        assert_eq!(matrix.c2r1, matrix[2][1]);
-        assert_eq!(matrix.c2r2, matrix[2][2]);
-        assert_eq!(matrix.c2r3, matrix[2][3]);
-        assert_eq!(matrix.c3r0, matrix[3][0]);
-        assert_eq!(matrix.c3r1, matrix[3][1]);
-        assert_eq!(matrix.c3r2, matrix[3][2]);
-        assert_eq!(matrix.c3r3, matrix[3][3]);
+        assert!(matrix.is_invertible_eps(1e-12_f64));
+        assert_eq!(matrix.inverse_eps(1e-12_f64), matrix.inverse());
     }
 
     #[test]
-    #[should_panic]
-    fn test_matrix_components_out_of_bounds1() {
-        let matrix = Matrix4x4::new(
-            1_i32,  2_i32,  3_i32,  4_i32,
-            5_i32,  6_i32,  7_i32,  8_i32,
-            9_i32,  10_i32, 11_i32, 12_i32,
-            13_i32, 14_i32, 15_i32, 16_i32
+    fn test_component_div_component_mul() {
+        let matrix = Matrix3x3::new(
+            1.5_f64, -2.25_f64, 8.0_f64,
+            3.1_f64, 4.7_f64, -0.6_f64,
+            9.2_f64, 0.01_f64, 5.5_f64
+        );
+        let other = Matrix3x3::new(
+            0.3_f64, 7_f64, -2_f64,
+            -1.9_f64, 11.3_f64, 0.8_f64,
+            4_f64, 6.5_f64, -3.3_f64
         );
+        let result = matrix.component_mul(&other).component_div(&other);
 
-        assert_eq!(matrix[4][0], matrix[4][0]);
+        assert!(relative_eq!(result, matrix, epsilon = 1e-10));
     }
 
     #[test]
-    #[should_panic]
-    fn test_matrix_components_out_of_bounds2() {
-        let matrix = Matrix4x4::new(
-            1_i32,  2_i32,  3_i32,  4_i32,
-            5_i32,  6_i32,  7_i32,  8_i32,
-            9_i32,  10_i32, 11_i32, 12_i32,
-            13_i32, 14_i32, 15_i32, 16_i32
+    fn test_tuple_index_row_column_order() {
+        let matrix = Matrix3x3::new(
+            1_i32, 2_i32, 3_i32,
+            4_i32, 5_i32, 6_i32,
+            7_i32, 8_i32, 9_i32
         );
 
-        assert_eq!(matrix[0][4], matrix[0][4]);
+        assert_eq!(matrix[(0, 1)], matrix[1][0]);
+        assert_eq!(matrix[(0, 1)], 4_i32);
+        assert_eq!(matrix[(1, 0)], 2_i32);
+        assert_eq!(matrix[(2, 1)], 6_i32);
     }
 
     #[test]
-    fn test_mat_times_identity_equals_mat() {
-        test_cases().iter().for_each(|test| {
-            let a_mat_times_identity = test.a_mat * Matrix4x4::identity();
-            let b_mat_times_identity = test.b_mat * Matrix4x4::identity();
+    fn test_tuple_index_mut_row_column_order() {
+        let mut result = Matrix3x3::new(
+            1_i32, 2_i32, 3_i32,
+            4_i32, 5_i32, 6_i32,
+            7_i32, 8_i32, 9_i32
+        );
+        result[(0, 2)] = 10_i32;
+        let expected = Matrix3x3::new(
+            1_i32,  2_i32, 3_i32,
+            4_i32,  5_i32, 6_i32,
+            10_i32, 8_i32, 9_i32
+        );
 
-            assert_eq!(a_mat_times_identity, test.a_mat);
-            assert_eq!(b_mat_times_identity, test.b_mat);
-        })
+        assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_mat_times_zero_equals_zero() {
+    fn test_display_grid() {
+        let matrix = Matrix3x3::new(
+            1.5_f64, 20_f64, -3_f64,
+            4_f64, 5.25_f64, 6_f64,
+            7_f64, 8_f64, 9.125_f64
+        );
+        let expected = "\
+            Matrix3x3 [\n    \
+                [ 1.5, 4,    7    ],\n    \
+                [20,   5.25, 8    ],\n    \
+                [-3,   6,    9.125]\n\
+            ]";
+        let result = format!("{}", matrix);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display_grid_rows() {
+        let matrix = Matrix3x3::new(
+            11_i32, 2_i32, 3_i32,
+            4_i32, 5_i32, 6_i32,
+            7_i32, 8_i32, 9_i32
+        );
+        let result = format!("{}", matrix);
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines.len(), 3 + 2);
+        assert!(lines[1].trim_start().starts_with("[11,"));
+    }
+
+    #[test]
+    fn test_display_grid_precision() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::identity();
+        let result = format!("{:.2}", matrix);
+
+        assert!(result.contains("[1.00, 0.00, 0.00]"));
+        assert!(result.contains("[0.00, 0.00, 1.00]"));
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let axis1 = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let axis2 = Unit::from_value(Vector3::new(-2_f64, 1_f64, 0.5_f64));
+        let matrix1 = Matrix3x3::from_axis_angle(&axis1, Radians(0.7_f64));
+        let matrix2 = Matrix3x3::from_axis_angle(&axis2, Radians(2.9_f64));
+
+        assert_eq!(matrix1.slerp(&matrix2, 0_f64), matrix1);
+        assert!(relative_eq!(matrix1.slerp(&matrix2, 1_f64), matrix2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_intermediate_orthonormal() {
+        let axis1 = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let axis2 = Unit::from_value(Vector3::new(-2_f64, 1_f64, 0.5_f64));
+        let matrix1 = Matrix3x3::from_axis_angle(&axis1, Radians(0.7_f64));
+        let matrix2 = Matrix3x3::from_axis_angle(&axis2, Radians(2.9_f64));
+        let identity = Matrix3x3::identity();
+        for i in 1..10 {
+            let amount = (i as f64) / 10_f64;
+            let result = matrix1.slerp(&matrix2, amount);
+
+            assert!(relative_eq!(result * result.transpose(), identity, epsilon = 1e-10));
+            assert!(relative_eq!(result.determinant(), 1_f64, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_slerp_constant_angular_speed() {
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, 1_f64));
+        let matrix1 = Matrix3x3::identity();
+        let matrix2 = Matrix3x3::from_axis_angle(&axis, Radians(3_f64));
+        let expected = Matrix3x3::from_axis_angle(&axis, Radians(1_f64));
+        let result = matrix1.slerp(&matrix2, 1_f64 / 3_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_half_turn() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let matrix1 = Matrix3x3::from_axis_angle(&axis, Radians(0.25_f64));
+        let difference = Matrix3x3::from_axis_angle(&axis, Radians::full_turn_div_2());
+        let matrix2 = matrix1 * difference;
+
+        assert!(relative_eq!(matrix1.slerp(&matrix2, 1_f64), matrix2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_to_axis_angle_round_trip() {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 3_f64));
+        let angles = [
+            1e-6_f64, 0.01_f64, 0.5_f64, 1_f64, core::f64::consts::FRAC_PI_2, 2_f64, 3_f64,
+            core::f64::consts::PI - 0.01_f64, core::f64::consts::PI - 1e-6_f64,
+        ];
+        for &angle in angles.iter() {
+            let matrix = Matrix3x3::from_axis_angle(&axis, Radians(angle));
+            let (result_axis, result_angle) = matrix.to_axis_angle();
+
+            assert!(relative_eq!(result_axis.into_inner(), axis.into_inner(), epsilon = 1e-6));
+            assert!(relative_eq!(result_angle, Radians(angle), epsilon = 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_to_axis_angle_half_turn() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -2_f64));
+        let angle = Radians::full_turn_div_2();
+        let matrix = Matrix3x3::from_axis_angle(&axis, angle);
+        let (result_axis, result_angle) = matrix.to_axis_angle();
+        let result_matrix = Matrix3x3::from_axis_angle(&result_axis, result_angle);
+
+        assert!(relative_eq!(result_angle, angle, epsilon = 1e-6));
+        // At a half turn the axis is only determined up to sign.
+        assert!(relative_eq!(result_axis.dot(&axis).abs(), 1_f64, epsilon = 1e-6));
+        assert!(relative_eq!(result_matrix, matrix, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_to_axis_angle_identity() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::identity();
+        let (_, result_angle) = matrix.to_axis_angle();
+
+        assert_eq!(result_angle, Radians(0_f64));
+    }
+
+
+    #[test]
+    fn test_to_cols_array_from_cols_slice_round_trip() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, 9_f64,
+        );
+        let array = matrix.to_cols_array();
+        let result = Matrix3x3::from_cols_slice(&array);
+
+        assert_eq!(result, matrix);
+    }
+
+    #[test]
+    fn test_to_cols_array_column_major_order() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, 9_f64,
+        );
+        let expected = [
+            matrix.c0r0, matrix.c0r1, matrix.c0r2,
+            matrix.c1r0, matrix.c1r1, matrix.c1r2,
+            matrix.c2r0, matrix.c2r1, matrix.c2r2,
+        ];
+        let result = matrix.to_cols_array();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_cols_slice_too_short() {
+        let data = [1_f64; 8];
+        Matrix3x3::from_cols_slice(&data);
+    }
+
+
+    #[test]
+    fn test_characteristic_polynomial_roots() {
+        // A symmetric matrix with the eigenvalues 1, 2, and 4.
+        let matrix = Matrix3x3::new(
+            2_f64, 0_f64, 0_f64,
+            0_f64, 2.5_f64, 1.5_f64,
+            0_f64, 1.5_f64, 2.5_f64,
+        );
+        let [c0, c1, c2, c3] = matrix.characteristic_polynomial();
+        for &eigenvalue in [1_f64, 2_f64, 4_f64].iter() {
+            let result = c0 + c1 * eigenvalue + c2 * eigenvalue * eigenvalue + c3 * eigenvalue * eigenvalue * eigenvalue;
+
+            assert!(relative_eq!(result, 0_f64, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_characteristic_polynomial_matches_determinant() {
+        let matrix = Matrix3x3::new(
+            1_f64, 4_f64, -2_f64,
+            3_f64, 0_f64, 5_f64,
+            -1_f64, 2_f64, 7_f64,
+        );
+        let [c0, c1, c2, c3] = matrix.characteristic_polynomial();
+        for &lambda in [-3_f64, 0.5_f64, 2_f64, 10_f64].iter() {
+            let expected = (matrix - Matrix3x3::identity() * lambda).determinant();
+            let result = c0 + c1 * lambda + c2 * lambda * lambda + c3 * lambda * lambda * lambda;
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_characteristic_polynomial_identity() {
+        let matrix: Matrix3x3<i32> = Matrix3x3::identity();
+        // (1 - lambda)^3 == 1 - 3 * lambda + 3 * lambda^2 - lambda^3.
+        let expected = [1_i32, -3_i32, 3_i32, -1_i32];
+
+        assert_eq!(matrix.characteristic_polynomial(), expected);
+    }
+
+
+    #[test]
+    fn test_orthonormalize_independent_columns() {
+        let matrix = Matrix3x3::new(
+            1_f64, 1_f64, 0_f64,
+            1_f64, 0_f64, 1_f64,
+            0_f64, 1_f64, 1_f64,
+        );
+        let result = matrix.orthonormalize().unwrap();
+
+        assert!(relative_eq!(result.transpose() * result, Matrix3x3::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(
+            result.column(0), matrix.column(0) / f64::sqrt(2_f64), epsilon = 1e-10
+        ));
+        assert!(result.determinant() * matrix.determinant() > 0_f64);
+    }
+
+    #[test]
+    fn test_orthonormalize_preserves_span() {
+        let matrix = Matrix3x3::new(
+            3_f64, 1_f64, 2_f64,
+            -1_f64, 4_f64, 1_f64,
+            2_f64, 0_f64, 5_f64,
+        );
+        let result = matrix.orthonormalize().unwrap();
+        // The matrix relating the columns to the orthonormal basis is upper 
+        // triangular with a positive diagonal.
+        let coefficients = result.transpose() * matrix;
+
+        assert!(relative_eq!(coefficients.c0r1, 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(coefficients.c0r2, 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(coefficients.c1r2, 0_f64, epsilon = 1e-10));
+        assert!(coefficients.c0r0 > 0_f64);
+        assert!(coefficients.c1r1 > 0_f64);
+        assert!(coefficients.c2r2 > 0_f64);
+    }
+
+    #[test]
+    fn test_orthonormalize_duplicated_column() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            1_f64, 2_f64, 3_f64,
+        );
+
+        assert!(matrix.orthonormalize().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_scaled_parallel_columns() {
+        let matrix = Matrix3x3::new(
+            1_f64, 0_f64, 0_f64,
+            1_f64, 2_f64, 3_f64,
+            3_f64, 6_f64, 9_f64,
+        );
+
+        assert!(matrix.orthonormalize().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_duplicated_non_trivial_column() {
+        let matrix = Matrix3x3::new(
+            3.7_f64, -1.3_f64, 9.1_f64,
+            3.7_f64, -1.3_f64, 9.1_f64,
+            0_f64,    1_f64,   0_f64,
+        );
+
+        assert!(matrix.orthonormalize().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_duplicated_large_column() {
+        let matrix = Matrix3x3::new(
+            0_f64,     0_f64,   1_f64,
+            123.4_f64, 5.6_f64, 7.8_f64,
+            123.4_f64, 5.6_f64, 7.8_f64,
+        );
+
+        assert!(matrix.orthonormalize().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_small_independent_columns() {
+        let matrix = Matrix3x3::new(
+            1e-9_f64, 1e-9_f64, 0_f64,
+            1e-9_f64, 0_f64,    1e-9_f64,
+            0_f64,    1e-9_f64, 1e-9_f64,
+        );
+        let result = matrix.orthonormalize().unwrap();
+
+        assert!(relative_eq!(result.transpose() * result, Matrix3x3::identity(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_orthonormalize_zero_column() {
+        let matrix = Matrix3x3::new(
+            0_f64, 0_f64, 0_f64,
+            4_f64, 5_f64, 6_f64,
+            1_f64, 2_f64, 4_f64,
+        );
+
+        assert!(matrix.orthonormalize().is_none());
+    }
+
+    #[test]
+    fn test_cast_f64_to_f32() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, 9_f64,
+        );
+        let expected: Option<Matrix3x3<f32>> = Some(Matrix3x3::new(
+            1_f32, 2_f32, 3_f32,
+            4_f32, 5_f32, 6_f32,
+            7_f32, 8_f32, 9_f32,
+        ));
+        let result = matrix.cast::<f32>();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cast_out_of_range_fails() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 256_f64, 6_f64,
+            7_f64, 8_f64, 9_f64,
+        );
+        let result = matrix.cast::<u8>();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_cast_negative_to_unsigned_fails() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, -9_f64,
+        );
+        let result = matrix.cast::<u8>();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_from_fill() {
+        let matrix = Matrix3x3::from_fill(7_i32);
+
+        for c in 0..3 {
+            for r in 0..3 {
+                assert_eq!(matrix[c][r], 7_i32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_column_array() {
+        let columns = [
+            Vector3::new(1_f64, 2_f64, 3_f64),
+            Vector3::new(4_f64, 5_f64, 6_f64),
+            Vector3::new(7_f64, 8_f64, 9_f64),
+        ];
+        let expected = Matrix3x3::from_columns(columns[0], columns[1], columns[2]);
+        let result = Matrix3x3::from_column_array(columns);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_condition_number_identity() {
+        let identity: Matrix3x3<f64> = Matrix3x3::identity();
+
+        assert!(relative_eq!(identity.condition_number(), 3_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_condition_number_rotation() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let matrix = Matrix3x3::from_axis_angle(&axis, Radians(1_f64)) * 5_f64;
+
+        assert!(relative_eq!(matrix.condition_number(), 3_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_condition_number_near_singular() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, 9_f64 + 1e-9_f64,
+        );
+
+        assert!(matrix.condition_number() > 1e9_f64);
+    }
+
+    #[test]
+    fn test_condition_number_singular() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::zero();
+
+        assert!(matrix.condition_number().is_infinite());
+    }
+
+    #[test]
+    fn test_from_affine() {
+        let linear = Matrix2x2::from_angle(Radians::full_turn_div_4()) * 2_f64;
+        let translation = Vector2::new(3_f64, -4_f64);
+        let matrix = Matrix3x3::from_affine(&linear, &translation);
+        let point = Vector3::new(1_f64, 0_f64, 1_f64);
+        let expected = Vector3::new(3_f64, -2_f64, 1_f64);
+        let result = matrix * point;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_affine_matches_composition() {
+        let linear = Matrix2x2::new(
+            1_f64, 2_f64,
+            3_f64, 4_f64,
+        );
+        let translation = Vector2::new(5_f64, 6_f64);
+        let expected = Matrix3x3::from_affine_translation(&translation) * Matrix3x3::new(
+            1_f64, 2_f64, 0_f64,
+            3_f64, 4_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+        let result = Matrix3x3::from_affine(&linear, &translation);
+
+        assert_eq!(result, expected);
+    }
+}
+
+#[cfg(test)]
+mod matrix4x4_tests {
+    use cglinalg::{
+        Vector3,
+        Vector4,
+        Magnitude,
+        Matrix3x3,
+        Matrix4x4,
+        Radians,
+        Degrees,
+        Angle,
+        Unit,
+        Point3,
+    };
+    use approx::{
+        relative_eq,
+    };
+    use core::convert::TryFrom;
+    use core::slice::Iter;
+
+
+    struct TestCase {
+        a_mat: Matrix4x4<f64>,
+        b_mat: Matrix4x4<f64>,
+        expected: Matrix4x4<f64>,
+    }
+
+    struct Test {
+        tests: Vec<TestCase>,
+    }
+
+    impl Test {
+        fn iter(&self) -> TestIter {
+            TestIter {
+                inner: self.tests.iter()
+            }
+        }
+    }
+
+    struct TestIter<'a> {
+        inner: Iter<'a, TestCase>,
+    }
+
+    impl<'a> Iterator for TestIter<'a> {
+        type Item = &'a TestCase;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next()
+        }
+    }
+
+    fn test_cases() -> Test {
+        Test {
+            tests: vec![
+                TestCase {
+                    a_mat: Matrix4x4::new(
+                        80.0,  23.43,  43.56, 6.74, 
+                        426.1, 23.57,  27.61, 13.90,
+                        4.22,  258.08, 31.70, 42.17, 
+                        70.0,  49.0,   95.0,  89.91
+                    ),
+                    b_mat: Matrix4x4::new(
+                        36.84, 427.46, 882.19, 89.50, 
+                        7.04,  61.89,  56.31,  89.0, 
+                        72.0,  936.5,  413.80, 50.31,  
+                        37.69, 311.8,  60.81,  73.83
+                    ),
+                    expected: Matrix4x4::new(
+                        195075.7478, 242999.4886, 49874.8440, 51438.8929,
+                        33402.1572,  20517.1793,  12255.4723, 11284.3033,
+                        410070.5860, 133018.9590, 46889.9950, 35475.9481,
+                        141297.8982, 27543.7175,  19192.1014, 13790.4636
+                    ),
+                },
+                TestCase {
+                    a_mat: Matrix4x4::identity(),
+                    b_mat: Matrix4x4::identity(),
+                    expected: Matrix4x4::identity(),
+                },
+                TestCase {
+                    a_mat: Matrix4x4::zero(),
+                    b_mat: Matrix4x4::zero(),
+                    expected: Matrix4x4::zero(),
+                },
+                TestCase {
+                    a_mat: Matrix4x4::new(
+                        68.32, 0.0,    0.0,   0.0,
+                        0.0,   37.397, 0.0,   0.0,
+                        0.0,   0.0,    9.483, 0.0,
+                        0.0,   0.0,    0.0,   887.710
+                    ),
+                    b_mat: Matrix4x4::new(
+                        57.72, 0.0,    0.0,     0.0, 
+                        0.0,   9.5433, 0.0,     0.0, 
+                        0.0,   0.0,    86.7312, 0.0,
+                        0.0,   0.0,    0.0,     269.1134
+                    ),
+                    expected: Matrix4x4::new(
+                        3943.4304, 0.0,         0.0,         0.0,
+                        0.0,       356.8907901, 0.0,         0.0,
+                        0.0,       0.0,         822.4719696, 0.0,
+                        0.0,       0.0,         0.0,         238894.656314
+                    ),
+                },
+            ]
+        }
+    }
+
+
+    #[test]
+    fn test_matrix_components1() {
+        let matrix = Matrix4x4::new(
+            1_i32,  2_i32,  3_i32,  4_i32,
+            5_i32,  6_i32,  7_i32,  8_i32,
+            9_i32,  10_i32, 11_i32, 12_i32,
+            13_i32, 14_i32, 15_i32, 16_i32
+        );
+
+        assert_eq!(matrix[0][0], 1_i32);
+        assert_eq!(matrix[0][1], 2_i32);
+        assert_eq!(matrix[0][2], 3_i32);
+        assert_eq!(matrix[0][3], 4_i32);
+        assert_eq!(matrix[1][0], 5_i32);
+        assert_eq!(matrix[1][1], 6_i32);
+        assert_eq!(matrix[1][2], 7_i32);
+        assert_eq!(matrix[1][3], 8_i32);
+        assert_eq!(matrix[2][0], 9_i32);
+        assert_eq!(matrix[2][1], 10_i32);
+        assert_eq!(matrix[2][2], 11_i32);
+        assert_eq!(matrix[2][3], 12_i32);
+        assert_eq!(matrix[3][0], 13_i32);
+        assert_eq!(matrix[3][1], 14_i32);
+        assert_eq!(matrix[3][2], 15_i32);
+        assert_eq!(matrix[3][3], 16_i32);
+    }
+
+    #[test]
+    fn test_matrix_components2() {
+        let matrix = Matrix4x4::new(
+            1_i32,  2_i32,  3_i32,  4_i32,
+            5_i32,  6_i32,  7_i32,  8_i32,
+            9_i32,  10_i32, 11_i32, 12_i32,
+            13_i32, 14_i32, 15_i32, 16_i32
+        );
+
+        assert_eq!(matrix.c0r0, matrix[0][0]);
+        assert_eq!(matrix.c0r1, matrix[0][1]);
+        assert_eq!(matrix.c0r2, matrix[0][2]);
+        assert_eq!(matrix.c0r3, matrix[0][3]);
+        assert_eq!(matrix.c1r0, matrix[1][0]);
+        assert_eq!(matrix.c1r1, matrix[1][1]);
+        assert_eq!(matrix.c1r2, matrix[1][2]);
+        assert_eq!(matrix.c1r3, matrix[1][3]);
+        assert_eq!(matrix.c2r0, matrix[2][0]);
+        assert_eq!(matrix.c2r1, matrix[2][1]);
+        assert_eq!(matrix.c2r2, matrix[2][2]);
+        assert_eq!(matrix.c2r3, matrix[2][3]);
+        assert_eq!(matrix.c3r0, matrix[3][0]);
+        assert_eq!(matrix.c3r1, matrix[3][1]);
+        assert_eq!(matrix.c3r2, matrix[3][2]);
+        assert_eq!(matrix.c3r3, matrix[3][3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_components_out_of_bounds1() {
+        let matrix = Matrix4x4::new(
+            1_i32,  2_i32,  3_i32,  4_i32,
+            5_i32,  6_i32,  7_i32,  8_i32,
+            9_i32,  10_i32, 11_i32, 12_i32,
+            13_i32, 14_i32, 15_i32, 16_i32
+        );
+
+        assert_eq!(matrix[4][0], matrix[4][0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_components_out_of_bounds2() {
+        let matrix = Matrix4x4::new(
+            1_i32,  2_i32,  3_i32,  4_i32,
+            5_i32,  6_i32,  7_i32,  8_i32,
+            9_i32,  10_i32, 11_i32, 12_i32,
+            13_i32, 14_i32, 15_i32, 16_i32
+        );
+
+        assert_eq!(matrix[0][4], matrix[0][4]);
+    }
+
+    #[test]
+    fn test_mat_times_identity_equals_mat() {
+        test_cases().iter().for_each(|test| {
+            let a_mat_times_identity = test.a_mat * Matrix4x4::identity();
+            let b_mat_times_identity = test.b_mat * Matrix4x4::identity();
+
+            assert_eq!(a_mat_times_identity, test.a_mat);
+            assert_eq!(b_mat_times_identity, test.b_mat);
+        })
+    }
+
+    #[test]
+    fn test_mat_times_zero_equals_zero() {
         test_cases().iter().for_each(|test| {
             let a_mat_times_zero = test.a_mat * Matrix4x4::zero();
             let b_mat_times_zero = test.b_mat * Matrix4x4::zero();
@@ -2660,81 +3354,720 @@ mod matrix4x4_tests {
     }
 
     #[test]
-    fn test_look_at_rh_at_origin() {
-        let eye = Point3::new(0.0, 0.0, 0.0);
-        let target = Point3::new(1.0, 1.0, 1.0);
+    fn test_look_at_rh_at_origin() {
+        let eye = Point3::new(0.0, 0.0, 0.0);
+        let target = Point3::new(1.0, 1.0, 1.0);
+        let up = Vector3::unit_y();
+        let minus_unit_z = -Vector3::unit_z();
+        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let direction = target - Point3::origin();
+        let expected = minus_unit_z.extend(0.0);
+        let result = look_at * direction.normalize().extend(0.0);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-7));
+    }
+
+    #[test]
+    fn test_look_at_lh_at_origin() {
+        let eye = Point3::new(0.0, 0.0, 0.0);
+        let target = Point3::new(1.0, 1.0, 1.0);
+        let up = Vector3::unit_y();
+        let unit_z = Vector3::unit_z();
+        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
+        let direction = target - Point3::origin();
+        let expected = unit_z.extend(0.0);
+        let result = look_at * direction.normalize().extend(0.0);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-7));
+    }
+
+    #[test]
+    fn test_look_at_lh_no_displacement_or_rotation() {
+        let eye = Point3::new(0.0, 0.0, 0.0);
+        let target = Point3::new(0.0, 0.0, 1.0);
+        let up = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
+        let direction = target - Point3::origin();
+        let expected = Vector4::new(0.0, 0.0, 1.0, 0.0);
+        let result = look_at * direction.normalize().extend(0.0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_look_at_rh_no_displacement_or_rotation() {
+        let eye = Point3::new(0.0, 0.0, 0.0);
+        let target = Point3::new(0.0, 0.0, 1.0);
+        let up = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let expected = Vector4::new(0.0, 0.0, 0.0, 1.0);
+        let result = look_at * eye.to_homogeneous();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_look_at_lh_eye_to_origin() {
+        let eye = Point3::new(-1.0, -1.0, -1.0);
+        let target = Point3::new(1.0, 1.0, 1.0);
+        let up = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
+        let expected = Vector4::unit_w();
+        let result = look_at * eye.to_homogeneous();
+        eprintln!("{}", look_at);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_look_at_rh_eye_to_origin() {
+        let eye = Point3::new(-1.0, -1.0, -1.0);
+        let target = Point3::new(1.0, 1.0, 1.0);
+        let up = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let expected = Vector4::unit_w();
+        let result = look_at * eye.to_homogeneous();
+        eprintln!("{}", look_at);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_perspective_reverse_z_near_plane_maps_to_one() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_perspective_reverse_z(-1.0, 1.0, -1.0, 1.0, near, far);
+        let point = Vector4::new(0.0, 0.0, -near, 1.0);
+        let clip = matrix * point;
+
+        assert!(relative_eq!(clip.z / clip.w, 1.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_perspective_reverse_z_far_plane_maps_to_zero() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_perspective_reverse_z(-1.0, 1.0, -1.0, 1.0, near, far);
+        let point = Vector4::new(0.0, 0.0, -far, 1.0);
+        let clip = matrix * point;
+
+        assert!(relative_eq!(clip.z / clip.w, 0.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_perspective_fov_reverse_z_depth_range() {
+        let vfov = Degrees(72.0);
+        let aspect = 800 as f64 / 600 as f64;
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_perspective_fov_reverse_z(vfov, aspect, near, far);
+        let near_point = Vector4::new(0.0, 0.0, -near, 1.0);
+        let far_point = Vector4::new(0.0, 0.0, -far, 1.0);
+        let near_clip = matrix * near_point;
+        let far_clip = matrix * far_point;
+
+        assert!(relative_eq!(near_clip.z / near_clip.w, 1.0, epsilon = 1e-10));
+        assert!(relative_eq!(far_clip.z / far_clip.w, 0.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_perspective_wgpu_depth_range() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_perspective_wgpu(-1.0, 1.0, -1.0, 1.0, near, far);
+        let near_clip = matrix * Vector4::new(0.0, 0.0, -near, 1.0);
+        let far_clip = matrix * Vector4::new(0.0, 0.0, -far, 1.0);
+
+        assert!(relative_eq!(near_clip.z / near_clip.w, 0.0, epsilon = 1e-10));
+        assert!(relative_eq!(far_clip.z / far_clip.w, 1.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_perspective_gl_depth_range() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_perspective(-1.0, 1.0, -1.0, 1.0, near, far);
+        let near_clip = matrix * Vector4::new(0.0, 0.0, -near, 1.0);
+        let far_clip = matrix * Vector4::new(0.0, 0.0, -far, 1.0);
+
+        assert!(relative_eq!(near_clip.z / near_clip.w, -1.0, epsilon = 1e-10));
+        assert!(relative_eq!(far_clip.z / far_clip.w, 1.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_orthographic_wgpu_depth_range() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_orthographic_wgpu(-1.0, 1.0, -1.0, 1.0, near, far);
+        let near_clip = matrix * Vector4::new(0.0, 0.0, -near, 1.0);
+        let far_clip = matrix * Vector4::new(0.0, 0.0, -far, 1.0);
+
+        assert!(relative_eq!(near_clip.z / near_clip.w, 0.0, epsilon = 1e-10));
+        assert!(relative_eq!(far_clip.z / far_clip.w, 1.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_orthographic_gl_depth_range() {
+        let near = 0.1;
+        let far = 100.0;
+        let matrix = Matrix4x4::from_orthographic(-1.0, 1.0, -1.0, 1.0, near, far);
+        let near_clip = matrix * Vector4::new(0.0, 0.0, -near, 1.0);
+        let far_clip = matrix * Vector4::new(0.0, 0.0, -far, 1.0);
+
+        assert!(relative_eq!(near_clip.z / near_clip.w, -1.0, epsilon = 1e-10));
+        assert!(relative_eq!(far_clip.z / far_clip.w, 1.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_points_matches_transform_point() {
+        let matrix = Matrix4x4::from_perspective_fov(Degrees(72_f64), 4_f64 / 3_f64, 0.1_f64, 100_f64);
+        let points = [
+            Point3::new(1_f64, 2_f64, -3_f64),
+            Point3::new(-4_f64, 0.5_f64, -10_f64),
+            Point3::new(0_f64, 0_f64, -50_f64),
+            Point3::new(7_f64, -8_f64, -99_f64),
+        ];
+        let mut result = [Point3::origin(); 4];
+        matrix.transform_points(&points, &mut result);
+
+        for (point, result_point) in points.iter().zip(result.iter()) {
+            assert_eq!(*result_point, matrix.transform_point(point));
+        }
+    }
+
+    #[test]
+    fn test_transform_point_homogeneous_divide() {
+        let matrix = Matrix4x4::from_affine_scale(2_f64) * 0.5_f64;
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let result = matrix.transform_point(&point);
+
+        assert!(relative_eq!(result, Point3::new(2_f64, 4_f64, 6_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_is_finite_with_infinity() {
+        let matrix = Matrix4x4::new(
+            1_f64, 2_f64,         3_f64,  4_f64,
+            5_f64, f64::INFINITY, 7_f64,  8_f64,
+            9_f64, 10_f64,        11_f64, 12_f64,
+            13_f64, 14_f64,       15_f64, 16_f64
+        );
+
+        assert!(!matrix.is_finite());
+        assert!(!matrix.is_nan());
+    }
+
+    #[test]
+    fn test_is_nan() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, f64::NAN
+        );
+
+        assert!(matrix.is_nan());
+        assert!(!matrix.is_finite());
+    }
+
+    #[test]
+    fn test_look_at_rh_direction_parallel_to_up() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(1_f64, 7_f64, 3_f64);
+        let up: Vector3<f64> = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let x_axis = Vector3::new(look_at[0][0], look_at[1][0], look_at[2][0]);
+        let y_axis = Vector3::new(look_at[0][1], look_at[1][1], look_at[2][1]);
+        let z_axis = Vector3::new(look_at[0][2], look_at[1][2], look_at[2][2]);
+
+        assert!(look_at.is_finite());
+        assert!(relative_eq!(x_axis.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(y_axis.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(z_axis.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(x_axis.dot(&y_axis), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(y_axis.dot(&z_axis), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(z_axis.dot(&x_axis), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(
+            look_at * target.to_homogeneous(), 
+            Vector4::new(0_f64, 0_f64, -5_f64, 1_f64), 
+            epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_look_at_lh_direction_parallel_to_up() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(1_f64, -3_f64, 3_f64);
+        let up: Vector3<f64> = Vector3::unit_y();
+        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
+
+        assert!(look_at.is_finite());
+        assert!(relative_eq!(
+            look_at * target.to_homogeneous(), 
+            Vector4::new(0_f64, 0_f64, 5_f64, 1_f64), 
+            epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_nearly_singular_matrix_is_not_invertible_eps() {
+        let matrix = Matrix4x4::from_affine_scale(1e-7_f64);
+
+        assert!(relative_eq!(matrix.determinant(), 1e-21_f64, epsilon = 1e-30));
+        assert!(matrix.inverse().is_some());
+        assert!(!matrix.is_invertible_eps(1e-12_f64));
+        assert!(matrix.inverse_eps(1e-12_f64).is_none());
+    }
+
+    #[test]
+    fn test_component_div_component_mul() {
+        let matrix = Matrix4x4::new(
+            1.5_f64, -2.25_f64, 8.0_f64, 1_f64,
+            3.1_f64, 4.7_f64, -0.6_f64, 2_f64,
+            9.2_f64, 0.01_f64, 5.5_f64, 3_f64,
+            -7_f64, 0.5_f64, 2.5_f64, 4_f64
+        );
+        let other = Matrix4x4::new(
+            0.3_f64, 7_f64, -2_f64, 1.1_f64,
+            -1.9_f64, 11.3_f64, 0.8_f64, 2.2_f64,
+            4_f64, 6.5_f64, -3.3_f64, 3.3_f64,
+            0.9_f64, -0.7_f64, 5_f64, 4.4_f64
+        );
+        let result = matrix.component_mul(&other).component_div(&other);
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_default() {
+        let expected: Matrix4x4<f32> = Matrix4x4::zero();
+        let result = Matrix4x4::<f32>::default();
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_try_from_slice() {
+        let slice: &[f64] = &[
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, 16_f64,
+        ];
+        let expected = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let result = Matrix4x4::try_from(slice).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(result.c1r0, 5_f64);
+        assert_eq!(result.c0r1, 2_f64);
+    }
+
+    #[test]
+    fn test_try_from_slice_wrong_length() {
+        let data = [1_f64; 17];
+
+        assert!(Matrix4x4::try_from(&data[..15]).is_err());
+        assert!(Matrix4x4::try_from(&data[..]).is_err());
+        assert!(Matrix4x4::try_from(&data[..16]).is_ok());
+    }
+
+
+    #[test]
+    fn test_to_cols_array_from_cols_slice_round_trip() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let array = matrix.to_cols_array();
+        let result = Matrix4x4::from_cols_slice(&array);
+
+        assert_eq!(result, matrix);
+    }
+
+    #[test]
+    fn test_to_cols_array_column_major_order() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let expected = [
+            matrix.c0r0, matrix.c0r1, matrix.c0r2, matrix.c0r3,
+            matrix.c1r0, matrix.c1r1, matrix.c1r2, matrix.c1r3,
+            matrix.c2r0, matrix.c2r1, matrix.c2r2, matrix.c2r3,
+            matrix.c3r0, matrix.c3r1, matrix.c3r2, matrix.c3r3,
+        ];
+        let result = matrix.to_cols_array();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_cols_slice_ignores_extra_elements() {
+        let data: [f64; 20] = core::array::from_fn(|i| i as f64);
+        let result = Matrix4x4::from_cols_slice(&data);
+
+        assert_eq!(result.to_cols_array()[..], data[..16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_cols_slice_too_short() {
+        let data = [1_f64; 15];
+        Matrix4x4::from_cols_slice(&data);
+    }
+
+
+    #[test]
+    fn test_as_slice() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let slice = matrix.as_slice();
+
+        assert_eq!(slice.len(), 16);
+        assert_eq!(slice[0], matrix.c0r0);
+        assert_eq!(slice[1], matrix.c0r1);
+        assert_eq!(slice[4], matrix.c1r0);
+        assert_eq!(slice[15], matrix.c3r3);
+    }
+
+    #[test]
+    fn test_as_mut_slice() {
+        let mut matrix = Matrix4x4::identity();
+        let slice = matrix.as_mut_slice();
+
+        assert_eq!(slice.len(), 16);
+        slice[0] = 2_f64;
+        slice[4] = 3_f64;
+
+        assert_eq!(matrix.c0r0, 2_f64);
+        assert_eq!(matrix.c1r0, 3_f64);
+    }
+
+
+    #[test]
+    fn test_look_to_rh_agrees_with_look_at_rh() {
+        let eye = Point3::new(-3_f64, 2_f64, 5_f64);
+        let direction = Vector3::new(1_f64, -2_f64, -4_f64);
+        let up = Vector3::unit_y();
+        let target = eye + direction * 7_f64;
+        let expected = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let result = Matrix4x4::look_to_rh(&eye, &direction, &up);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_look_to_lh_agrees_with_look_at_lh() {
+        let eye = Point3::new(-3_f64, 2_f64, 5_f64);
+        let direction = Vector3::new(1_f64, -2_f64, -4_f64);
+        let up = Vector3::unit_y();
+        let target = eye + direction * 7_f64;
+        let expected = Matrix4x4::look_at_lh(&eye, &target, &up);
+        let result = Matrix4x4::look_to_lh(&eye, &direction, &up);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_look_to_rh_maps_direction_to_negative_z() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let direction = Vector3::new(0_f64, 0_f64, 5_f64);
+        let up = Vector3::unit_y();
+        let matrix = Matrix4x4::look_to_rh(&eye, &direction, &up);
+        let result = matrix * (eye + direction).to_homogeneous();
+
+        assert!(relative_eq!(result, Vector4::new(0_f64, 0_f64, -5_f64, 1_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_cast_f64_to_f32() {
+        let matrix: Matrix4x4<f64> = Matrix4x4::from_affine_translation(
+            &Vector3::new(1_f64, -2_f64, 3_f64)
+        );
+        let expected: Option<Matrix4x4<f32>> = Some(Matrix4x4::from_affine_translation(
+            &Vector3::new(1_f32, -2_f32, 3_f32)
+        ));
+        let result = matrix.cast::<f32>();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cast_out_of_range_fails() {
+        let matrix: Matrix4x4<f64> = Matrix4x4::from_affine_translation(
+            &Vector3::new(1000_f64, 2_f64, 3_f64)
+        );
+        let result = matrix.cast::<u8>();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_from_translation_rotation_scale() {
+        let translation = Vector3::new(1_f64, -2_f64, 3_f64);
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let angle = Radians(1_f64);
+        let rotation = Matrix3x3::from_axis_angle(&axis, angle);
+        let scale = Vector3::new(2_f64, 3_f64, 5_f64);
+        let expected = Matrix4x4::from_affine_translation(&translation) *
+            Matrix4x4::from_affine_axis_angle(&axis, angle) *
+            Matrix4x4::from_affine_nonuniform_scale(scale.x, scale.y, scale.z);
+        let result = Matrix4x4::from_translation_rotation_scale(&translation, &rotation, &scale);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_translation_rotation_scale_round_trip() {
+        let translation = Vector3::new(4_f64, 5_f64, -6_f64);
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64));
+        let rotation = Matrix3x3::from_axis_angle(&axis, Radians(2_f64));
+        let scale = Vector3::new(0.5_f64, 2_f64, 7_f64);
+        let matrix = Matrix4x4::from_translation_rotation_scale(&translation, &rotation, &scale);
+        let result_translation = matrix.column(3).contract();
+        let result_scale = Vector3::new(
+            matrix.column(0).contract().magnitude(),
+            matrix.column(1).contract().magnitude(),
+            matrix.column(2).contract().magnitude(),
+        );
+        let result_rotation = Matrix3x3::from_columns(
+            matrix.column(0).contract() / result_scale.x,
+            matrix.column(1).contract() / result_scale.y,
+            matrix.column(2).contract() / result_scale.z,
+        );
+
+        assert!(relative_eq!(result_translation, translation, epsilon = 1e-10));
+        assert!(relative_eq!(result_scale, scale, epsilon = 1e-10));
+        assert!(relative_eq!(result_rotation, rotation, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_column_array() {
+        let columns = [
+            Vector4::new(1_f64, 2_f64, 3_f64, 4_f64),
+            Vector4::new(5_f64, 6_f64, 7_f64, 8_f64),
+            Vector4::new(9_f64, 10_f64, 11_f64, 12_f64),
+            Vector4::new(13_f64, 14_f64, 15_f64, 16_f64),
+        ];
+        let expected = Matrix4x4::from_columns(columns[0], columns[1], columns[2], columns[3]);
+        let result = Matrix4x4::from_column_array(columns);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_condition_number_identity() {
+        let identity: Matrix4x4<f64> = Matrix4x4::identity();
+
+        assert!(relative_eq!(identity.condition_number(), 4_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_condition_number_near_singular() {
+        let matrix = Matrix4x4::from_affine_nonuniform_scale(1e-9_f64, 1_f64, 1_f64);
+
+        assert!(matrix.condition_number() > 1e9_f64);
+    }
+
+    #[test]
+    fn test_look_at_interpolated_amount_one() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0_f64);
         let up = Vector3::unit_y();
-        let minus_unit_z = -Vector3::unit_z();
-        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
-        let direction = target - Point3::origin();
-        let expected = minus_unit_z.extend(0.0);
-        let result = look_at * direction.normalize().extend(0.0);
+        let current = Matrix4x4::look_at_rh(&Point3::new(0_f64, 0_f64, 10_f64), &Point3::origin(), &up);
+        let expected = Matrix4x4::look_at_rh(&eye, &target, &up);
+        let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 1_f64);
 
-        assert!(relative_eq!(result, expected, epsilon = 1e-7));
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
     }
 
     #[test]
-    fn test_look_at_lh_at_origin() {
-        let eye = Point3::new(0.0, 0.0, 0.0);
-        let target = Point3::new(1.0, 1.0, 1.0);
+    fn test_look_at_interpolated_amount_zero() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0_f64);
         let up = Vector3::unit_y();
-        let unit_z = Vector3::unit_z();
-        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
-        let direction = target - Point3::origin();
-        let expected = unit_z.extend(0.0);
-        let result = look_at * direction.normalize().extend(0.0);
+        let current = Matrix4x4::look_at_rh(&Point3::new(0_f64, 0_f64, 10_f64), &Point3::origin(), &up);
+        let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 0_f64);
 
-        assert!(relative_eq!(result, expected, epsilon = 1e-7));
+        assert!(relative_eq!(result, current, epsilon = 1e-10));
     }
 
     #[test]
-    fn test_look_at_lh_no_displacement_or_rotation() {
-        let eye = Point3::new(0.0, 0.0, 0.0);
-        let target = Point3::new(0.0, 0.0, 1.0);
+    fn test_look_at_interpolated_fixed_eye_does_not_move() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0_f64);
         let up = Vector3::unit_y();
-        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
-        let direction = target - Point3::origin();
-        let expected = Vector4::new(0.0, 0.0, 1.0, 0.0);
-        let result = look_at * direction.normalize().extend(0.0);
+        let current = Matrix4x4::look_at_rh(&eye, &Point3::new(6_f64, -1_f64, 2_f64), &up);
+        let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 0.5_f64);
+        let result_inverse = result.inverse().unwrap();
+        let result_eye = result_inverse.column(3).contract();
 
-        assert_eq!(result, expected);
+        assert!(relative_eq!(result_eye, eye.to_vector(), epsilon = 1e-10));
     }
 
     #[test]
-    fn test_look_at_rh_no_displacement_or_rotation() {
-        let eye = Point3::new(0.0, 0.0, 0.0);
-        let target = Point3::new(0.0, 0.0, 1.0);
+    fn test_look_at_interpolated_eye_moves_in_a_line() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0_f64);
         let up = Vector3::unit_y();
-        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
-        let expected = Vector4::new(0.0, 0.0, 0.0, 1.0);
-        let result = look_at * eye.to_homogeneous();
+        let current_eye = Point3::new(0_f64, 0_f64, 10_f64);
+        let current = Matrix4x4::look_at_rh(&current_eye, &Point3::origin(), &up);
+        let expected = current_eye.to_vector().lerp(&eye.to_vector(), 0.25_f64);
+        let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 0.25_f64);
+        let result_eye = result.inverse().unwrap().column(3).contract();
 
-        assert_eq!(result, expected);
+        assert!(relative_eq!(result_eye, expected, epsilon = 1e-10));
     }
 
     #[test]
-    fn test_look_at_lh_eye_to_origin() {
-        let eye = Point3::new(-1.0, -1.0, -1.0);
-        let target = Point3::new(1.0, 1.0, 1.0);
+    fn test_look_at_interpolated_is_rigid() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0_f64);
         let up = Vector3::unit_y();
-        let look_at = Matrix4x4::look_at_lh(&eye, &target, &up);
-        let expected = Vector4::unit_w();
-        let result = look_at * eye.to_homogeneous();
-        eprintln!("{}", look_at);
+        let current = Matrix4x4::look_at_rh(&Point3::new(0_f64, 0_f64, 10_f64), &Point3::origin(), &up);
+        let result = Matrix4x4::look_at_interpolated(&eye, &target, &up, &current, 0.3_f64);
+        let rotation = Matrix3x3::from_columns(
+            result.column(0).contract(),
+            result.column(1).contract(),
+            result.column(2).contract(),
+        );
+
+        assert!(relative_eq!(rotation.transpose() * rotation, Matrix3x3::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(rotation.determinant(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_mat3_to_mat3_round_trip() {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 5_f64));
+        let rotation = Matrix3x3::from_axis_angle(&axis, Radians(0.7_f64));
+        let result = Matrix4x4::from_mat3(&rotation).to_mat3();
+
+        assert_eq!(result, rotation);
+    }
+
+    #[test]
+    fn test_from_mat3_matches_affine_rotation() {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 5_f64));
+        let angle = Radians(0.7_f64);
+        let expected = Matrix4x4::from_affine_axis_angle(&axis, angle);
+        let result = Matrix4x4::from_mat3(&Matrix3x3::from_axis_angle(&axis, angle));
+
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_look_at_rh_eye_to_origin() {
-        let eye = Point3::new(-1.0, -1.0, -1.0);
-        let target = Point3::new(1.0, 1.0, 1.0);
-        let up = Vector3::unit_y();
-        let look_at = Matrix4x4::look_at_rh(&eye, &target, &up);
-        let expected = Vector4::unit_w();
-        let result = look_at * eye.to_homogeneous();
-        eprintln!("{}", look_at);
+    fn test_to_mat3_drops_translation() {
+        let rotation = Matrix3x3::from_angle_x(Radians(1_f64));
+        let translation = Vector3::new(7_f64, 8_f64, 9_f64);
+        let scale = Vector3::new(1_f64, 1_f64, 1_f64);
+        let matrix = Matrix4x4::from_translation_rotation_scale(&translation, &rotation, &scale);
+
+        assert_eq!(matrix.to_mat3(), rotation);
+    }
+
+    #[test]
+    fn test_orthonormalize_rotation() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+        let rotation = Matrix3x3::from_axis_angle(&axis, Radians(0.8_f64));
+        let drift = Matrix3x3::new(
+            1e-3_f64,  2e-3_f64, -1e-3_f64,
+            0_f64,    -3e-3_f64,  2e-3_f64,
+            1e-3_f64,  0_f64,     4e-3_f64,
+        );
+        let translation = Vector3::new(-7_f64, 8_f64, 9_f64);
+        let scale = Vector3::new(1_f64, 1_f64, 1_f64);
+        let matrix = Matrix4x4::from_translation_rotation_scale(&translation, &(rotation + drift), &scale);
+        let result = matrix.orthonormalize_rotation();
+        let result_rotation = result.to_mat3();
+
+        assert_eq!(result.column(3), matrix.column(3));
+        assert_eq!(result.row(3), matrix.row(3));
+        assert!(relative_eq!(
+            result_rotation.transpose() * result_rotation, Matrix3x3::identity(), epsilon = 1e-10
+        ));
+        assert!(relative_eq!(result_rotation.determinant(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result_rotation, rotation, epsilon = 1e-2));
+    }
+
+    #[test]
+    fn test_orthonormalize_rotation_scaled_parallel_columns() {
+        let matrix = Matrix4x4::new(
+            1_f64,  0_f64,  0_f64, 0_f64,
+            1_f64,  2_f64,  3_f64, 0_f64,
+            3_f64,  6_f64,  9_f64, 0_f64,
+            -7_f64, 8_f64,  9_f64, 1_f64,
+        );
+        let result = matrix.orthonormalize_rotation();
+        let result_rotation = result.to_mat3();
+
+        assert_eq!(result.column(3), matrix.column(3));
+        assert_eq!(result.row(3), matrix.row(3));
+        assert!(relative_eq!(
+            result_rotation.transpose() * result_rotation, Matrix3x3::identity(), epsilon = 1e-10
+        ));
+        assert!(relative_eq!(result_rotation.determinant(), 1_f64, epsilon = 1e-10));
+        assert_eq!(result_rotation.column(0), Vector3::unit_x());
+    }
+
+    #[test]
+    fn test_orthonormalize_rotation_degenerate() {
+        let matrix = Matrix4x4::from_affine_nonuniform_scale(0_f64, 0_f64, 1_f64);
+        let result = matrix.orthonormalize_rotation();
+        let result_rotation = result.to_mat3();
+
+        assert_eq!(result.column(3), matrix.column(3));
+        assert!(relative_eq!(
+            result_rotation.transpose() * result_rotation, Matrix3x3::identity(), epsilon = 1e-10
+        ));
+        assert!(relative_eq!(result_rotation.determinant(), 1_f64, epsilon = 1e-10));
+        assert_eq!(result_rotation.column(0), Vector3::unit_x());
+        assert_eq!(result_rotation.column(1).dot(&Vector3::unit_x()), 0_f64);
+    }
+
+    #[test]
+    fn test_orthonormalize_rotation_reflection_becomes_rotation() {
+        let matrix = Matrix4x4::new(
+            1_f64,  0_f64, 0_f64,  0_f64,
+            0_f64,  1_f64, 0_f64,  0_f64,
+            0_f64,  0_f64, -1_f64, 0_f64,
+            -7_f64, 8_f64, 9_f64,  1_f64,
+        );
+        let result = matrix.orthonormalize_rotation();
+        let expected = Matrix4x4::new(
+            1_f64,  0_f64, 0_f64, 0_f64,
+            0_f64,  1_f64, 0_f64, 0_f64,
+            0_f64,  0_f64, 1_f64, 0_f64,
+            -7_f64, 8_f64, 9_f64, 1_f64,
+        );
+
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_orthonormalize_rotation_ignores_third_column() {
+        let matrix = Matrix4x4::new(
+            1_f64,  0_f64,  0_f64, 0_f64,
+            0_f64,  1_f64,  0_f64, 0_f64,
+            5_f64,  -3_f64, 0_f64, 0_f64,
+            -7_f64, 8_f64,  9_f64, 1_f64,
+        );
+        let result = matrix.orthonormalize_rotation();
+
+        assert_eq!(result.to_mat3(), Matrix3x3::identity());
+    }
 }
 
 
@@ -3048,6 +4381,14 @@ mod matrix1x3_tests {
 
         assert_eq!(matrix - matrix, zero_mat1x3);
     }
+
+    #[test]
+    fn test_from_fill() {
+        let expected = Matrix1x3::new(5_i32, 5_i32, 5_i32);
+        let result = Matrix1x3::from_fill(5_i32);
+
+        assert_eq!(result, expected);
+    }
 }
 
 #[cfg(test)]
@@ -3216,6 +4557,7 @@ mod matrix1x4_tests {
 
 #[cfg(test)]
 mod matrix2x3_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector3,
         Vector2,
@@ -3508,11 +4850,109 @@ mod matrix2x3_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_rank_deficient() {
+        let matrix = Matrix2x3::new(
+            1_f64, 2_f64,
+            2_f64, 4_f64,
+            3_f64, 6_f64
+        );
+
+        assert!(matrix.pseudo_inverse().is_none());
+    }
+
+    #[test]
+    fn test_gram() {
+        let matrix = Matrix2x3::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32,
+            5_i32, 6_i32
+        );
+        let expected = Matrix3x3::new(
+            5_i32,  11_i32, 17_i32,
+            11_i32, 25_i32, 39_i32,
+            17_i32, 39_i32, 61_i32
+        );
+        let result = matrix.gram();
+
+        assert_eq!(result, expected);
+        assert_eq!(result, result.transpose());
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Transform {
+            matrix: Matrix2x3<i32>,
+            offset: Vector2<i32>,
+        }
+
+        let transform = Transform::default();
+
+        assert_eq!(transform.matrix, Matrix2x3::zero());
+        assert_eq!(transform.offset, Vector2::zero());
+    }
+
+    #[test]
+    fn test_tuple_index_row_column_order() {
+        let matrix = Matrix2x3::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32,
+            5_i32, 6_i32
+        );
+
+        assert_eq!(matrix[(1, 2)], matrix[2][1]);
+        assert_eq!(matrix[(1, 2)], 6_i32);
+    }
+
+    #[test]
+    fn test_display_grid() {
+        let matrix = Matrix2x3::new(
+            1_i32, 4_i32,
+            2_i32, 5_i32,
+            3_i32, -6_i32
+        );
+        let expected = "Matrix2x3 [\n    [1, 2,  3],\n    [4, 5, -6]\n]";
+        let result = format!("{}", matrix);
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[test]
+    fn test_as_mut_slice() {
+        let mut matrix = Matrix2x3::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32,
+            5_i32, 6_i32,
+        );
+        let data = [7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32];
+        matrix.as_mut_slice().copy_from_slice(&data);
+
+        assert_eq!(matrix.as_slice().len(), 6);
+        assert_eq!(matrix.as_slice()[0], matrix.c0r0);
+        assert_eq!(matrix, Matrix2x3::new(7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32));
+    }
 }
 
 
 #[cfg(test)]
 mod matrix3x2_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector3,
         Vector2,
@@ -3781,12 +5221,48 @@ mod matrix3x2_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix3x2::new(
+            2_f64, -1_f64, 4_f64,
+            1_f64, 3_f64, 5_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_left_inverse() {
+        let matrix = Matrix3x2::new(
+            2_f64, -1_f64, 4_f64,
+            1_f64, 3_f64, 5_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let expected = Matrix2x2::identity();
+        let result = pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_rank_deficient() {
+        let matrix = Matrix3x2::new(
+            1_f64, 2_f64, 3_f64,
+            2_f64, 4_f64, 6_f64
+        );
+
+        assert!(matrix.pseudo_inverse().is_none());
+    }
 }
 
 
 
 #[cfg(test)]
 mod matrix2x4_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector4,
         Vector2,
@@ -4116,11 +5592,26 @@ mod matrix2x4_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix2x4::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64,
+            0_f64, 1_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
 }
 
 
 #[cfg(test)]
 mod matrix4x2_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector4,
         Vector2,
@@ -4396,11 +5887,24 @@ mod matrix4x2_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix4x2::new(
+            2_f64, -1_f64, 4_f64, 0_f64,
+            1_f64, 3_f64, 5_f64, 1_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
 }
 
 
 #[cfg(test)]
 mod matrix3x4_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector4,
         Vector3,
@@ -4747,11 +6251,45 @@ mod matrix3x4_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix3x4::new(
+            1_f64, 0_f64, 2_f64,
+            0_f64, 1_f64, 1_f64,
+            3_f64, 0_f64, 1_f64,
+            1_f64, 2_f64, 0_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_component_div_component_mul() {
+        let matrix = Matrix3x4::new(
+            1.5_f64, -2.25_f64, 8.0_f64,
+            3.1_f64, 4.7_f64, -0.6_f64,
+            9.2_f64, 0.01_f64, 5.5_f64,
+            -7_f64, 0.5_f64, 2.5_f64
+        );
+        let other = Matrix3x4::new(
+            0.3_f64, 7_f64, -2_f64,
+            -1.9_f64, 11.3_f64, 0.8_f64,
+            4_f64, 6.5_f64, -3.3_f64,
+            0.9_f64, -0.7_f64, 5_f64
+        );
+        let result = matrix.component_mul(&other).component_div(&other);
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-10));
+    }
 }
 
 
 #[cfg(test)]
 mod matrix4x3_tests {
+    use approx::relative_eq;
     use cglinalg::{
         Vector4,
         Vector3,
@@ -5061,5 +6599,107 @@ mod matrix4x3_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let matrix = Matrix4x3::new(
+            1_f64, 0_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 0_f64, 2_f64,
+            2_f64, 1_f64, 1_f64, 0_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let result = matrix * pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, matrix, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_left_inverse() {
+        let matrix = Matrix4x3::new(
+            1_f64, 0_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 0_f64, 2_f64,
+            2_f64, 1_f64, 1_f64, 0_f64
+        );
+        let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+        let expected = Matrix3x3::identity();
+        let result = pseudo_inverse * matrix;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_gram_symmetric() {
+        let matrix = Matrix4x3::new(
+            3_f64, -1_f64, 0.5_f64, 7_f64,
+            2_f64, 9_f64, -4_f64, 1_f64,
+            -6_f64, 0.25_f64, 8_f64, 3_f64
+        );
+        let result = matrix.gram();
+
+        assert_eq!(result, result.transpose());
+    }
 }
 
+
+
+#[cfg(test)]
+mod square_matrix_tests {
+    use approx::relative_eq;
+    use cglinalg::{
+        Matrix2x2,
+        Matrix3x3,
+        SquareMatrix,
+    };
+
+
+    fn determinant_of_inverse<M>(matrix: &M) -> Option<M::Element>
+    where
+        M: SquareMatrix
+    {
+        matrix.inverse().map(|matrix_inverse| matrix_inverse.determinant())
+    }
+
+    #[test]
+    fn test_square_matrix_generic_matrix2x2() {
+        let matrix = Matrix2x2::new(
+            2_f64, 1_f64,
+            3_f64, 4_f64
+        );
+        let expected = 1_f64 / 5_f64;
+        let result = determinant_of_inverse(&matrix).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_square_matrix_generic_matrix3x3() {
+        let matrix = Matrix3x3::new(
+            2_f64, 0_f64, 0_f64,
+            0_f64, 4_f64, 1_f64,
+            0_f64, 1_f64, 1_f64
+        );
+        let expected = 1_f64 / 6_f64;
+        let result = determinant_of_inverse(&matrix).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_square_matrix_generic_singular() {
+        let matrix: Matrix3x3<f64> = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            2_f64, 4_f64, 6_f64,
+            0_f64, 1_f64, 1_f64
+        );
+
+        assert!(determinant_of_inverse(&matrix).is_none());
+    }
+
+    #[test]
+    fn test_square_matrix_identity() {
+        let identity: Matrix2x2<f64> = <Matrix2x2<f64> as SquareMatrix>::identity();
+
+        assert!(SquareMatrix::is_identity(&identity));
+        assert_eq!(SquareMatrix::trace(&identity), 2_f64);
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "mint")]
+extern crate cglinalg;
+
+
+use cglinalg::{
+    Vector2,
+    Vector3,
+    Vector4,
+    Point2,
+    Point3,
+    Matrix2x2,
+    Matrix3x3,
+    Matrix4x4,
+};
+
+
+#[test]
+fn test_vector2_mint_round_trip() {
+    let vector = Vector2::new(1_f64, 2_f64);
+    let mint_vector: mint::Vector2<f64> = vector.into();
+
+    assert_eq!(mint_vector.x, vector.x);
+    assert_eq!(mint_vector.y, vector.y);
+    assert_eq!(Vector2::from(mint_vector), vector);
+}
+
+#[test]
+fn test_vector3_mint_round_trip() {
+    let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    let mint_vector: mint::Vector3<f64> = vector.into();
+
+    assert_eq!(mint_vector.x, vector.x);
+    assert_eq!(mint_vector.y, vector.y);
+    assert_eq!(mint_vector.z, vector.z);
+    assert_eq!(Vector3::from(mint_vector), vector);
+}
+
+#[test]
+fn test_vector4_mint_round_trip() {
+    let vector = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+    let mint_vector: mint::Vector4<f64> = vector.into();
+
+    assert_eq!(mint_vector.x, vector.x);
+    assert_eq!(mint_vector.y, vector.y);
+    assert_eq!(mint_vector.z, vector.z);
+    assert_eq!(mint_vector.w, vector.w);
+    assert_eq!(Vector4::from(mint_vector), vector);
+}
+
+#[test]
+fn test_point2_mint_round_trip() {
+    let point = Point2::new(1_f64, 2_f64);
+    let mint_point: mint::Point2<f64> = point.into();
+
+    assert_eq!(mint_point.x, point.x);
+    assert_eq!(mint_point.y, point.y);
+    assert_eq!(Point2::from(mint_point), point);
+}
+
+#[test]
+fn test_point3_mint_round_trip() {
+    let point = Point3::new(1_f64, 2_f64, 3_f64);
+    let mint_point: mint::Point3<f64> = point.into();
+
+    assert_eq!(mint_point.x, point.x);
+    assert_eq!(mint_point.y, point.y);
+    assert_eq!(mint_point.z, point.z);
+    assert_eq!(Point3::from(mint_point), point);
+}
+
+#[test]
+fn test_matrix2x2_mint_column_order() {
+    let matrix = Matrix2x2::new(
+        1_f64, 2_f64, 
+        3_f64, 4_f64
+    );
+    let mint_matrix: mint::ColumnMatrix2<f64> = matrix.into();
+
+    assert_eq!(mint_matrix.x.x, matrix.c0r0);
+    assert_eq!(mint_matrix.x.y, matrix.c0r1);
+    assert_eq!(mint_matrix.y.x, matrix.c1r0);
+    assert_eq!(mint_matrix.y.y, matrix.c1r1);
+    assert_eq!(Matrix2x2::from(mint_matrix), matrix);
+}
+
+#[test]
+fn test_matrix3x3_mint_column_order() {
+    let matrix = Matrix3x3::new(
+        1_f64, 2_f64, 3_f64, 
+        4_f64, 5_f64, 6_f64,
+        7_f64, 8_f64, 9_f64
+    );
+    let mint_matrix: mint::ColumnMatrix3<f64> = matrix.into();
+
+    assert_eq!(mint_matrix.x.x, matrix.c0r0);
+    assert_eq!(mint_matrix.x.z, matrix.c0r2);
+    assert_eq!(mint_matrix.y.x, matrix.c1r0);
+    assert_eq!(mint_matrix.z.y, matrix.c2r1);
+    assert_eq!(Matrix3x3::from(mint_matrix), matrix);
+}
+
+#[test]
+fn test_matrix4x4_mint_column_order() {
+    let matrix = Matrix4x4::new(
+        1_f64,  2_f64,  3_f64,  4_f64,
+        5_f64,  6_f64,  7_f64,  8_f64,
+        9_f64,  10_f64, 11_f64, 12_f64,
+        13_f64, 14_f64, 15_f64, 16_f64
+    );
+    let mint_matrix: mint::ColumnMatrix4<f64> = matrix.into();
+
+    assert_eq!(mint_matrix.x.y, matrix.c0r1);
+    assert_eq!(mint_matrix.w.x, matrix.c3r0);
+    assert_eq!(mint_matrix.w.z, matrix.c3r2);
+    assert_eq!(mint_matrix.z.w, matrix.c2r3);
+    assert_eq!(Matrix4x4::from(mint_matrix), matrix);
+}
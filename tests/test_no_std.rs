@@ -0,0 +1,56 @@
+#![cfg(not(feature = "std"))]
+#![no_std]
+extern crate cglinalg;
+
+// This test suite only runs when the crate is built without the `std` feature.
+// The test crate is itself `no_std`, so it can only call functions that need 
+// nothing beyond `core`, the same ones an embedded or WebAssembly target uses.
+
+
+use cglinalg::{
+    Degrees,
+    Matrix4x4,
+    Point3,
+    Vector3,
+    Vector4,
+};
+use approx::relative_eq;
+
+
+fn model_view_projection(eye: &Point3<f64>, target: &Point3<f64>) -> Matrix4x4<f64> {
+    let up = Vector3::unit_y();
+    let projection = Matrix4x4::from_perspective_fov(Degrees(90_f64), 1_f64, 0.1_f64, 100_f64);
+    let view = Matrix4x4::look_at_rh(eye, target, &up);
+    let model = Matrix4x4::from_affine_translation(&Vector3::new(0_f64, 0_f64, -1_f64));
+
+    projection * view * model
+}
+
+#[test]
+fn test_matrix4x4_math_without_std() {
+    let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    let target = Point3::origin();
+    let matrix = model_view_projection(&eye, &target);
+    let matrix_inverse = matrix.inverse().unwrap();
+
+    assert!(relative_eq!(matrix * matrix_inverse, Matrix4x4::identity(), epsilon = 1e-10));
+}
+
+#[test]
+fn test_matrix4x4_transform_without_std() {
+    let matrix = Matrix4x4::from_affine_scale(2_f64);
+    let vector = Vector4::new(1_f64, 2_f64, 3_f64, 1_f64);
+    let expected = Vector4::new(2_f64, 4_f64, 6_f64, 1_f64);
+    let result = matrix * vector;
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_matrix4x4_rotation_without_std() {
+    let matrix = Matrix4x4::from_affine_angle_z(Degrees(30_f64));
+    let matrix_inverse = matrix.inverse().unwrap();
+
+    assert!(relative_eq!(matrix.determinant(), 1_f64, epsilon = 1e-10));
+    assert!(relative_eq!(matrix_inverse, matrix.transpose(), epsilon = 1e-10));
+}
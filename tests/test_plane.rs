@@ -0,0 +1,145 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod plane3_tests {
+    use cglinalg::{
+        Magnitude,
+        Perspective3,
+        Plane3,
+        Point3,
+        Unit,
+        Vector3,
+        Vector4,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_points() {
+        let a = Point3::new(1_f64, 0_f64, 0_f64);
+        let b = Point3::new(0_f64, 1_f64, 0_f64);
+        let c = Point3::new(0_f64, 0_f64, 1_f64);
+        let plane = Plane3::from_points(&a, &b, &c).unwrap();
+        let expected_normal = Vector3::new(1_f64, 1_f64, 1_f64) / f64::sqrt(3_f64);
+
+        assert!(relative_eq!(plane.normal.into_inner(), expected_normal, epsilon = 1e-10));
+        assert!(relative_eq!(plane.signed_distance(&a), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(plane.signed_distance(&b), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(plane.signed_distance(&c), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_points_collinear() {
+        let a = Point3::new(1_f64, 2_f64, 3_f64);
+        let b = Point3::new(2_f64, 4_f64, 6_f64);
+        let c = Point3::new(3_f64, 6_f64, 9_f64);
+
+        assert!(Plane3::from_points(&a, &b, &c).is_none());
+    }
+
+    #[test]
+    fn test_signed_distance_point_above_plane() {
+        let plane = Plane3::new(Unit::from_value(Vector3::unit_y()), -2_f64);
+        let point = Point3::new(3_f64, 7_f64, -1_f64);
+
+        assert_eq!(plane.signed_distance(&point), 5_f64);
+    }
+
+    #[test]
+    fn test_signed_distance_point_below_plane() {
+        let plane = Plane3::new(Unit::from_value(Vector3::unit_y()), -2_f64);
+        let point = Point3::new(3_f64, -1_f64, -1_f64);
+
+        assert_eq!(plane.signed_distance(&point), -3_f64);
+    }
+
+    #[test]
+    fn test_project_point_lies_in_plane() {
+        let a = Point3::new(1_f64, 2_f64, 0_f64);
+        let b = Point3::new(-1_f64, 3_f64, 1_f64);
+        let c = Point3::new(2_f64, -2_f64, 4_f64);
+        let plane = Plane3::from_points(&a, &b, &c).unwrap();
+        let point = Point3::new(10_f64, -7_f64, 3_f64);
+        let projected = plane.project_point(&point);
+
+        assert!(relative_eq!(plane.signed_distance(&projected), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(
+            (point - projected).magnitude(), plane.signed_distance(&point).abs(), epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_from_coefficients_normalizes() {
+        let coefficients = Vector4::new(2_f64, 0_f64, 0_f64, -6_f64);
+        let plane = Plane3::from_coefficients(&coefficients).unwrap();
+        let expected = Plane3::new(Unit::from_value(Vector3::unit_x()), -3_f64);
+
+        assert_eq!(plane, expected);
+        assert_eq!(plane.to_coefficients(), Vector4::new(1_f64, 0_f64, 0_f64, -3_f64));
+    }
+
+    #[test]
+    fn test_from_coefficients_zero_normal() {
+        let coefficients = Vector4::new(0_f64, 0_f64, 0_f64, 1_f64);
+
+        assert!(Plane3::from_coefficients(&coefficients).is_none());
+    }
+
+    #[test]
+    fn test_frustum_planes() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let inside = Point3::new(0_f64, 0_f64, -50_f64);
+        let outside = Point3::new(0_f64, 0_f64, 50_f64);
+        let planes = perspective.frustum_planes();
+
+        assert!(planes.iter().all(|coefficients| {
+            let plane = Plane3::from_coefficients(coefficients).unwrap();
+            plane.signed_distance(&inside) >= 0_f64
+        }));
+        assert!(planes.iter().any(|coefficients| {
+            let plane = Plane3::from_coefficients(coefficients).unwrap();
+            plane.signed_distance(&outside) < 0_f64
+        }));
+    }
+
+    #[test]
+    fn test_from_points_small_triangle() {
+        let a = Point3::new(1_f64, 1_f64, 1_f64);
+        let b = Point3::new(1_f64 + 1e-8_f64, 1_f64, 1_f64);
+        let c = Point3::new(1_f64, 1_f64 + 1e-8_f64, 1_f64);
+        let plane = Plane3::from_points(&a, &b, &c).unwrap();
+
+        assert!(relative_eq!(plane.normal.into_inner(), Vector3::unit_z(), epsilon = 1e-6));
+        assert!(relative_eq!(plane.signed_distance(&a), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_points_large_nearly_collinear() {
+        let a = Point3::new(0_f64, 0_f64, 0_f64);
+        let b = Point3::new(1e8_f64, 2e8_f64, 3e8_f64);
+        let c = Point3::new(2e8_f64, 4e8_f64, 6e8_f64 + 1e-6_f64);
+
+        assert!(Plane3::from_points(&a, &b, &c).is_none());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = Point3::new(1_f64, 2_f64, 0_f64);
+        let b = Point3::new(-1_f64, 3_f64, 1_f64);
+        let c = Point3::new(2_f64, -2_f64, 4_f64);
+        let plane = Plane3::from_points(&a, &b, &c).unwrap();
+        let result = plane.normalize();
+        let point = Point3::new(10_f64, -7_f64, 3_f64);
+
+        assert!(relative_eq!(result.normal.magnitude(), 1_f64, epsilon = 1e-15));
+        assert!(relative_eq!(result.normal.into_inner(), plane.normal.into_inner(), epsilon = 1e-15));
+        assert!(relative_eq!(result.distance, plane.distance, epsilon = 1e-15));
+        assert!(relative_eq!(
+            result.signed_distance(&point), plane.signed_distance(&point), epsilon = 1e-12
+        ));
+    }
+}
+
@@ -141,8 +141,10 @@ mod point2_tests {
     use cglinalg::{
         Point2,
         Vector2,
+        Vector3,
         Magnitude,   
     };
+    use approx::relative_eq;
 
 
     #[test]
@@ -272,6 +274,49 @@ mod point2_tests {
         assert_eq!(p.x, p[0]);
         assert_eq!(p.y, p[1]);
     }
+
+    #[test]
+    fn test_distance() {
+        let p1: Point2<f64> = Point2::origin();
+        let p2 = Point2::new(3_f64, 4_f64);
+
+        assert_eq!(p1.distance_squared(&p2), 25_f64);
+        assert_eq!(p1.distance(&p2), 5_f64);
+    }
+
+    #[test]
+    fn test_barycentric_vertices() {
+        let a = Point2::new(1_f64, 1_f64);
+        let b = Point2::new(5_f64, 2_f64);
+        let c = Point2::new(2_f64, 6_f64);
+
+        assert_eq!(Point2::barycentric(&a, &a, &b, &c), Some(Vector3::unit_x()));
+        assert_eq!(Point2::barycentric(&b, &a, &b, &c), Some(Vector3::unit_y()));
+        assert_eq!(Point2::barycentric(&c, &a, &b, &c), Some(Vector3::unit_z()));
+    }
+
+    #[test]
+    fn test_barycentric_centroid() {
+        let a = Point2::new(1_f64, 1_f64);
+        let b = Point2::new(5_f64, 2_f64);
+        let c = Point2::new(2_f64, 6_f64);
+        let centroid = Point2::new(8_f64 / 3_f64, 3_f64);
+        let one_third = 1_f64 / 3_f64;
+        let expected = Vector3::new(one_third, one_third, one_third);
+        let result = Point2::barycentric(&centroid, &a, &b, &c).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_barycentric_degenerate_triangle() {
+        let a = Point2::new(0_f64, 0_f64);
+        let b = Point2::new(1_f64, 1_f64);
+        let c = Point2::new(2_f64, 2_f64);
+        let p = Point2::new(1_f64, 0_f64);
+
+        assert!(Point2::barycentric(&p, &a, &b, &c).is_none());
+    }
 }
 
 
@@ -281,7 +326,11 @@ mod point3_tests {
         Point3,
         Vector3,
         Magnitude,   
+        Radians,
+        Angle,
     };
+    use approx::relative_eq;
+    use core::cmp::Ordering;
 
 
     #[test]
@@ -416,5 +465,386 @@ mod point3_tests {
         assert_eq!(p.y, p[1]);
         assert_eq!(p.z, p[2]);
     }
+
+    #[test]
+    fn test_try_normalize_unit_point() {
+        let p = Point3::new(0_f64, 1_f64, 0_f64);
+        let expected = Some(p);
+        let result = p.try_normalize(1e-10);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_normalize_origin() {
+        let origin: Point3<f64> = Point3::origin();
+        let result = origin.try_normalize(1e-10);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_distance() {
+        let p1: Point3<f64> = Point3::origin();
+        let p2 = Point3::new(3_f64, 4_f64, 0_f64);
+
+        assert_eq!(p1.distance_squared(&p2), 25_f64);
+        assert_eq!(p1.distance(&p2), 5_f64);
+        assert_eq!(p2.distance(&p1), 5_f64);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let p1 = Point3::new(1_f64, -2_f64, 3_f64);
+        let p2 = Point3::new(3_f64, 2_f64, -3_f64);
+        let expected = Point3::new(2_f64, 0_f64, 0_f64);
+        let result = p1.midpoint(&p2);
+
+        assert_eq!(result, expected);
+        assert_eq!(p2.midpoint(&p1), expected);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [
+            Point3::new(1_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 1_f64, 0_f64),
+            Point3::new(0_f64, 0_f64, 1_f64),
+            Point3::new(-1_f64, -1_f64, -1_f64),
+        ];
+        let expected = Some(Point3::origin());
+        let result = Point3::centroid(&points);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_centroid_single_point() {
+        let points = [Point3::new(1_f64, 2_f64, 3_f64)];
+        let expected = Some(points[0]);
+        let result = Point3::centroid(&points);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_centroid_empty() {
+        let points: [Point3<f64>; 0] = [];
+        let result = Point3::centroid(&points);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_is_finite() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let point_infinite = Point3::new(1_f64, f64::NEG_INFINITY, 3_f64);
+
+        assert!(point.is_finite());
+        assert!(!point_infinite.is_finite());
+    }
+
+    #[test]
+    fn test_is_nan() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let point_nan = Point3::new(f64::NAN, 2_f64, 3_f64);
+
+        assert!(!point.is_nan());
+        assert!(point_nan.is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        let expected: Point3<f32> = Point3::origin();
+        let result = Point3::<f32>::default();
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[test]
+    fn test_hermite_endpoints() {
+        let p0 = Point3::new(1_f64, -2_f64, 3_f64);
+        let m0 = Vector3::new(4_f64, 0_f64, -1_f64);
+        let p1 = Point3::new(-5_f64, 6_f64, 7_f64);
+        let m1 = Vector3::new(0_f64, 2_f64, 2_f64);
+
+        assert_eq!(Point3::hermite(&p0, &m0, &p1, &m1, 0_f64), p0);
+        assert_eq!(Point3::hermite(&p0, &m0, &p1, &m1, 1_f64), p1);
+    }
+
+    #[test]
+    fn test_hermite_tangents() {
+        let p0 = Point3::new(1_f64, -2_f64, 3_f64);
+        let m0 = Vector3::new(4_f64, 0_f64, -1_f64);
+        let p1 = Point3::new(-5_f64, 6_f64, 7_f64);
+        let m1 = Vector3::new(0_f64, 2_f64, 2_f64);
+        let h = 1e-6_f64;
+        let velocity0 = (Point3::hermite(&p0, &m0, &p1, &m1, h) - p0) / h;
+        let velocity1 = (p1 - Point3::hermite(&p0, &m0, &p1, &m1, 1_f64 - h)) / h;
+
+        assert!(relative_eq!(velocity0, m0, epsilon = 1e-4));
+        assert!(relative_eq!(velocity1, m1, epsilon = 1e-4));
+    }
+
+    #[test]
+    fn test_catmull_rom_passes_through_control_points() {
+        let p0 = Point3::new(0_f64, 0_f64, 0_f64);
+        let p1 = Point3::new(1_f64, 2_f64, 0_f64);
+        let p2 = Point3::new(3_f64, 3_f64, 1_f64);
+        let p3 = Point3::new(4_f64, 1_f64, 5_f64);
+
+        assert_eq!(Point3::catmull_rom(&p0, &p1, &p2, &p3, 0_f64), p1);
+        assert!(relative_eq!(Point3::catmull_rom(&p0, &p1, &p2, &p3, 1_f64), p2, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_catmull_rom_collinear_points() {
+        let p0 = Point3::new(0_f64, 0_f64, 0_f64);
+        let p1 = Point3::new(1_f64, 1_f64, 1_f64);
+        let p2 = Point3::new(2_f64, 2_f64, 2_f64);
+        let p3 = Point3::new(3_f64, 3_f64, 3_f64);
+        let expected = Point3::new(1.25_f64, 1.25_f64, 1.25_f64);
+        let result = Point3::catmull_rom(&p0, &p1, &p2, &p3, 0.25_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-12));
+    }
+
+
+    #[test]
+    fn test_cmp_lexicographic_differ_in_z() {
+        let point1 = Point3::new(1_f64, 2_f64, 3_f64);
+        let point2 = Point3::new(1_f64, 2_f64, 4_f64);
+
+        assert_eq!(point1.cmp_lexicographic(&point2), Ordering::Less);
+        assert_eq!(point2.cmp_lexicographic(&point1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_lexicographic_x_before_y_before_z() {
+        let point1 = Point3::new(1_f64, 9_f64, 9_f64);
+        let point2 = Point3::new(2_f64, 0_f64, 0_f64);
+        let point3 = Point3::new(2_f64, 1_f64, -5_f64);
+
+        assert_eq!(point1.cmp_lexicographic(&point2), Ordering::Less);
+        assert_eq!(point2.cmp_lexicographic(&point3), Ordering::Less);
+    }
+
+    #[test]
+    fn test_cmp_lexicographic_equal_points() {
+        let point = Point3::new(1_f64, -2_f64, 3_f64);
+
+        assert_eq!(point.cmp_lexicographic(&point), Ordering::Equal);
+        assert_eq!(
+            Point3::new(0_f64, 0_f64, 0_f64).cmp_lexicographic(&Point3::new(-0_f64, 0_f64, 0_f64)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_cmp_lexicographic_nan() {
+        let nan_point = Point3::new(1_f64, f64::NAN, 0_f64);
+        let point = Point3::new(1_f64, f64::INFINITY, 0_f64);
+
+        assert_eq!(nan_point.cmp_lexicographic(&nan_point), Ordering::Equal);
+        assert_eq!(nan_point.cmp_lexicographic(&point), Ordering::Greater);
+        assert_eq!(point.cmp_lexicographic(&nan_point), Ordering::Less);
+    }
+
+    #[test]
+    fn test_cmp_lexicographic_sort_is_stable() {
+        let mut points = [
+            (0, Point3::new(1_f64, 1_f64, 1_f64)),
+            (1, Point3::new(0_f64, 0_f64, 0_f64)),
+            (2, Point3::new(1_f64, 1_f64, 1_f64)),
+            (3, Point3::new(0_f64, 0_f64, 0_f64)),
+        ];
+        points.sort_by(|a, b| a.1.cmp_lexicographic(&b.1));
+        let result = [points[0].0, points[1].0, points[2].0, points[3].0];
+
+        assert_eq!(result, [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_lerp_clamped_overshoot() {
+        let point1 = Point3::new(1_f64, 2_f64, 3_f64);
+        let point2 = Point3::new(-4_f64, 5_f64, 6_f64);
+
+        assert_eq!(point1.lerp_clamped(&point2, 2_f64), point2);
+        assert_eq!(point1.lerp_clamped(&point2, -3_f64), point1);
+    }
+
+    #[test]
+    fn test_from_spherical_positive_x_ray() {
+        let center = Point3::new(-2_f64, 3_f64, 4_f64);
+        let expected = Point3::new(-1_f64, 3_f64, 4_f64);
+        let result = Point3::from_spherical(&center, 1_f64, Radians::full_turn_div_4(), Radians(0_f64));
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_spherical_distance_from_center() {
+        let center = Point3::new(-2_f64, 3_f64, 4_f64);
+        let radius = 5_f64;
+        let result = Point3::from_spherical(&center, radius, Radians(0.3_f64), Radians(2_f64));
+
+        assert!(relative_eq!((result - center).magnitude(), radius, epsilon = 1e-10));
+    }
 }
 
+
+
+#[cfg(test)]
+mod point4_tests {
+    use cglinalg::{
+        Point3,
+        Point4,
+        Vector4,
+        Magnitude,   
+    };
+
+
+    #[test]
+    fn test_components1() {
+        let point = Point4::new(1_i32, 2_i32, 3_i32, 4_i32);
+
+        assert_eq!(point[0], 1_i32);
+        assert_eq!(point[1], 2_i32);
+        assert_eq!(point[2], 3_i32);
+        assert_eq!(point[3], 4_i32);
+    }
+
+    #[test]
+    fn test_components2() {
+        let point = Point4::new(1_i32, 2_i32, 3_i32, 4_i32);
+
+        assert_eq!(point.x, point[0]);
+        assert_eq!(point.y, point[1]);
+        assert_eq!(point.z, point[2]);
+        assert_eq!(point.w, point[3]);
+    }
+
+    #[test]
+    fn test_addition() {
+        let p = Point4::new(27.6189, 13.90, 4.2219, 1.0);
+        let v = Vector4::new(258.083, 31.70, 42.17, 8.0);
+        let expected = Point4::new(p.x + v.x, p.y + v.y, p.z + v.z, p.w + v.w);
+        let result = p + v;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_subtraction_point_vector() {
+        let p = Point4::new(70.0,  49.0,  95.0, 1.0);
+        let v = Vector4::new(89.9138, 36.84, 427.46894, 8.0);
+        let expected = Point4::new(p.x - v.x, p.y - v.y, p.z - v.z, p.w - v.w);
+        let result = p - v;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_subtraction_point_point() {
+        let p1 = Point4::new(8827.1983, 89.5049494, 56.31, 1.0);
+        let p2 = Point4::new(89.0, 72.0, 936.5, 8.0);
+        let expected = Vector4::new(p1.x - p2.x, p1.y - p2.y, p1.z - p2.z, p1.w - p2.w);
+        let result = p1 - p2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let c = 7.04217;
+        let p = Point4::new(70.0,  49.0,  95.0, 1.0);
+        let expected = Point4::new(p.x * c, p.y * c, p.z * c, p.w * c);
+        let result = p * c;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let c = 802.3435169;
+        let p = Point4::new(80.0,  23.43, 43.569, 1.0);
+        let expected = Point4::new(p.x / c, p.y / c, p.z / c, p.w / c);
+        let result = p / c;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let p = Point4::new(1_f32, 2_f32, 3_f32, 4_f32);
+
+        assert_eq!(p[4], p[4]);
+    }
+
+    #[test]
+    fn test_point_times_zero_equals_zero() {
+        let p = Point4::new(1_f32, 2_f32, 3_f32, 4_f32);
+
+        assert_eq!(p * 0_f32, Point4::origin());
+    }
+
+    #[test]
+    fn test_zero_times_point_equals_zero() {
+        let p = Point4::new(1_f32, 2_f32, 3_f32, 4_f32);
+
+        assert_eq!(0_f32 * p, Point4::origin());
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let p: Point4<i32> = Point4::new(1, 2, 3, 4);
+        let p_ref: &[i32; 4] = p.as_ref();
+
+        assert_eq!(p_ref, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut p: Point4<i32> = Point4::new(1, 2, 3, 4);
+        let p_ref: &mut [i32; 4] = p.as_mut();
+        p_ref[3] = 5;
+
+        assert_eq!(p.w, 5);
+    }
+
+    #[test]
+    fn test_zero_point_zero_magnitude() {
+        let zero: Point4<f32> = Point4::origin();
+
+        assert_eq!(zero.magnitude(), 0_f32);
+    }
+
+    #[test]
+    fn test_distance() {
+        let p1: Point4<f64> = Point4::origin();
+        let p2 = Point4::new(1_f64, 1_f64, 1_f64, 1_f64);
+
+        assert_eq!(p1.distance(&p2), 2_f64);
+    }
+
+    #[test]
+    fn test_extend_contract() {
+        let p = Point3::new(1_i32, 2_i32, 3_i32);
+        let expected = Point4::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let result = p.extend(4_i32);
+
+        assert_eq!(result, expected);
+        assert_eq!(result.contract(), p);
+    }
+
+    #[test]
+    fn test_cast() {
+        let p: Point4<u32> = Point4::new(1_u32, 2_u32, 3_u32, 4_u32);
+        let expected: Option<Point4<f64>> = Some(Point4::new(1_f64, 2_f64, 3_f64, 4_f64));
+        let result = p.cast::<f64>();
+
+        assert_eq!(result, expected);
+    }
+}
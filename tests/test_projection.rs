@@ -11,7 +11,10 @@ use cglinalg::{
     Degrees,
     Radians,
     Point3,
+    Vector2,
     Vector3,
+    Vector4,
+    Magnitude,
 };
 use approx::{
     relative_eq,
@@ -289,3 +292,413 @@ fn test_orthographic_fov_projection_unproject_vector() {
     assert_eq!(result, expected);
 }
 
+
+#[test]
+fn test_perspective_projection_frustum_corners() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 3.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let scale = far / near;
+    let expected = [
+        Point3::new(left,  bottom, -near),
+        Point3::new(right, bottom, -near),
+        Point3::new(right, top,    -near),
+        Point3::new(left,  top,    -near),
+        Point3::new(left  * scale, bottom * scale, -far),
+        Point3::new(right * scale, bottom * scale, -far),
+        Point3::new(right * scale, top    * scale, -far),
+        Point3::new(left  * scale, top    * scale, -far),
+    ];
+    let result = projection.frustum_corners();
+
+    for (result_i, expected_i) in result.iter().zip(expected.iter()) {
+        assert!(relative_eq!(result_i, expected_i, epsilon = 1e-8));
+    }
+}
+
+#[test]
+fn test_orthographic_projection_frustum_corners() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 2.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let expected = [
+        Point3::new(left,  bottom, -near),
+        Point3::new(right, bottom, -near),
+        Point3::new(right, top,    -near),
+        Point3::new(left,  top,    -near),
+        Point3::new(left,  bottom, -far),
+        Point3::new(right, bottom, -far),
+        Point3::new(right, top,    -far),
+        Point3::new(left,  top,    -far),
+    ];
+    let result = projection.frustum_corners();
+
+    for (result_i, expected_i) in result.iter().zip(expected.iter()) {
+        assert!(relative_eq!(result_i, expected_i, epsilon = 1e-8));
+    }
+}
+
+#[test]
+fn test_perspective_projection_frustum_planes_contain_interior_point() {
+    let vfov = Degrees(72.0);
+    let aspect = 800.0 / 600.0;
+    let near = 0.1;
+    let far = 100.0;
+    let projection = PerspectiveFov3::new(vfov, aspect, near, far);
+    let point = Vector4::new(1.0, -1.0, -20.0, 1.0);
+    let planes = projection.frustum_planes();
+
+    for plane in planes.iter() {
+        assert!(plane.dot(&point) >= 0.0);
+    }
+}
+
+#[test]
+fn test_perspective_projection_frustum_planes_reject_exterior_point() {
+    let vfov = Degrees(72.0);
+    let aspect = 800.0 / 600.0;
+    let near = 0.1;
+    let far = 100.0;
+    let projection = PerspectiveFov3::new(vfov, aspect, near, far);
+    let point = Vector4::new(0.0, 0.0, -200.0, 1.0);
+    let planes = projection.frustum_planes();
+
+    assert!(planes[5].dot(&point) < 0.0);
+}
+
+#[test]
+fn test_perspective_projection_frustum_planes_are_normalized() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 3.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let planes = projection.frustum_planes();
+
+    for plane in planes.iter() {
+        assert!(relative_eq!(plane.contract().magnitude(), 1.0, epsilon = 1e-10));
+    }
+}
+
+#[test]
+fn test_perspective_projection_frustum_near_far_plane_distances() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 3.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let planes = projection.frustum_planes();
+    let near_point = Vector4::new(0.0, 0.0, -near, 1.0);
+    let far_point = Vector4::new(0.0, 0.0, -far, 1.0);
+
+    assert!(relative_eq!(planes[4].dot(&near_point), 0.0, epsilon = 1e-10));
+    assert!(relative_eq!(planes[5].dot(&far_point), 0.0, epsilon = 1e-10));
+}
+
+#[test]
+fn test_orthographic_projection_frustum_planes_contain_interior_point() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 2.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let point = Vector4::new(3.0, 1.0, -50.0, 1.0);
+    let planes = projection.frustum_planes();
+
+    for plane in planes.iter() {
+        assert!(plane.dot(&point) >= 0.0);
+    }
+}
+
+#[test]
+fn test_perspective_fov_projection_with_aspect() {
+    let vfov = Degrees(72.0);
+    let near = 0.1;
+    let far = 100.0;
+    let projection = PerspectiveFov3::new(vfov, 800.0 / 600.0, near, far);
+    let result = projection.with_aspect(2.0);
+
+    assert_eq!(result.vfov(), projection.vfov());
+    assert_eq!(result.near_z(), near);
+    assert_eq!(result.far_z(), far);
+    assert_eq!(result.aspect(), 2.0);
+    assert!(relative_eq!(result.matrix().c0r0, result.matrix().c1r1 / 2.0, epsilon = 1e-10));
+    assert_eq!(result.matrix().c1r1, projection.matrix().c1r1);
+}
+
+#[test]
+fn test_orthographic_fov_projection_with_aspect() {
+    let vfov = Degrees(90.0);
+    let near = 1.0;
+    let far = 100.0;
+    let projection = OrthographicFov3::new(vfov, 800.0 / 600.0, near, far);
+    let result = projection.with_aspect(2.0);
+
+    assert_eq!(result.vfov(), projection.vfov());
+    assert_eq!(result.near_z(), near);
+    assert_eq!(result.far_z(), far);
+    assert_eq!(result.aspect(), 2.0);
+    assert_ne!(result.matrix().c1r1, projection.matrix().c1r1);
+    assert_eq!(result.matrix().c0r0, projection.matrix().c0r0);
+}
+
+#[test]
+fn test_perspective_projection_screen_to_world_center_pixel() {
+    let vfov = Degrees(72.0);
+    let aspect = 800.0 / 600.0;
+    let near = 0.1;
+    let far = 100.0;
+    let projection = PerspectiveFov3::new(vfov, aspect, near, far);
+    let viewport = (0.0, 0.0, 800.0, 600.0);
+    let screen = Point3::new(400.0, 300.0, 0.0);
+    let result = projection.screen_to_world(&screen, viewport);
+
+    assert!(relative_eq!(result.x, 0.0, epsilon = 1e-10));
+    assert!(relative_eq!(result.y, 0.0, epsilon = 1e-10));
+    assert!(result.z < -near);
+    assert!(result.z > -far);
+}
+
+#[test]
+fn test_perspective_projection_screen_to_world_viewport_corner() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 3.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let viewport = (100.0, 50.0, 800.0, 600.0);
+    let screen = Point3::new(100.0, 50.0, -1.0);
+    let expected = Point3::new(left, bottom, -near);
+    let result = projection.screen_to_world(&screen, viewport);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_orthographic_projection_screen_to_world_center_pixel() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 2.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let viewport = (0.0, 0.0, 800.0, 600.0);
+    let screen = Point3::new(400.0, 300.0, 0.0);
+    let expected = Point3::new(0.0, 0.0, -(near + far) / 2.0);
+    let result = projection.screen_to_world(&screen, viewport);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_projection_accessors() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 3.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+
+    assert_eq!(projection.left_x(), left);
+    assert_eq!(projection.right_x(), right);
+    assert_eq!(projection.bottom_y(), bottom);
+    assert_eq!(projection.top_y(), top);
+    assert_eq!(projection.near_z(), near);
+    assert_eq!(projection.far_z(), far);
+}
+
+#[test]
+fn test_perspective_fov_projection_accessors() {
+    let vfov = Degrees(72.0);
+    let aspect = 800.0 / 600.0;
+    let near = 0.1;
+    let far = 100.0;
+    let projection = PerspectiveFov3::new(vfov, aspect, near, far);
+
+    assert_eq!(projection.vfov(), Radians::from(vfov));
+    assert_eq!(projection.aspect(), aspect);
+    assert_eq!(projection.near_z(), near);
+    assert_eq!(projection.far_z(), far);
+}
+
+#[test]
+fn test_orthographic_projection_accessors() {
+    let left = -4.0;
+    let right = 4.0;
+    let bottom = -2.0;
+    let top = 2.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+
+    assert_eq!(projection.left_x(), left);
+    assert_eq!(projection.right_x(), right);
+    assert_eq!(projection.bottom_y(), bottom);
+    assert_eq!(projection.top_y(), top);
+    assert_eq!(projection.near_z(), near);
+    assert_eq!(projection.far_z(), far);
+}
+
+#[test]
+fn test_orthographic_fov_projection_accessors() {
+    let vfov = Degrees(90.0);
+    let aspect = 800.0 / 600.0;
+    let near = 1.0;
+    let far = 100.0;
+    let projection = OrthographicFov3::new(vfov, aspect, near, far);
+
+    assert_eq!(projection.vfov(), Radians::from(vfov));
+    assert_eq!(projection.aspect(), aspect);
+    assert_eq!(projection.near_z(), near);
+    assert_eq!(projection.far_z(), far);
+}
+
+#[test]
+fn test_perspective_relative_eq() {
+    let perspective1 = Perspective3::new(-4_f64, 4_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+    let perspective2 = Perspective3::new(-4_f64, 4_f64, -2_f64, 3_f64 + 1e-12_f64, 1_f64, 100_f64);
+
+    assert_ne!(perspective1, perspective2);
+    assert!(relative_eq!(perspective1, perspective2, epsilon = 1e-10));
+}
+
+#[test]
+fn test_orthographic_relative_eq() {
+    let orthographic1 = Orthographic3::new(-4_f64, 4_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+    let orthographic2 = Orthographic3::new(-4_f64, 4_f64 - 1e-12_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+
+    assert_ne!(orthographic1, orthographic2);
+    assert!(relative_eq!(orthographic1, orthographic2, epsilon = 1e-10));
+}
+
+
+#[test]
+fn test_perspective_clip_to_plane_depth() {
+    let perspective = Perspective3::new(-2_f64, 2_f64, -1_f64, 1_f64, 1_f64, 100_f64);
+    // A tilted plane through the point (0, 0, -10) with the camera behind it.
+    let normal = Vector3::new(0_f64, 1_f64, -2_f64).normalize();
+    let point_on_plane = Vector3::new(0_f64, 0_f64, -10_f64);
+    let plane = normal.extend(-normal.dot(&point_on_plane));
+    let matrix = perspective.clip_to_plane(&plane);
+    let in_front = Vector4::new(0.5_f64, 0.5_f64, -20_f64, 1_f64);
+    let behind = Vector4::new(0.5_f64, 0.5_f64, -5_f64, 1_f64);
+    let clip_in_front = matrix * in_front;
+    let clip_behind = matrix * behind;
+
+    assert!(plane.dot(&in_front) > 0_f64);
+    assert!(plane.dot(&behind) < 0_f64);
+    assert!(clip_in_front.z / clip_in_front.w > -1_f64);
+    assert!(clip_behind.z / clip_behind.w < -1_f64);
+}
+
+#[test]
+fn test_perspective_clip_to_plane_near_plane() {
+    let perspective = Perspective3::new(-2_f64, 2_f64, -1_f64, 1_f64, 1_f64, 100_f64);
+    let normal = Vector3::new(1_f64, 1_f64, -4_f64).normalize();
+    let point_on_plane = Vector3::new(0_f64, 0_f64, -10_f64);
+    let plane = normal.extend(-normal.dot(&point_on_plane));
+    let matrix = perspective.clip_to_plane(&plane);
+    let on_plane = (point_on_plane + Vector3::new(1_f64, 3_f64, 1_f64)).extend(1_f64);
+    let clip = matrix * on_plane;
+
+    assert!(relative_eq!(plane.dot(&on_plane), 0_f64, epsilon = 1e-10));
+    assert!(relative_eq!(clip.z / clip.w, -1_f64, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_clip_to_plane_preserves_other_rows() {
+    let perspective = Perspective3::new(-2_f64, 2_f64, -1_f64, 1_f64, 1_f64, 100_f64);
+    let plane = Vector4::new(0_f64, 0.6_f64, -0.8_f64, -3_f64);
+    let original = perspective.matrix();
+    let result = perspective.clip_to_plane(&plane);
+
+    assert_eq!(result.row(0), original.row(0));
+    assert_eq!(result.row(1), original.row(1));
+    assert_eq!(result.row(3), original.row(3));
+    assert_ne!(result.row(2), original.row(2));
+}
+
+#[test]
+fn test_orthographic_from_center_size_symmetric() {
+    let center = Point3::new(3_f64, -2_f64, -25_f64);
+    let width = 10_f64;
+    let height = 4_f64;
+    let depth = 30_f64;
+    let orthographic = Orthographic3::from_center_size(center, width, height, depth);
+
+    assert_eq!(orthographic.left_x() + orthographic.right_x(), 2_f64 * center.x);
+    assert_eq!(orthographic.bottom_y() + orthographic.top_y(), 2_f64 * center.y);
+    assert_eq!(orthographic.near_z() + orthographic.far_z(), -2_f64 * center.z);
+    assert_eq!(orthographic.right_x() - orthographic.left_x(), width);
+    assert_eq!(orthographic.top_y() - orthographic.bottom_y(), height);
+    assert_eq!(orthographic.far_z() - orthographic.near_z(), depth);
+}
+
+#[test]
+fn test_orthographic_from_center_size_projects_center_to_origin() {
+    let center = Point3::new(3_f64, -2_f64, -25_f64);
+    let orthographic = Orthographic3::from_center_size(center, 10_f64, 4_f64, 30_f64);
+    let expected = Point3::origin();
+    let result = orthographic.project_point(&center);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_with_zero_jitter() {
+    let perspective = Perspective3::new(-4_f64, 4_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+    let result = perspective.with_jitter(Vector2::zero());
+
+    assert_eq!(result, perspective);
+    assert_eq!(result.matrix(), perspective.matrix());
+}
+
+#[test]
+fn test_perspective_with_jitter_shifts_projected_points() {
+    let perspective = Perspective3::new(-4_f64, 4_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+    let width = 1920_f64;
+    let height = 1080_f64;
+    let offset = Vector2::new(2_f64 * 0.25_f64 / width, 2_f64 * -0.5_f64 / height);
+    let jittered = perspective.with_jitter(offset);
+    let points = [
+        Point3::new(0_f64, 0_f64, -1_f64),
+        Point3::new(1_f64, -2_f64, -10_f64),
+        Point3::new(-30_f64, 20_f64, -90_f64),
+    ];
+    for point in points.iter() {
+        let projected = perspective.project_point(point);
+        let expected = Point3::new(projected.x + offset.x, projected.y + offset.y, projected.z);
+        let result = jittered.project_point(point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+}
+
+#[test]
+fn test_perspective_project_point_off_center() {
+    let perspective = Perspective3::new(-4_f64, 4_f64, -2_f64, 3_f64, 1_f64, 100_f64);
+    let point = Point3::new(1_f64, -2_f64, -10_f64);
+    let expected = Point3::from_homogeneous(perspective.matrix() * point.to_homogeneous()).unwrap();
+    let result = perspective.project_point(&point);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    assert!(relative_eq!(perspective.unproject_point(&result), point, epsilon = 1e-10));
+}
@@ -0,0 +1,78 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod ray3_tests {
+    use cglinalg::{
+        Degrees,
+        Magnitude,
+        Matrix4x4,
+        Point3,
+        Ray3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_point_at_origin() {
+        let origin = Point3::new(1_f64, -2_f64, 3_f64);
+        let direction = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let ray = Ray3::new(origin, direction);
+
+        assert_eq!(ray.point_at(0_f64), origin);
+    }
+
+    #[test]
+    fn test_point_at_advances_along_direction() {
+        let origin = Point3::new(1_f64, -2_f64, 3_f64);
+        let direction = Unit::from_value(Vector3::new(3_f64, 0_f64, 4_f64));
+        let ray = Ray3::new(origin, direction);
+        let expected = Point3::new(7_f64, -2_f64, 11_f64);
+        let result = ray.point_at(10_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        assert!(relative_eq!((result - origin).magnitude(), 10_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_rotation() {
+        let origin = Point3::new(1_f64, 0_f64, 0_f64);
+        let direction = Unit::from_value(Vector3::unit_x());
+        let ray = Ray3::new(origin, direction);
+        let matrix = Matrix4x4::from_affine_angle_z(Degrees(90_f64));
+        let result = ray.transform(&matrix);
+
+        assert!(relative_eq!(result.origin, Point3::new(0_f64, 1_f64, 0_f64), epsilon = 1e-10));
+        assert!(relative_eq!(result.direction.into_inner(), Vector3::unit_y(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_translation_preserves_direction() {
+        let origin = Point3::new(1_f64, 2_f64, 3_f64);
+        let direction = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+        let ray = Ray3::new(origin, direction);
+        let distance = Vector3::new(-4_f64, 5_f64, 6_f64);
+        let matrix = Matrix4x4::from_affine_translation(&distance);
+        let result = ray.transform(&matrix);
+
+        assert_eq!(result.origin, origin + distance);
+        assert!(relative_eq!(
+            result.direction.into_inner(), direction.into_inner(), epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_transform_scale_renormalizes_direction() {
+        let ray = Ray3::new(Point3::new(1_f64, 1_f64, 1_f64), Unit::from_value(Vector3::unit_y()));
+        let matrix = Matrix4x4::from_affine_scale(3_f64);
+        let result = ray.transform(&matrix);
+
+        assert_eq!(result.origin, Point3::new(3_f64, 3_f64, 3_f64));
+        assert!(relative_eq!(result.direction.into_inner(), Vector3::unit_y(), epsilon = 1e-10));
+    }
+}
+
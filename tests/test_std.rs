@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+extern crate cglinalg;
+
+
+use cglinalg::{
+    Matrix2x2,
+    Matrix3x4,
+};
+
+
+#[test]
+fn test_matrix2x2_to_string_precision() {
+    let matrix: Matrix2x2<f64> = Matrix2x2::identity();
+    let result = matrix.to_string_precision(2);
+
+    assert!(result.contains("1.00"));
+    assert!(result.contains("0.00"));
+    assert!(!result.contains("1.000"));
+}
+
+#[test]
+fn test_matrix2x2_to_string_precision_rounds() {
+    let matrix = Matrix2x2::new(1_f64 / 3_f64, 2_f64 / 3_f64, 1_f64, 0_f64);
+    let result = matrix.to_string_precision(3);
+
+    assert!(result.contains("0.333"));
+    assert!(result.contains("0.667"));
+    assert!(!result.contains("0.3333"));
+}
+
+#[test]
+fn test_matrix3x4_to_string_precision_matches_display() {
+    let matrix = Matrix3x4::new(
+        1.5_f32, 2.25_f32, -3_f32,
+        4_f32,   5_f32,     6.125_f32,
+        7_f32,  -8_f32,     9_f32,
+        10_f32,  11_f32,    12_f32,
+    );
+    let expected = format!("{:.1}", matrix);
+    let result = matrix.to_string_precision(1);
+
+    assert_eq!(result, expected);
+}
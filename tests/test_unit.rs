@@ -7,6 +7,11 @@ use cglinalg::{
     Unit,
     Vector3,
 };
+use approx::{
+    relative_eq,
+    relative_ne,
+    ulps_eq,
+};
 
 
 /// Pass a unit vector into a `Unit` constructor should not affect the unit vector.
@@ -54,3 +59,68 @@ fn test_vector_close_to_zero() {
     assert_eq!(result, expected);
 }
 
+
+/// The `try_from_value` function should reject the zero vector instead of 
+/// producing a unit vector full of `NaN` values.
+#[test]
+fn test_zero_vector_try_from_value() {
+    let vector: Vector3<f64> = Vector3::zero();
+    let result = Unit::try_from_value(vector, f64::EPSILON);
+
+    assert!(result.is_none());
+}
+
+/// The `try_from_value` function should normalize a vector whose magnitude
+/// exceeds the threshold.
+#[test]
+fn test_nonzero_vector_try_from_value() {
+    let vector = Vector3::new(3_f64, 0_f64, 4_f64);
+    let result = Unit::try_from_value(vector, f64::EPSILON).unwrap();
+
+    assert_eq!(result.into_inner(), Vector3::new(0.6_f64, 0_f64, 0.8_f64));
+    assert_eq!(result.magnitude(), 1_f64);
+}
+
+/// Unwrapping a unit vector should return the normalized vector.
+#[test]
+fn test_into_inner() {
+    let vector = Vector3::new(0_f64, 5_f64, 0_f64);
+    let expected = Vector3::unit_y();
+    let result = Unit::from_value(vector).into_inner();
+
+    assert_eq!(result, expected);
+}
+
+/// The fields of the underlying value should be accessible through a unit 
+/// value without unwrapping it.
+#[test]
+fn test_deref_field_access() {
+    let unit = Unit::from_value(Vector3::new(0_f64, 0_f64, 2_f64));
+
+    assert_eq!(unit.x, 0_f64);
+    assert_eq!(unit.y, 0_f64);
+    assert_eq!(unit.z, 1_f64);
+    assert_eq!(unit.as_ref(), &Vector3::unit_z());
+}
+
+/// Unit values that are nearly equal should compare as approximately equal.
+#[test]
+fn test_unit_relative_eq() {
+    let unit1 = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    let unit2 = Unit::from_value(Vector3::new(1_f64, 1_f64 + 1e-12_f64, 0_f64));
+
+    assert_ne!(unit1, unit2);
+    assert!(relative_eq!(unit1, unit2, epsilon = 1e-10));
+    assert!(ulps_eq!(unit1, unit2, epsilon = 1e-10));
+}
+
+/// Unit values pointing in different directions should not compare as 
+/// approximately equal.
+#[test]
+fn test_unit_relative_ne() {
+    let unit1 = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    let unit2 = Unit::from_value(Vector3::new(1_f64, 0_f64, 1_f64));
+
+    assert!(relative_ne!(unit1, unit2, epsilon = 1e-10));
+}
+
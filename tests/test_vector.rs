@@ -198,6 +198,15 @@ mod vector1_tests {
         assert_eq!(unit_x.magnitude_squared(), 1.0);
         assert_eq!(unit_x.magnitude(), 1.0);
     }
+
+    #[test]
+    fn test_array_round_trip() {
+        let array = [1_i32];
+        let vector = Vector1::from(array);
+        let result: [i32; 1] = vector.into();
+
+        assert_eq!(result, array);
+    }
 }
 
 
@@ -205,8 +214,14 @@ mod vector1_tests {
 mod vector2_tests {
     use cglinalg::{
         Vector2,
-        Magnitude,   
+        Magnitude,
+        Matrix2x2,
+        Radians,
+        Degrees,
+        Unit,
     };
+    use approx::relative_eq;
+    use core::f64;
     use core::slice::Iter;
 
 
@@ -412,15 +427,339 @@ mod vector2_tests {
         assert_eq!(unit_y.magnitude_squared(), 1.0);
         assert_eq!(unit_y.magnitude(), 1.0);
     }
+
+    #[test]
+    fn test_perp_dot_perpendicular_vectors() {
+        let vector1 = Vector2::new(3_f64, 0_f64);
+        let vector2 = Vector2::new(0_f64, 4_f64);
+        let expected = vector1.magnitude() * vector2.magnitude();
+        let result = vector1.perp_dot(&vector2);
+
+        assert_eq!(result, expected);
+        assert_eq!(vector2.perp_dot(&vector1), -expected);
+    }
+
+    #[test]
+    fn test_perp_unit_x() {
+        let unit_x: Vector2<f64> = Vector2::unit_x();
+        let unit_y: Vector2<f64> = Vector2::unit_y();
+
+        assert_eq!(unit_x.perp(), unit_y);
+        assert_eq!(unit_y.perp(), -unit_x);
+    }
+
+    #[test]
+    fn test_reflect() {
+        let vector = Vector2::new(1_f64, -1_f64);
+        let normal: Unit<Vector2<f64>> = Unit::from_value(Vector2::unit_y());
+        let expected = Vector2::new(1_f64, 1_f64);
+        let result = vector.reflect(&normal);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_project_onto_axis() {
+        let vector = Vector2::new(2_f64, 3_f64);
+        let unit_x: Vector2<f64> = Vector2::unit_x();
+        let unit_y: Vector2<f64> = Vector2::unit_y();
+
+        assert_eq!(vector.project_onto(&unit_x), Vector2::new(2_f64, 0_f64));
+        assert_eq!(vector.project_onto(&unit_y), Vector2::new(0_f64, 3_f64));
+    }
+
+    #[test]
+    fn test_reject_from_axis() {
+        let vector = Vector2::new(2_f64, 3_f64);
+        let unit_x: Vector2<f64> = Vector2::unit_x();
+        let unit_y: Vector2<f64> = Vector2::unit_y();
+
+        assert_eq!(vector.reject_from(&unit_x), Vector2::new(0_f64, 3_f64));
+        assert_eq!(vector.reject_from(&unit_y), Vector2::new(2_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_project_plus_reject_equals_vector() {
+        test_cases().iter().for_each(|test| {
+            let result = test.v1.project_onto(&test.v2) + test.v1.reject_from(&test.v2);
+
+            assert!(relative_eq!(result, test.v1, epsilon = 1e-10));
+        });
+    }
+
+    #[test]
+    fn test_angle_signed_counterclockwise() {
+        let unit_x: Vector2<f64> = Vector2::unit_x();
+        let unit_y: Vector2<f64> = Vector2::unit_y();
+        let expected = Radians(f64::consts::FRAC_PI_2);
+        let result = unit_x.angle_signed(&unit_y);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_angle_signed_clockwise() {
+        let unit_x: Vector2<f64> = Vector2::unit_x();
+        let unit_y: Vector2<f64> = Vector2::unit_y();
+        let expected = Radians(-f64::consts::FRAC_PI_2);
+        let result = unit_x.angle_signed(&(-unit_y));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_min() {
+        let vector1 = Vector2::new(1_f64, -2_f64);
+        let vector2 = Vector2::new(-3_f64, 4_f64);
+        let expected = Vector2::new(-3_f64, -2_f64);
+        let result = vector1.component_min(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_max() {
+        let vector1 = Vector2::new(1_f64, -2_f64);
+        let vector2 = Vector2::new(-3_f64, 4_f64);
+        let expected = Vector2::new(1_f64, 4_f64);
+        let result = vector1.component_max(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let vector = Vector2::new(-5_f64, 0.5_f64);
+        let min = Vector2::new(-1_f64, -1_f64);
+        let max = Vector2::new(1_f64, 1_f64);
+        let expected = Vector2::new(-1_f64, 0.5_f64);
+        let result = vector.clamp(&min, &max);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_floor() {
+        let vector = Vector2::new(1.25_f64, -1.75_f64);
+        let expected = Vector2::new(1_f64, -2_f64);
+        let result = vector.floor();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ceil() {
+        let vector = Vector2::new(1.25_f64, -1.75_f64);
+        let expected = Vector2::new(2_f64, -1_f64);
+        let result = vector.ceil();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_round() {
+        let vector = Vector2::new(1.25_f64, -1.75_f64);
+        let expected = Vector2::new(1_f64, -2_f64);
+        let result = vector.round();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp_magnitude_long_vector() {
+        let vector = Vector2::new(6_f64, 8_f64);
+        let result = vector.clamp_magnitude(5_f64);
+
+        assert!(relative_eq!(result.magnitude(), 5_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.normalize(), vector.normalize(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_clamp_magnitude_short_vector() {
+        let vector = Vector2::new(0.6_f64, 0.8_f64);
+        let result = vector.clamp_magnitude(5_f64);
+
+        assert_eq!(result, vector);
+    }
+
+    #[test]
+    fn test_clamp_magnitude_zero_vector() {
+        let zero: Vector2<f64> = Vector2::zero();
+        let result = zero.clamp_magnitude(5_f64);
+
+        assert_eq!(result, zero);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let array = [1_i32, 2_i32];
+        let vector = Vector2::from(array);
+        let result: [i32; 2] = vector.into();
+
+        assert_eq!(result, array);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let vector = Vector2::new(4_f64, 9_f64);
+        let expected = Vector2::new(2_f64, 3_f64);
+        let result = vector.sqrt();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_recip() {
+        let vector = Vector2::new(0.25_f64, 0.5_f64);
+        let expected = Vector2::new(4_f64, 2_f64);
+        let result = vector.recip();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powf() {
+        let vector = Vector2::new(0.25_f64, 1_f64);
+        let expected = Vector2::new(0.5_f64, 1_f64);
+        let result = vector.powf(0.5_f64);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_step() {
+        let edge = Vector2::new(0.5_f64, 0.5_f64);
+        let x = Vector2::new(0.25_f64, 0.5_f64);
+        let expected = Vector2::new(0_f64, 1_f64);
+        let result = Vector2::step(&edge, &x);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let edge0 = Vector2::new(1_f64, 1_f64);
+        let edge1 = Vector2::new(3_f64, 3_f64);
+        let x = Vector2::new(1.5_f64, 2.5_f64);
+        let expected = Vector2::new(0.15625_f64, 0.84375_f64);
+        let result = Vector2::smoothstep(&edge0, &edge1, &x);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_mix() {
+        let v0 = Vector2::new(1_f64, 2_f64);
+        let v1 = Vector2::new(3_f64, 6_f64);
+        let expected = Vector2::new(1.5_f64, 3_f64);
+        let result = v0.mix(&v1, 0.25_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_signum_copysign() {
+        let vector = Vector2::new(-4_f64, 9_f64);
+        let sign = Vector2::new(3_f64, -0.1_f64);
+        let expected = Vector2::new(1_f64, -1_f64);
+        let result = vector.copysign(&sign).signum();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_l1_norm_linf_norm() {
+        let vector = Vector2::new(-3_f64, 4_f64);
+
+        assert_eq!(vector.l1_norm(), 7_f64);
+        assert_eq!(vector.linf_norm(), 4_f64);
+    }
+
+
+    #[test]
+    fn test_sum() {
+        let vectors = [
+            Vector2::new(1_f32, 2_f32),
+            Vector2::new(3_f32, -4_f32),
+            Vector2::new(-5_f32, 6_f32),
+        ];
+        let expected = Vector2::new(-1_f32, 4_f32);
+        let result: Vector2<f32> = vectors.iter().copied().sum();
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[test]
+    fn test_cmp() {
+        let vector1 = Vector2::new(1_f64, 5_f64);
+        let vector2 = Vector2::new(5_f64, 5_f64);
+
+        assert_eq!(vector1.cmp_lt(&vector2), [true, false]);
+        assert_eq!(vector1.cmp_gt(&vector2), [false, false]);
+        assert_eq!(vector1.cmp_eq(&vector2), [false, true]);
+    }
+
+
+    #[test]
+    fn test_select() {
+        let if_true = Vector2::new(1_i32, 2_i32);
+        let if_false = Vector2::new(3_i32, 4_i32);
+
+        assert_eq!(Vector2::select([false, true], &if_true, &if_false), Vector2::new(3_i32, 2_i32));
+    }
+
+    #[test]
+    fn test_dot() {
+        let vector1 = Vector2::new(1_f64, -2_f64);
+        let vector2 = Vector2::new(3_f64, 4_f64);
+        let expected = vector1.x * vector2.x + vector1.y * vector2.y;
+
+        assert_eq!(vector1.dot(&vector2), expected);
+        assert_eq!(vector1.dot(&vector2), vector2.dot(&vector1));
+    }
+
+    #[test]
+    fn test_distance() {
+        let vector1 = Vector2::new(1_f64, 1_f64);
+        let vector2 = Vector2::new(4_f64, 5_f64);
+
+        assert_eq!(vector1.distance(&vector2), 5_f64);
+        assert_eq!(vector1.distance_squared(&vector2), 25_f64);
+    }
+
+    #[test]
+    fn test_rotate_unit_x_quarter_turn() {
+        let vector: Vector2<f64> = Vector2::unit_x();
+        let expected = Vector2::unit_y();
+        let result = vector.rotate(Radians(f64::consts::FRAC_PI_2));
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotate_matches_rotation_matrix() {
+        let vector = Vector2::new(3_f64, -4_f64);
+        let angle = Degrees(72_f64);
+        let expected = Matrix2x2::from_angle(angle) * vector;
+        let result = vector.rotate(angle);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        assert!(relative_eq!(result.magnitude(), vector.magnitude(), epsilon = 1e-10));
+    }
 }
 
 
 #[cfg(test)]
 mod vector3_tests {
     use cglinalg::{
+        ScalarSigned,
         Vector3,
         Magnitude,
+        Unit,
+        Radians,
     };
+    use approx::relative_eq;
     use core::slice::Iter;
 
 
@@ -658,94 +997,937 @@ mod vector3_tests {
         assert_eq!(unit_z.magnitude_squared(), 1.0);
         assert_eq!(unit_z.magnitude(), 1.0);
     }
-}
-
 
-#[cfg(test)]
-mod vector4_tests {
-    use cglinalg::{
-        Vector4,
-        Magnitude,
-    };
-    use core::slice::Iter;
+    #[test]
+    fn test_cross_product_unit_vectors() {
+        let unit_x: Vector3<f64> = Vector3::unit_x();
+        let unit_y: Vector3<f64> = Vector3::unit_y();
+        let unit_z: Vector3<f64> = Vector3::unit_z();
 
-    
-    struct TestCase {
-        c: f32,
-        v1: Vector4<f32>,
-        v2: Vector4<f32>,
+        assert_eq!(unit_x.cross(&unit_y), unit_z);
+        assert_eq!(unit_y.cross(&unit_z), unit_x);
+        assert_eq!(unit_z.cross(&unit_x), unit_y);
     }
 
-    struct Test {
-        tests: Vec<TestCase>,
-    }
+    #[test]
+    fn test_cross_product_anticommutative() {
+        test_cases().iter().for_each(|test| {
+            let expected = -(test.y.cross(&test.x));
+            let result = test.x.cross(&test.y);
 
-    impl Test {
-        fn iter(&self) -> TestIter {
-            TestIter {
-                inner: self.tests.iter()
-            }
-        }
+            assert_eq!(result, expected);
+        });
     }
 
-    struct TestIter<'a> {
-        inner: Iter<'a, TestCase>,
+    #[test]
+    fn test_reflect() {
+        let vector = Vector3::new(1_f64, -1_f64, 0_f64);
+        let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+        let expected = Vector3::new(1_f64, 1_f64, 0_f64);
+        let result = vector.reflect(&normal);
+
+        assert_eq!(result, expected);
     }
 
-    impl<'a> Iterator for TestIter<'a> {
-        type Item = &'a TestCase;
+    #[test]
+    fn test_reflect_twice_is_identity() {
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+        let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let result = vector.reflect(&normal).reflect(&normal);
 
-        fn next(&mut self) -> Option<Self::Item> {
-            self.inner.next()
-        }
+        assert!(relative_eq!(result, vector, epsilon = 1e-10));
     }
 
-    fn test_cases() -> Test {
-        Test {
-            tests: vec![
-                TestCase {
-                    c: 802.3435169,
-                    v1: Vector4::from((80.0,  23.43, 43.569, 69.9093)),
-                    v2: Vector4::from((6.741, 426.1, 23.5724, 85567.75976)),
-                },
-                TestCase {
-                    c: 33.249539,
-                    v1: Vector4::from((27.6189, 13.90, 4.2219, 91.11955)),
-                    v2: Vector4::from((258.083, 31.70, 42.17, 8438.2376))
-                },
-                TestCase {
-                    c: 7.04217,
-                    v1: Vector4::from((70.0, 49.0, 95.0, 508.5602759)),
-                    v2: Vector4::from((89.9138, 36.84, 427.46894, 0.5796180917)),
-                },
-                TestCase {
-                    c: 61.891390,
-                    v1: Vector4::from((8827.1983, 89.5049494, 56.31, 0.2888633714)),
-                    v2: Vector4::from((89.0, 72.0, 936.5, 0.2888633714)),
-                }
-            ]
-        }
+    #[test]
+    fn test_refract_straight_on_incident_ray() {
+        let vector = Vector3::new(0_f64, -1_f64, 0_f64);
+        let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+        let expected = Some(vector);
+        let result = vector.refract(&normal, 1_f64);
+
+        assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_refract_total_internal_reflection() {
+        let vector = Vector3::new(1_f64, -0.1_f64, 0_f64).normalize();
+        let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+        let result = vector.refract(&normal, 1.5_f64);
+
+        assert!(result.is_none());
+    }
 
     #[test]
-    fn test_components1() {
-        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    fn test_refract_snells_law() {
+        let vector = Vector3::new(1_f64, -1_f64, 0_f64).normalize();
+        let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::unit_y());
+        let eta = 1_f64 / 1.5_f64;
+        let result = vector.refract(&normal, eta).unwrap();
+        let sin_theta_i = vector.cross(&normal).magnitude();
+        let sin_theta_t = result.cross(&normal).magnitude();
 
-        assert_eq!(vector[0], 1_i32);
-        assert_eq!(vector[1], 2_i32);
-        assert_eq!(vector[2], 3_i32);
-        assert_eq!(vector[3], 4_i32);
+        assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(sin_theta_t, eta * sin_theta_i, epsilon = 1e-10));
     }
 
     #[test]
-    fn test_components2() {
-        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+    fn test_project_onto_axis() {
+        let vector = Vector3::new(2_f64, 3_f64, 4_f64);
+        let unit_x: Vector3<f64> = Vector3::unit_x();
+        let unit_y: Vector3<f64> = Vector3::unit_y();
+        let unit_z: Vector3<f64> = Vector3::unit_z();
 
-        assert_eq!(vector.x, vector[0]);
-        assert_eq!(vector.y, vector[1]);
-        assert_eq!(vector.z, vector[2]);
-        assert_eq!(vector.w, vector[3]);
+        assert_eq!(vector.project_onto(&unit_x), Vector3::new(2_f64, 0_f64, 0_f64));
+        assert_eq!(vector.project_onto(&unit_y), Vector3::new(0_f64, 3_f64, 0_f64));
+        assert_eq!(vector.project_onto(&unit_z), Vector3::new(0_f64, 0_f64, 4_f64));
+    }
+
+    #[test]
+    fn test_reject_from_axis() {
+        let vector = Vector3::new(2_f64, 3_f64, 4_f64);
+        let unit_x: Vector3<f64> = Vector3::unit_x();
+        let unit_y: Vector3<f64> = Vector3::unit_y();
+        let unit_z: Vector3<f64> = Vector3::unit_z();
+
+        assert_eq!(vector.reject_from(&unit_x), Vector3::new(0_f64, 3_f64, 4_f64));
+        assert_eq!(vector.reject_from(&unit_y), Vector3::new(2_f64, 0_f64, 4_f64));
+        assert_eq!(vector.reject_from(&unit_z), Vector3::new(2_f64, 3_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_project_plus_reject_equals_vector() {
+        test_cases().iter().for_each(|test| {
+            let result = test.x.project_onto(&test.y) + test.x.reject_from(&test.y);
+
+            assert!(relative_eq!(result, test.x, epsilon = 1e-10));
+        });
+    }
+
+    #[test]
+    fn test_component_min() {
+        let vector1 = Vector3::new(1_f64, -2_f64, 3_f64);
+        let vector2 = Vector3::new(-3_f64, 4_f64, -5_f64);
+        let expected = Vector3::new(-3_f64, -2_f64, -5_f64);
+        let result = vector1.component_min(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_max() {
+        let vector1 = Vector3::new(1_f64, -2_f64, 3_f64);
+        let vector2 = Vector3::new(-3_f64, 4_f64, -5_f64);
+        let expected = Vector3::new(1_f64, 4_f64, 3_f64);
+        let result = vector1.component_max(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let vector = Vector3::new(-5_f64, 0.5_f64, 5_f64);
+        let min = Vector3::new(-1_f64, -1_f64, -1_f64);
+        let max = Vector3::new(1_f64, 1_f64, 1_f64);
+        let expected = Vector3::new(-1_f64, 0.5_f64, 1_f64);
+        let result = vector.clamp(&min, &max);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_floor() {
+        let vector = Vector3::new(1.25_f64, -1.75_f64, 2.5_f64);
+        let expected = Vector3::new(1_f64, -2_f64, 2_f64);
+        let result = vector.floor();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ceil() {
+        let vector = Vector3::new(1.25_f64, -1.75_f64, 2.5_f64);
+        let expected = Vector3::new(2_f64, -1_f64, 3_f64);
+        let result = vector.ceil();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_round() {
+        let vector = Vector3::new(1.25_f64, -1.75_f64, 2.5_f64);
+        let expected = Vector3::new(1_f64, -2_f64, 3_f64);
+        let result = vector.round();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_normalize_unit_vector() {
+        let unit_x: Vector3<f64> = Vector3::unit_x();
+        let expected = Some(unit_x);
+        let result = unit_x.try_normalize(1e-10);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_normalize_zero_vector() {
+        let zero: Vector3<f64> = Vector3::zero();
+        let result = zero.try_normalize(1e-10);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_clamp_magnitude_long_vector() {
+        let vector = Vector3::new(0_f64, 6_f64, 8_f64);
+        let result = vector.clamp_magnitude(5_f64);
+
+        assert!(relative_eq!(result.magnitude(), 5_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.normalize(), vector.normalize(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_clamp_magnitude_short_vector() {
+        let vector = Vector3::new(0_f64, 0.6_f64, 0.8_f64);
+        let result = vector.clamp_magnitude(5_f64);
+
+        assert_eq!(result, vector);
+    }
+
+    #[test]
+    fn test_clamp_magnitude_zero_vector() {
+        let zero: Vector3<f64> = Vector3::zero();
+        let result = zero.clamp_magnitude(5_f64);
+
+        assert_eq!(result, zero);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let array = [1_i32, 2_i32, 3_i32];
+        let vector = Vector3::from(array);
+        let result: [i32; 3] = vector.into();
+
+        assert_eq!(result, array);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+        let mut iter = vector.into_iter();
+
+        assert_eq!(iter.next(), Some(vector.x));
+        assert_eq!(iter.next(), Some(vector.y));
+        assert_eq!(iter.next(), Some(vector.z));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let expected = Vector3::new(1_i32, 2_i32, 3_i32);
+        let result: Vector3<i32> = (1..4).collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_into_iter_from_iter_round_trip() {
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+        let expected = Vector3::new(2_f64, 4_f64, 6_f64);
+        let result: Vector3<f64> = vector.into_iter().map(|c| c * 2_f64).collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_iter_too_few_components() {
+        let _: Vector3<i32> = (1..3).collect();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_iter_too_many_components() {
+        let _: Vector3<i32> = (1..5).collect();
+    }
+
+    #[test]
+    fn test_spherical_positive_y_axis() {
+        let vector = Vector3::unit_y() * 2_f64;
+        let (radius, theta, _phi) = vector.to_spherical();
+
+        assert!(relative_eq!(radius, 2_f64, epsilon = 1e-10));
+        assert!(relative_eq!(theta, Radians(0_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let vectors = [
+            Vector3::new(1_f64, 0_f64, 0_f64),
+            Vector3::new(0_f64, 0_f64, -1_f64),
+            Vector3::new(0_f64, -4_f64, 0_f64),
+            Vector3::new(1_f64, 2_f64, 3_f64),
+            Vector3::new(-3_f64, 0.5_f64, -7_f64),
+        ];
+        for vector in vectors.iter() {
+            let (radius, theta, phi) = vector.to_spherical();
+            let result = Vector3::from_spherical(radius, theta, phi);
+
+            assert!(relative_eq!(result, vector, epsilon = 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_spherical_zero_vector() {
+        let vector: Vector3<f64> = Vector3::zero();
+        let (radius, theta, phi) = vector.to_spherical();
+
+        assert_eq!(radius, 0_f64);
+        assert_eq!(theta, Radians(0_f64));
+        assert_eq!(phi, Radians(0_f64));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let vector = Vector3::new(4_f64, 9_f64, 16_f64);
+        let expected = Vector3::new(2_f64, 3_f64, 4_f64);
+        let result = vector.sqrt();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_recip() {
+        let vector = Vector3::new(0.25_f64, 0.5_f64, -2_f64);
+        let expected = Vector3::new(4_f64, 2_f64, -0.5_f64);
+        let result = vector.recip();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powf() {
+        let vector = Vector3::new(0.25_f64, 1_f64, 9_f64);
+        let expected = Vector3::new(0.5_f64, 1_f64, 3_f64);
+        let result = vector.powf(0.5_f64);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_step() {
+        let edge = Vector3::new(0.5_f64, 0.5_f64, 0.5_f64);
+        let x = Vector3::new(0.25_f64, 0.5_f64, 0.75_f64);
+        let expected = Vector3::new(0_f64, 1_f64, 1_f64);
+        let result = Vector3::step(&edge, &x);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let edge0 = Vector3::new(1_f64, 1_f64, 1_f64);
+        let edge1 = Vector3::new(3_f64, 3_f64, 3_f64);
+        let x = Vector3::new(0_f64, 1.5_f64, 2.5_f64);
+        let expected = Vector3::new(0_f64, 0.15625_f64, 0.84375_f64);
+        let result = Vector3::smoothstep(&edge0, &edge1, &x);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_mix() {
+        let v0 = Vector3::new(1_f64, 2_f64, 4_f64);
+        let v1 = Vector3::new(3_f64, 6_f64, 0_f64);
+        let expected = Vector3::new(1.5_f64, 3_f64, 3_f64);
+        let result = v0.mix(&v1, 0.25_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_max_element_argmax() {
+        let vector = Vector3::new(1_i32, 5_i32, 3_i32);
+
+        assert_eq!(vector.argmax(), 1);
+        assert_eq!(vector.max_element(), 5_i32);
+    }
+
+    #[test]
+    fn test_min_element_argmin() {
+        let vector = Vector3::new(4_i32, 5_i32, -3_i32);
+
+        assert_eq!(vector.argmin(), 2);
+        assert_eq!(vector.min_element(), -3_i32);
+    }
+
+    #[test]
+    fn test_argmax_ties_return_first_index() {
+        let vector = Vector3::new(2_f64, 7_f64, 7_f64);
+
+        assert_eq!(vector.argmax(), 1);
+    }
+
+    #[test]
+    fn test_argmax_dominant_axis_of_negative_vector() {
+        let vector = Vector3::new(-1_f64, -5_f64, -3_f64);
+
+        assert_eq!(vector.argmax(), 0);
+        assert_eq!(vector.max_element(), -1_f64);
+    }
+
+    #[test]
+    fn test_triple_product_unit_axes() {
+        let unit_x: Vector3<i32> = Vector3::unit_x();
+        let unit_y: Vector3<i32> = Vector3::unit_y();
+        let unit_z: Vector3<i32> = Vector3::unit_z();
+
+        assert_eq!(Vector3::triple_product(&unit_x, &unit_y, &unit_z), 1_i32);
+    }
+
+    #[test]
+    fn test_triple_product_swapping_arguments_negates() {
+        let a = Vector3::new(1_i32, 2_i32, 3_i32);
+        let b = Vector3::new(-4_i32, 5_i32, 1_i32);
+        let c = Vector3::new(2_i32, -1_i32, 7_i32);
+        let result = Vector3::triple_product(&a, &b, &c);
+
+        assert_eq!(Vector3::triple_product(&b, &a, &c), -result);
+        assert_eq!(Vector3::triple_product(&a, &c, &b), -result);
+        assert_eq!(Vector3::triple_product(&c, &b, &a), -result);
+    }
+
+    #[test]
+    fn test_is_nan() {
+        let vector = Vector3::new(1_f64, f64::NAN, 3_f64);
+
+        assert!(vector.is_nan());
+        assert!(!vector.is_finite());
+    }
+
+    #[test]
+    fn test_is_nan_with_infinity() {
+        let vector = Vector3::new(1_f64, f64::INFINITY, 3_f64);
+
+        assert!(!vector.is_nan());
+        assert!(!vector.is_finite());
+    }
+
+    #[test]
+    fn test_default() {
+        let expected: Vector3<f32> = Vector3::zero();
+        let result = Vector3::<f32>::default();
+
+        assert_eq!(result, expected);
+    }
+
+    fn assert_orthonormal_basis(direction: &Unit<Vector3<f64>>) {
+        let (first, second, third) = direction.orthonormal_basis();
+
+        assert_eq!(&first, direction.as_ref());
+        assert!(relative_eq!(second.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(third.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(first.dot(&second), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(first.dot(&third), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(second.dot(&third), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(first.cross(&second), third, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let direction = Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64));
+
+        assert_orthonormal_basis(&direction);
+    }
+
+    #[test]
+    fn test_orthonormal_basis_poles() {
+        let directions = [
+            Vector3::unit_z(),
+            -Vector3::unit_z(),
+            Vector3::new(1e-9_f64, 1e-9_f64, -1_f64),
+            Vector3::new(-1e-9_f64, 0_f64, 1_f64),
+        ];
+        for direction in directions.iter() {
+            assert_orthonormal_basis(&Unit::from_value(*direction));
+        }
+    }
+
+    #[test]
+    fn test_orthonormal_basis_coordinate_axes() {
+        assert_orthonormal_basis(&Unit::from_value(Vector3::unit_x()));
+        assert_orthonormal_basis(&Unit::from_value(Vector3::unit_y()));
+        assert_orthonormal_basis(&Unit::from_value(-Vector3::unit_x()));
+    }
+
+    #[test]
+    fn test_nlerp_endpoints() {
+        let vector1 = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let vector2 = Unit::from_value(Vector3::new(-3_f64, 1_f64, 2_f64));
+
+        assert!(relative_eq!(
+            vector1.nlerp(&vector2, 0_f64).into_inner(), vector1.into_inner(), epsilon = 1e-10
+        ));
+        assert!(relative_eq!(
+            vector1.nlerp(&vector2, 1_f64).into_inner(), vector2.into_inner(), epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_nlerp_midpoint_orthogonal() {
+        let vector1 = Unit::from_value(Vector3::unit_y());
+        let vector2 = Unit::from_value(Vector3::unit_z());
+        let result = vector1.nlerp(&vector2, 0.5_f64);
+
+        assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&vector1), result.dot(&vector2), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_midpoint_orthogonal() {
+        let vector1 = Unit::from_value(Vector3::new(1_f64, 0_f64, 1_f64));
+        let vector2 = Unit::from_value(Vector3::new(-1_f64, 0_f64, 1_f64));
+        let expected = Vector3::unit_z();
+        let result = vector1.slerp(&vector2, 0.5_f64);
+
+        assert!(relative_eq!(result.into_inner(), expected, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let vector1 = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let vector2 = Unit::from_value(Vector3::new(-3_f64, 1_f64, 2_f64));
+
+        assert!(relative_eq!(
+            vector1.slerp(&vector2, 0_f64).into_inner(), vector1.into_inner(), epsilon = 1e-10
+        ));
+        assert!(relative_eq!(
+            vector1.slerp(&vector2, 1_f64).into_inner(), vector2.into_inner(), epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_slerp_constant_angular_speed() {
+        let vector1 = Unit::from_value(Vector3::unit_x());
+        let vector2 = Unit::from_value(Vector3::new(-1_f64, 1_f64, 0_f64));
+        let angle = vector1.dot(&vector2).acos();
+        for i in 0..=10 {
+            let amount = (i as f64) / 10_f64;
+            let result = vector1.slerp(&vector2, amount);
+
+            assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+            assert!(relative_eq!(result.dot(&vector1).acos(), amount * angle, epsilon = 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_slerp_nearly_parallel() {
+        let vector1 = Unit::from_value(Vector3::unit_x());
+        let vector2 = Unit::from_value(Vector3::new(1_f64, 1e-5_f64, 0_f64));
+        let result = vector1.slerp(&vector2, 0.5_f64);
+
+        assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&vector1), result.dot(&vector2), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_opposite() {
+        let vector1 = Unit::from_value(Vector3::unit_x());
+        let vector2 = Unit::from_value(-Vector3::unit_x());
+        let result = vector1.slerp(&vector2, 0.5_f64);
+
+        assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&vector1), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(
+            vector1.slerp(&vector2, 1_f64).into_inner(), vector2.into_inner(), epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_signum() {
+        let vector = Vector3::new(-2_f64, 0_f64, 5_f64);
+        let expected = Vector3::new(-1_f64, 0_f64, 1_f64);
+        let result = vector.signum();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_signum_nan() {
+        let vector = Vector3::new(f64::NAN, -0.5_f64, 0.5_f64);
+        let result = vector.signum();
+
+        assert!(result.x.is_nan());
+        assert_eq!(result.y, -1_f64);
+        assert_eq!(result.z, 1_f64);
+    }
+
+    #[test]
+    fn test_copysign() {
+        let vector = Vector3::new(-2_f64, 3_f64, 0_f64);
+        let sign = Vector3::new(1_f64, -7_f64, -1_f64);
+        let result = vector.copysign(&sign);
+
+        assert_eq!(result, Vector3::new(2_f64, -3_f64, 0_f64));
+        assert!(result.z.is_sign_negative());
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+
+        assert_eq!(vector.sum(), 6_i32);
+        assert_eq!(vector.product(), 6_i32);
+    }
+
+    #[test]
+    fn test_product_volume() {
+        let extents = Vector3::new(2_f64, 0.5_f64, 3_f64);
+
+        assert_eq!(extents.product(), 3_f64);
+    }
+
+    #[test]
+    fn test_norm_inequalities() {
+        let vector = Vector3::new(1.5_f64, -2.5_f64, 0.25_f64);
+
+        assert!(vector.linf_norm() <= vector.magnitude());
+        assert!(vector.magnitude() <= vector.l1_norm());
+        assert_eq!(vector.l1_norm(), 4.25_f64);
+        assert_eq!(vector.linf_norm(), 2.5_f64);
+    }
+
+
+    #[test]
+    fn test_sum() {
+        let vectors = [
+            Vector3::new(1_f64, 2_f64, 3_f64),
+            Vector3::new(-4_f64, 5_f64, 6_f64),
+            Vector3::new(7_f64, -8_f64, 9_f64),
+        ];
+        let expected = Vector3::new(4_f64, -1_f64, 18_f64);
+
+        assert_eq!(vectors.iter().sum::<Vector3<f64>>(), expected);
+        assert_eq!(vectors.iter().copied().sum::<Vector3<f64>>(), expected);
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        let vectors: [Vector3<f64>; 0] = [];
+
+        assert_eq!(vectors.iter().sum::<Vector3<f64>>(), Vector3::zero());
+    }
+
+    #[test]
+    fn test_sum_centroid() {
+        let vectors = [
+            Vector3::new(0_f64, 0_f64, 0_f64),
+            Vector3::new(3_f64, 0_f64, 0_f64),
+            Vector3::new(0_f64, 3_f64, 6_f64),
+        ];
+        let expected = Vector3::new(1_f64, 1_f64, 2_f64);
+        let result = vectors.iter().sum::<Vector3<f64>>() / (vectors.len() as f64);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_product() {
+        let vectors = [
+            Vector3::new(1_i32, 2_i32, 3_i32),
+            Vector3::new(-4_i32, 5_i32, 6_i32),
+            Vector3::new(7_i32, -8_i32, 9_i32),
+        ];
+        let expected = Vector3::new(-28_i32, -80_i32, 162_i32);
+
+        assert_eq!(vectors.iter().product::<Vector3<i32>>(), expected);
+        assert_eq!(vectors.iter().copied().product::<Vector3<i32>>(), expected);
+    }
+
+    #[test]
+    fn test_product_empty() {
+        let vectors: [Vector3<i32>; 0] = [];
+
+        assert_eq!(vectors.iter().product::<Vector3<i32>>(), Vector3::from_fill(1_i32));
+    }
+
+
+    #[test]
+    fn test_cmp_lt() {
+        let vector1 = Vector3::new(1_i32, 5_i32, 3_i32);
+        let vector2 = Vector3::new(2_i32, 2_i32, 2_i32);
+
+        assert_eq!(vector1.cmp_lt(&vector2), [true, false, false]);
+    }
+
+    #[test]
+    fn test_cmp_gt() {
+        let vector1 = Vector3::new(1_i32, 5_i32, 2_i32);
+        let vector2 = Vector3::new(2_i32, 2_i32, 2_i32);
+
+        assert_eq!(vector1.cmp_gt(&vector2), [false, true, false]);
+    }
+
+    #[test]
+    fn test_cmp_eq() {
+        let vector1 = Vector3::new(1_i32, 5_i32, 2_i32);
+        let vector2 = Vector3::new(2_i32, 2_i32, 2_i32);
+
+        assert_eq!(vector1.cmp_eq(&vector2), [false, false, true]);
+    }
+
+    #[test]
+    fn test_cmp_nan() {
+        let vector1 = Vector3::new(f64::NAN, 1_f64, 1_f64);
+        let vector2 = Vector3::new(f64::NAN, 1_f64, 2_f64);
+
+        assert_eq!(vector1.cmp_lt(&vector2), [false, false, true]);
+        assert_eq!(vector1.cmp_gt(&vector2), [false, false, false]);
+        assert_eq!(vector1.cmp_eq(&vector2), [false, true, false]);
+    }
+
+
+    #[test]
+    fn test_select() {
+        let if_true = Vector3::new(1_f64, 2_f64, 3_f64);
+        let if_false = Vector3::new(-1_f64, -2_f64, -3_f64);
+
+        assert_eq!(Vector3::select([true, true, true], &if_true, &if_false), if_true);
+        assert_eq!(Vector3::select([false, false, false], &if_true, &if_false), if_false);
+        assert_eq!(
+            Vector3::select([true, false, false], &if_true, &if_false), 
+            Vector3::new(1_f64, -2_f64, -3_f64)
+        );
+        assert_eq!(
+            Vector3::select([false, true, false], &if_true, &if_false), 
+            Vector3::new(-1_f64, 2_f64, -3_f64)
+        );
+        assert_eq!(
+            Vector3::select([false, false, true], &if_true, &if_false), 
+            Vector3::new(-1_f64, -2_f64, 3_f64)
+        );
+    }
+
+    #[test]
+    fn test_select_with_comparison_mask() {
+        let vector = Vector3::new(-1_f64, 2_f64, -3_f64);
+        let zero = Vector3::zero();
+        let expected = Vector3::new(0_f64, 2_f64, 0_f64);
+        let result = Vector3::select(vector.cmp_lt(&zero), &zero, &vector);
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[test]
+    fn test_abs_i32() {
+        let vector = Vector3::new(-1_i32, 2_i32, -3_i32);
+        let expected = Vector3::new(1_i32, 2_i32, 3_i32);
+
+        assert_eq!(vector.abs(), expected);
+    }
+
+    #[test]
+    fn test_abs_f64() {
+        let vector = Vector3::new(-1.5_f64, 0_f64, -3_f64);
+        let expected = Vector3::new(1.5_f64, 0_f64, 3_f64);
+
+        assert_eq!(vector.abs(), expected);
+    }
+
+    #[test]
+    fn test_scalar_signed_generic() {
+        fn sign_and_magnitude<S: ScalarSigned>(value: S) -> (S, S) {
+            (value.signum(), value.abs())
+        }
+
+        assert_eq!(sign_and_magnitude(-4_i32), (-1_i32, 4_i32));
+        assert_eq!(sign_and_magnitude(0_i64), (0_i64, 0_i64));
+        assert_eq!(sign_and_magnitude(2.5_f64), (1_f64, 2.5_f64));
+        assert_eq!(sign_and_magnitude(-2.5_f32), (-1_f32, 2.5_f32));
+    }
+
+    #[test]
+    fn test_any_perpendicular() {
+        let vectors = [
+            Vector3::new(1_f64, 2_f64, 3_f64),
+            Vector3::new(-4_f64, 0.5_f64, 7_f64),
+            Vector3::new(1e-8_f64, -1e8_f64, 3_f64),
+            Vector3::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_z(),
+            -Vector3::unit_z(),
+        ];
+        for vector in vectors.iter() {
+            let perpendicular = vector.any_perpendicular();
+
+            assert!(relative_eq!(vector.dot(&perpendicular), 0_f64, epsilon = 1e-10));
+            assert!(perpendicular.magnitude() > 0_f64);
+        }
+    }
+
+    #[test]
+    fn test_truncate() {
+        let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+
+        assert_eq!(vector.truncate(), vector.contract());
+    }
+
+    #[test]
+    fn test_lerp_clamped_overshoot() {
+        let v0 = Vector3::new(1_f64, 2_f64, 3_f64);
+        let v1 = Vector3::new(-4_f64, 5_f64, 6_f64);
+
+        assert_eq!(v0.lerp_clamped(&v1, 2_f64), v1);
+        assert_eq!(v0.lerp_clamped(&v1, -3_f64), v0);
+    }
+
+    #[test]
+    fn test_lerp_clamped_within_range() {
+        let v0 = Vector3::new(1_f64, 2_f64, 3_f64);
+        let v1 = Vector3::new(-4_f64, 5_f64, 6_f64);
+
+        assert_eq!(v0.lerp_clamped(&v1, 0.25_f64), v0.lerp(&v1, 0.25_f64));
+    }
+
+    #[test]
+    fn test_dot() {
+        let vector1 = Vector3::new(1_f64, -2_f64, 5_f64);
+        let vector2 = Vector3::new(3_f64, 4_f64, -6_f64);
+        let expected = vector1.x * vector2.x + vector1.y * vector2.y + vector1.z * vector2.z;
+
+        assert_eq!(vector1.dot(&vector2), expected);
+        assert_eq!(vector1.dot(&vector2), vector2.dot(&vector1));
+    }
+
+    #[test]
+    fn test_distance() {
+        let vector1 = Vector3::new(0_f64, 0_f64, 0_f64);
+        let vector2 = Vector3::new(3_f64, 4_f64, 0_f64);
+
+        assert_eq!(vector1.distance(&vector2), 5_f64);
+        assert_eq!(vector1.distance_squared(&vector2), 25_f64);
+    }
+
+    #[test]
+    fn test_distance_is_magnitude_of_difference() {
+        let vector1 = Vector3::new(1_f64, -2_f64, 7_f64);
+        let vector2 = Vector3::new(-3_f64, 5_f64, 2_f64);
+
+        assert_eq!(vector1.distance(&vector2), (vector2 - vector1).magnitude());
+        assert_eq!(vector1.distance(&vector2), vector2.distance(&vector1));
+    }
+
+    #[test]
+    fn test_slerp_nearly_opposite() {
+        let vector1 = Unit::from_value(Vector3::unit_x());
+        let vector2 = Unit::from_value(Vector3::new(-1_f64, 1e-4_f64, 0_f64));
+        let angle = vector1.dot(&vector2).acos();
+
+        assert!(relative_eq!(
+            vector1.slerp(&vector2, 1_f64).into_inner(), vector2.into_inner(), epsilon = 1e-10
+        ));
+        for i in 0..=4 {
+            let amount = (i as f64) / 8_f64;
+            let result = vector1.slerp(&vector2, amount);
+
+            assert!(relative_eq!(result.magnitude(), 1_f64, epsilon = 1e-10));
+            assert!(relative_eq!(result.dot(&vector1).acos(), amount * angle, epsilon = 1e-6));
+            assert!(relative_eq!(result.z, 0_f64, epsilon = 1e-10));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod vector4_tests {
+    use cglinalg::{
+        Vector3,
+        Vector4,
+        Magnitude,
+    };
+    use approx::relative_eq;
+    use core::convert::TryFrom;
+    use core::slice::Iter;
+
+    
+    struct TestCase {
+        c: f32,
+        v1: Vector4<f32>,
+        v2: Vector4<f32>,
+    }
+
+    struct Test {
+        tests: Vec<TestCase>,
+    }
+
+    impl Test {
+        fn iter(&self) -> TestIter {
+            TestIter {
+                inner: self.tests.iter()
+            }
+        }
+    }
+
+    struct TestIter<'a> {
+        inner: Iter<'a, TestCase>,
+    }
+
+    impl<'a> Iterator for TestIter<'a> {
+        type Item = &'a TestCase;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next()
+        }
+    }
+
+    fn test_cases() -> Test {
+        Test {
+            tests: vec![
+                TestCase {
+                    c: 802.3435169,
+                    v1: Vector4::from((80.0,  23.43, 43.569, 69.9093)),
+                    v2: Vector4::from((6.741, 426.1, 23.5724, 85567.75976)),
+                },
+                TestCase {
+                    c: 33.249539,
+                    v1: Vector4::from((27.6189, 13.90, 4.2219, 91.11955)),
+                    v2: Vector4::from((258.083, 31.70, 42.17, 8438.2376))
+                },
+                TestCase {
+                    c: 7.04217,
+                    v1: Vector4::from((70.0, 49.0, 95.0, 508.5602759)),
+                    v2: Vector4::from((89.9138, 36.84, 427.46894, 0.5796180917)),
+                },
+                TestCase {
+                    c: 61.891390,
+                    v1: Vector4::from((8827.1983, 89.5049494, 56.31, 0.2888633714)),
+                    v2: Vector4::from((89.0, 72.0, 936.5, 0.2888633714)),
+                }
+            ]
+        }
+    }
+
+
+    #[test]
+    fn test_components1() {
+        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+
+        assert_eq!(vector[0], 1_i32);
+        assert_eq!(vector[1], 2_i32);
+        assert_eq!(vector[2], 3_i32);
+        assert_eq!(vector[3], 4_i32);
+    }
+
+    #[test]
+    fn test_components2() {
+        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+
+        assert_eq!(vector.x, vector[0]);
+        assert_eq!(vector.y, vector[1]);
+        assert_eq!(vector.z, vector[2]);
+        assert_eq!(vector.w, vector[3]);
     }
 
     #[test]
@@ -921,4 +2103,348 @@ mod vector4_tests {
         assert_eq!(unit_w.magnitude_squared(), 1.0);
         assert_eq!(unit_w.magnitude(), 1.0);
     }
+
+    #[test]
+    fn test_project_onto_axis() {
+        let vector = Vector4::new(2_f64, 3_f64, 4_f64, 5_f64);
+        let unit_x: Vector4<f64> = Vector4::unit_x();
+        let unit_w: Vector4<f64> = Vector4::unit_w();
+
+        assert_eq!(vector.project_onto(&unit_x), Vector4::new(2_f64, 0_f64, 0_f64, 0_f64));
+        assert_eq!(vector.project_onto(&unit_w), Vector4::new(0_f64, 0_f64, 0_f64, 5_f64));
+    }
+
+    #[test]
+    fn test_reject_from_axis() {
+        let vector = Vector4::new(2_f64, 3_f64, 4_f64, 5_f64);
+        let unit_x: Vector4<f64> = Vector4::unit_x();
+        let unit_w: Vector4<f64> = Vector4::unit_w();
+
+        assert_eq!(vector.reject_from(&unit_x), Vector4::new(0_f64, 3_f64, 4_f64, 5_f64));
+        assert_eq!(vector.reject_from(&unit_w), Vector4::new(2_f64, 3_f64, 4_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_component_min() {
+        let vector1 = Vector4::new(1_f64, -2_f64, 3_f64, -4_f64);
+        let vector2 = Vector4::new(-3_f64, 4_f64, -5_f64, 6_f64);
+        let expected = Vector4::new(-3_f64, -2_f64, -5_f64, -4_f64);
+        let result = vector1.component_min(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_component_max() {
+        let vector1 = Vector4::new(1_f64, -2_f64, 3_f64, -4_f64);
+        let vector2 = Vector4::new(-3_f64, 4_f64, -5_f64, 6_f64);
+        let expected = Vector4::new(1_f64, 4_f64, 3_f64, 6_f64);
+        let result = vector1.component_max(&vector2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let vector = Vector4::new(-5_f64, 0.5_f64, 5_f64, -0.5_f64);
+        let min = Vector4::new(-1_f64, -1_f64, -1_f64, -1_f64);
+        let max = Vector4::new(1_f64, 1_f64, 1_f64, 1_f64);
+        let expected = Vector4::new(-1_f64, 0.5_f64, 1_f64, -0.5_f64);
+        let result = vector.clamp(&min, &max);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_floor() {
+        let vector = Vector4::new(1.25_f64, -1.75_f64, 2.5_f64, -0.5_f64);
+        let expected = Vector4::new(1_f64, -2_f64, 2_f64, -1_f64);
+        let result = vector.floor();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ceil() {
+        let vector = Vector4::new(1.25_f64, -1.75_f64, 2.5_f64, -0.5_f64);
+        let expected = Vector4::new(2_f64, -1_f64, 3_f64, 0_f64);
+        let result = vector.ceil();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_round() {
+        let vector = Vector4::new(1.25_f64, -1.75_f64, 2.5_f64, -0.5_f64);
+        let expected = Vector4::new(1_f64, -2_f64, 3_f64, -1_f64);
+        let result = vector.round();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let array = [1_i32, 2_i32, 3_i32, 4_i32];
+        let vector = Vector4::from(array);
+        let result: [i32; 4] = vector.into();
+
+        assert_eq!(result, array);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = [1_i32, 2_i32, 3_i32, 4_i32];
+
+        assert!(vector.into_iter().eq(expected.iter().copied()));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let expected = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let result: Vector4<i32> = (1..5).collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let vector = Vector4::new(4_f64, 9_f64, 16_f64, 25_f64);
+        let expected = Vector4::new(2_f64, 3_f64, 4_f64, 5_f64);
+        let result = vector.sqrt();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_recip() {
+        let vector = Vector4::new(0.25_f64, 0.5_f64, -2_f64, 1_f64);
+        let expected = Vector4::new(4_f64, 2_f64, -0.5_f64, 1_f64);
+        let result = vector.recip();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powf() {
+        let vector = Vector4::new(0.25_f64, 1_f64, 9_f64, 16_f64);
+        let expected = Vector4::new(0.5_f64, 1_f64, 3_f64, 4_f64);
+        let result = vector.powf(0.5_f64);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_step() {
+        let edge = Vector4::new(0.5_f64, 0.5_f64, 0.5_f64, -1_f64);
+        let x = Vector4::new(0.25_f64, 0.5_f64, 0.75_f64, -2_f64);
+        let expected = Vector4::new(0_f64, 1_f64, 1_f64, 0_f64);
+        let result = Vector4::step(&edge, &x);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let edge0 = Vector4::new(1_f64, 1_f64, 1_f64, 1_f64);
+        let edge1 = Vector4::new(3_f64, 3_f64, 3_f64, 3_f64);
+        let x = Vector4::new(0_f64, 1.5_f64, 2.5_f64, 4_f64);
+        let expected = Vector4::new(0_f64, 0.15625_f64, 0.84375_f64, 1_f64);
+        let result = Vector4::smoothstep(&edge0, &edge1, &x);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_mix() {
+        let v0 = Vector4::new(1_f64, 2_f64, 4_f64, 8_f64);
+        let v1 = Vector4::new(3_f64, 6_f64, 0_f64, 0_f64);
+        let expected = Vector4::new(1.5_f64, 3_f64, 3_f64, 6_f64);
+        let result = v0.mix(&v1, 0.25_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_signum_copysign() {
+        let vector = Vector4::new(-4_f64, 9_f64, 0_f64, 2_f64);
+        let sign = Vector4::new(3_f64, -0.1_f64, -5_f64, -0_f64);
+        let expected = Vector4::new(1_f64, -1_f64, 0_f64, -1_f64);
+        let result = vector.copysign(&sign).signum();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let vector = Vector4::new(1_u32, 2_u32, 3_u32, 4_u32);
+
+        assert_eq!(vector.sum(), 10_u32);
+        assert_eq!(vector.product(), 24_u32);
+    }
+
+
+    #[test]
+    fn test_perspective_divide() {
+        let vector = Vector4::new(2_f64, 4_f64, 6_f64, 2_f64);
+        let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+        let result = vector.perspective_divide();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_perspective_divide_zero_w() {
+        let vector = Vector4::new(1_f64, -1_f64, 0_f64, 0_f64);
+        let result = vector.perspective_divide();
+
+        assert_eq!(result[0], f64::INFINITY);
+        assert_eq!(result[1], f64::NEG_INFINITY);
+        assert!(result[2].is_nan());
+    }
+
+
+    #[test]
+    fn test_sum() {
+        let vectors = [
+            Vector4::new(1_i32, 2_i32, 3_i32, 4_i32),
+            Vector4::new(5_i32, 6_i32, 7_i32, 8_i32),
+            Vector4::new(-9_i32, 10_i32, -11_i32, 12_i32),
+        ];
+        let expected = Vector4::new(-3_i32, 18_i32, -1_i32, 24_i32);
+        let result: Vector4<i32> = vectors.iter().sum();
+
+        assert_eq!(result, expected);
+    }
+
+
+    #[test]
+    fn test_try_from_slice() {
+        let slice: &[f64] = &[1_f64, 2_f64, 3_f64, 4_f64];
+        let expected = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let result = Vector4::try_from(slice).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_from_slice_wrong_length() {
+        let too_short: &[f64] = &[1_f64, 2_f64, 3_f64];
+        let too_long: &[f64] = &[1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+
+        assert!(Vector4::try_from(too_short).is_err());
+        assert!(Vector4::try_from(too_long).is_err());
+    }
+
+
+    #[test]
+    fn test_cmp() {
+        let vector1 = Vector4::new(1_u32, 5_u32, 3_u32, 7_u32);
+        let vector2 = Vector4::new(5_u32, 5_u32, 1_u32, 8_u32);
+
+        assert_eq!(vector1.cmp_lt(&vector2), [true, false, false, true]);
+        assert_eq!(vector1.cmp_gt(&vector2), [false, false, true, false]);
+        assert_eq!(vector1.cmp_eq(&vector2), [false, true, false, false]);
+    }
+
+
+    #[test]
+    fn test_select() {
+        let if_true = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let if_false = Vector4::new(5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = Vector4::new(5_i32, 2_i32, 7_i32, 4_i32);
+
+        assert_eq!(Vector4::select([false, true, false, true], &if_true, &if_false), expected);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let vector = Vector4::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = Vector3::new(1_i32, 2_i32, 3_i32);
+        let result = vector.truncate();
+
+        assert_eq!(result, expected);
+        assert_eq!(result, vector.contract());
+    }
+
+    #[test]
+    fn test_dot() {
+        let vector1 = Vector4::new(1_f64, -2_f64, 5_f64, 7_f64);
+        let vector2 = Vector4::new(3_f64, 4_f64, -6_f64, 0.5_f64);
+        let expected = vector1.x * vector2.x + vector1.y * vector2.y 
+            + vector1.z * vector2.z + vector1.w * vector2.w;
+
+        assert_eq!(vector1.dot(&vector2), expected);
+        assert_eq!(vector1.dot(&vector2), vector2.dot(&vector1));
+    }
+}
+
+
+#[cfg(test)]
+mod vector_space_tests {
+    use approx::relative_eq;
+    use cglinalg::{
+        Vector2,
+        Vector3,
+        InnerProductSpace,
+        Magnitude,
+    };
+
+
+    fn orthonormalize<V>(vectors: &mut [V])
+    where
+        V: InnerProductSpace
+    {
+        for i in 0..vectors.len() {
+            let mut vector = vectors[i];
+            for basis_vector in vectors[..i].iter() {
+                vector = vector - *basis_vector * vector.dot(basis_vector);
+            }
+            vectors[i] = vector.normalize();
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize_vector2() {
+        let mut vectors = [
+            Vector2::new(3_f64, 1_f64),
+            Vector2::new(2_f64, 2_f64),
+        ];
+        orthonormalize(&mut vectors);
+
+        assert!(relative_eq!(vectors[0].magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(vectors[1].magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(vectors[0].dot(&vectors[1]), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_orthonormalize_vector3() {
+        let mut vectors = [
+            Vector3::new(1_f64, 1_f64, 0_f64),
+            Vector3::new(1_f64, 0_f64, 1_f64),
+            Vector3::new(0_f64, 1_f64, 1_f64),
+        ];
+        orthonormalize(&mut vectors);
+
+        for (i, vector) in vectors.iter().enumerate() {
+            assert!(relative_eq!(vector.magnitude(), 1_f64, epsilon = 1e-10));
+            for other in vectors[(i + 1)..].iter() {
+                assert!(relative_eq!(vector.dot(other), 0_f64, epsilon = 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize_preserves_first_direction() {
+        let mut vectors = [
+            Vector3::new(2_f64, 0_f64, 0_f64),
+            Vector3::new(1_f64, 3_f64, 0_f64),
+            Vector3::new(1_f64, 1_f64, 4_f64),
+        ];
+        orthonormalize(&mut vectors);
+
+        assert!(relative_eq!(vectors[0], Vector3::unit_x(), epsilon = 1e-10));
+        assert!(relative_eq!(vectors[1], Vector3::unit_y(), epsilon = 1e-10));
+        assert!(relative_eq!(vectors[2], Vector3::unit_z(), epsilon = 1e-10));
+    }
 }